@@ -0,0 +1,380 @@
+//! BabyJubJub, the twisted Edwards curve `a x^2 + y^2 = 1 + d x^2 y^2` over the scalar field of
+//! BN254, with the parameters of circomlib.
+//!
+//! Its points are pairs of [`Fr`], so they are cheap to compute with in circuits over BN254. The
+//! addition formulas are complete since `d` is not a square, an addition takes six constraints as
+//! in circomlib's `BabyAdd`. Scalars are little-endian [`Bit`]s: a multiplication of a constant
+//! point takes an addition per bit, of a variable point another addition to double it and two
+//! constraints to select it.
+use std::ops::Add;
+use std::str::FromStr;
+
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, Field, One, PrimeField, Zero};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+use num_bigint::BigUint;
+
+use crate::gadget::{mul, Bit, Lc, Var};
+
+pub const A: u64 = 168700;
+pub const D: u64 = 168696;
+
+const GENERATOR: [&str; 2] = [
+    "995203441582195749578291179787384436505546430278305826713579947235728471134",
+    "5472060717959818805561601436314318772137091100104008585924551046643952123905",
+];
+const BASE8: [&str; 2] = [
+    "5299619240641551281634865583518297030282874472190772894086521144482721001553",
+    "16950150798460657717958625567821834550301663161624707787222815936182638968203",
+];
+const SUBGROUP_ORDER: &str =
+    "2736030358979909402780800718157159386076813972158567259200215660948447373041";
+
+/// The prime order `l` of [`Point::base8`], the group has `8 l` points.
+pub fn subgroup_order() -> BigUint {
+    BigUint::from_str(SUBGROUP_ORDER).unwrap()
+}
+
+/// The little-endian bits of `scalar`, which has to fit in `n` bits.
+pub fn scalar_bits(scalar: &BigUint, n: usize) -> Vec<bool> {
+    assert!(
+        scalar.bits() <= n as u64,
+        "the scalar doesn't fit in {} bits",
+        n
+    );
+    (0..n as u64).map(|i| scalar.bit(i)).collect()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Point {
+    pub x: Fr,
+    pub y: Fr,
+}
+
+impl Point {
+    pub fn new(x: Fr, y: Fr) -> Self {
+        Point { x, y }
+    }
+
+    pub fn identity() -> Self {
+        Point::new(Fr::zero(), Fr::one())
+    }
+
+    /// The generator of the whole group.
+    pub fn generator() -> Self {
+        Point::parse(GENERATOR)
+    }
+
+    /// Eight times the generator, which generates the subgroup of order `l`.
+    pub fn base8() -> Self {
+        Point::parse(BASE8)
+    }
+
+    fn parse(coordinates: [&str; 2]) -> Self {
+        Point::new(
+            Fr::from_str(coordinates[0]).unwrap(),
+            Fr::from_str(coordinates[1]).unwrap(),
+        )
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        let xx = self.x.square();
+        let yy = self.y.square();
+        Fr::from(A) * xx + yy == Fr::one() + Fr::from(D) * xx * yy
+    }
+
+    pub fn double(&self) -> Self {
+        *self + *self
+    }
+
+    /// `scalar` times the point.
+    pub fn scalar_mul(&self, scalar: &BigUint) -> Self {
+        (0..scalar.bits()).rev().fold(Point::identity(), |acc, i| {
+            let acc = acc.double();
+            if scalar.bit(i) {
+                acc + *self
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        let tau = Fr::from(D) * self.x * other.x * self.y * other.y;
+        let x = (self.x * other.y + self.y * other.x) / (Fr::one() + tau);
+        let y = (self.y * other.y - Fr::from(A) * self.x * other.x) / (Fr::one() - tau);
+        Point::new(x, y)
+    }
+}
+
+/// A point whose coordinates are linear combinations.
+#[derive(Clone, Debug)]
+pub struct PointVar {
+    pub x: Lc<Fr>,
+    pub y: Lc<Fr>,
+}
+
+impl PointVar {
+    pub fn constant(point: Point) -> Self {
+        PointVar {
+            x: Lc::constant(point.x),
+            y: Lc::constant(point.y),
+        }
+    }
+
+    /// Allocates the coordinates as witnesses, constrained to be on the curve.
+    pub fn witness(
+        cs: &ConstraintSystemRef<Fr>,
+        point: Option<Point>,
+    ) -> Result<Self, SynthesisError> {
+        let x = Var::witness(cs, point.map(|point| point.x))?;
+        let y = Var::witness(cs, point.map(|point| point.y))?;
+        let point = PointVar {
+            x: Lc::from(x),
+            y: Lc::from(y),
+        };
+        point.enforce_on_curve(cs)?;
+        Ok(point)
+    }
+
+    /// Allocates the coordinates as two public inputs, constrained to be on the curve.
+    pub fn input(
+        cs: &ConstraintSystemRef<Fr>,
+        point: Option<Point>,
+    ) -> Result<Self, SynthesisError> {
+        let x = Var::input(cs, point.map(|point| point.x))?;
+        let y = Var::input(cs, point.map(|point| point.y))?;
+        let point = PointVar {
+            x: Lc::from(x),
+            y: Lc::from(y),
+        };
+        point.enforce_on_curve(cs)?;
+        Ok(point)
+    }
+
+    pub fn value(&self) -> Option<Point> {
+        self.x
+            .value
+            .zip(self.y.value)
+            .map(|(x, y)| Point::new(x, y))
+    }
+
+    /// Three constraints, the squares and the curve equation.
+    pub fn enforce_on_curve(&self, cs: &ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let xx = Lc::from(mul(cs, &self.x, &self.x)?);
+        let yy = Lc::from(mul(cs, &self.y, &self.y)?);
+        cs.enforce_constraint(
+            xx.clone().scale(Fr::from(D)).lc,
+            yy.lc.clone(),
+            (xx.scale(Fr::from(A)) + &yy).add_constant(-Fr::one()).lc,
+        )
+    }
+
+    pub fn add(
+        &self,
+        cs: &ConstraintSystemRef<Fr>,
+        other: &PointVar,
+    ) -> Result<PointVar, SynthesisError> {
+        let beta = Lc::from(mul(cs, &self.x, &other.y)?);
+        let gamma = Lc::from(mul(cs, &self.y, &other.x)?);
+        let delta = Lc::from(mul(
+            cs,
+            &(self.y.clone() - &self.x.clone().scale(Fr::from(A))),
+            &(other.x.clone() + &other.y),
+        )?);
+        let tau = Lc::from(mul(cs, &beta, &gamma)?).scale(Fr::from(D));
+
+        let sum = self.value().zip(other.value()).map(|(a, b)| a + b);
+        let x = Var::witness(cs, sum.map(|sum| sum.x))?;
+        let y = Var::witness(cs, sum.map(|sum| sum.y))?;
+        // x (1 + d tau) = beta + gamma
+        cs.enforce_constraint(
+            Lc::from(x).lc,
+            tau.clone().add_constant(Fr::one()).lc,
+            (beta.clone() + &gamma).lc,
+        )?;
+        // y (1 - d tau) = y1 y2 - a x1 x2 = delta + a beta - gamma
+        cs.enforce_constraint(
+            Lc::from(y).lc,
+            (Lc::constant(Fr::one()) - &tau).lc,
+            (delta + &beta.scale(Fr::from(A)) - &gamma).lc,
+        )?;
+        Ok(PointVar {
+            x: Lc::from(x),
+            y: Lc::from(y),
+        })
+    }
+
+    pub fn double(&self, cs: &ConstraintSystemRef<Fr>) -> Result<PointVar, SynthesisError> {
+        self.add(cs, self)
+    }
+
+    /// The point if `bit` is set and the identity otherwise.
+    fn select(
+        &self,
+        cs: &ConstraintSystemRef<Fr>,
+        bit: &Bit<Fr>,
+    ) -> Result<PointVar, SynthesisError> {
+        let y = self.y.clone().add_constant(-Fr::one());
+        Ok(PointVar {
+            x: Lc::from(mul(cs, &bit.lc(), &self.x)?),
+            y: Lc::from(mul(cs, &bit.lc(), &y)?).add_constant(Fr::one()),
+        })
+    }
+
+    /// The point times the scalar of little-endian `bits`, 14 constraints per bit.
+    pub fn scalar_mul(
+        &self,
+        cs: &ConstraintSystemRef<Fr>,
+        bits: &[Bit<Fr>],
+    ) -> Result<PointVar, SynthesisError> {
+        let mut acc = PointVar::constant(Point::identity());
+        let mut base = self.clone();
+        for (i, bit) in bits.iter().enumerate() {
+            if i > 0 {
+                base = base.double(cs)?;
+            }
+            let selected = base.select(cs, bit)?;
+            acc = if i == 0 {
+                selected
+            } else {
+                acc.add(cs, &selected)?
+            };
+        }
+        Ok(acc)
+    }
+
+    pub fn enforce_equal(
+        &self,
+        cs: &ConstraintSystemRef<Fr>,
+        other: &PointVar,
+    ) -> Result<(), SynthesisError> {
+        self.x.enforce_equal(cs, &other.x)?;
+        self.y.enforce_equal(cs, &other.y)
+    }
+}
+
+/// The constant `base` times the scalar of little-endian `bits`.
+///
+/// The multiples `2^i base` are constants, so selecting them is linear and only the additions
+/// take constraints.
+pub fn fixed_base_mul(
+    cs: &ConstraintSystemRef<Fr>,
+    base: Point,
+    bits: &[Bit<Fr>],
+) -> Result<PointVar, SynthesisError> {
+    let mut acc = PointVar::constant(Point::identity());
+    let mut multiple = base;
+    for (i, bit) in bits.iter().enumerate() {
+        let selected = PointVar {
+            x: bit.lc().scale(multiple.x),
+            y: bit
+                .lc()
+                .scale(multiple.y - Fr::one())
+                .add_constant(Fr::one()),
+        };
+        acc = if i == 0 {
+            selected
+        } else {
+            acc.add(cs, &selected)?
+        };
+        multiple = multiple.double();
+    }
+    Ok(acc)
+}
+
+/// The canonical integer of a field element.
+pub fn to_biguint(value: Fr) -> BigUint {
+    BigUint::from_bytes_le(&value.into_repr().to_bytes_le())
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use super::*;
+
+    fn point(x: &str, y: &str) -> Point {
+        Point::parse([x, y])
+    }
+
+    #[test]
+    fn base_points_are_in_the_subgroup() {
+        let base8 = Point::base8();
+        assert!(Point::generator().is_on_curve());
+        assert!(base8.is_on_curve());
+        assert_eq!(Point::generator().scalar_mul(&BigUint::from(8u64)), base8);
+        assert_eq!(base8.scalar_mul(&subgroup_order()), Point::identity());
+        assert_ne!(
+            base8.scalar_mul(&(subgroup_order() - 1u64)),
+            Point::identity()
+        );
+        assert_eq!(subgroup_order().bits(), 251);
+    }
+
+    #[test]
+    fn addition_matches_circomlib() {
+        // the additions of circomlib's babyjub tests
+        let p1 = point(
+            "17777552123799933955779906779655732241715742912184938656739573121738514868268",
+            "2626589144620713026669568689430873010625803728049924121243784502389097019475",
+        );
+        let p2 = point(
+            "16540640123574156134436876038791482806971768689494387082833631921987005038935",
+            "20819045374670962167435360035096875258406992893633759881276124905556507972311",
+        );
+        assert_eq!(
+            p1 + p1,
+            point(
+                "6890855772600357754907169075114257697580319025794532037257385534741338397365",
+                "4338620300185947561074059802482547481416142213883829469920100239455078257889",
+            )
+        );
+        assert_eq!(
+            p1 + p2,
+            point(
+                "7916061937171219682591368294088513039687205273691143098332585753343424131937",
+                "14035240266687799601661095864649209771790948434046947201833777492504781204499",
+            )
+        );
+
+        let cs = ConstraintSystem::new_ref();
+        let a = PointVar::witness(&cs, Some(p1)).unwrap();
+        let b = PointVar::witness(&cs, Some(p2)).unwrap();
+        let sum = a.add(&cs, &b).unwrap();
+        assert_eq!(sum.value(), Some(p1 + p2));
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(cs.num_constraints(), 2 * 3 + 6);
+    }
+
+    #[test]
+    fn multiplications_are_constrained() {
+        let scalar = BigUint::from(0xdead_beef_u64) * 0x1234_5678_9abc_u64;
+        let bits = scalar_bits(&scalar, 80);
+        let expected = Point::base8().scalar_mul(&scalar);
+
+        let cs = ConstraintSystem::new_ref();
+        let bits = bits
+            .iter()
+            .map(|bit| Bit::witness(&cs, Some(*bit)).unwrap())
+            .collect::<Vec<_>>();
+        let fixed = fixed_base_mul(&cs, Point::base8(), &bits).unwrap();
+        assert_eq!(fixed.value(), Some(expected));
+        assert_eq!(cs.num_constraints(), 80 + 79 * 6);
+
+        let base = PointVar::witness(&cs, Some(Point::base8())).unwrap();
+        let variable = base.scalar_mul(&cs, &bits).unwrap();
+        assert_eq!(variable.value(), Some(expected));
+        fixed.enforce_equal(&cs, &variable).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+
+        // a point off the curve isn't a witness
+        let cs = ConstraintSystem::new_ref();
+        PointVar::witness(&cs, Some(point("1", "2"))).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+}
@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use circuit::ceremony::Ceremony;
+use circuit::keys::{load_proving_key, save_proving_key, save_verifying_key};
+use circuit::merkle::MERKLE_CIRCUIT_ID;
+use circuit::range::RANGE_CIRCUIT_ID;
+use circuit::DEMO_CIRCUIT_ID;
+
+const USAGE: &str = "usage: ceremony [--circuit <circuit>] init <transcript> <phase-1 proving key>
+       ceremony [--circuit <circuit>] contribute <transcript>
+       ceremony [--circuit <circuit>] finalize <transcript> <beacon hex> <iterations>
+       ceremony [--circuit <circuit>] verify <transcript>
+       ceremony [--circuit <circuit>] export <transcript> <dir>
+<circuit> is demo, range or merkle, demo by default";
+
+/// Runs a phase-2 ceremony for the demo circuit, or the circuit named by `--circuit`, one
+/// command per participant.
+fn main() {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let (name, circuit_id) = if args.first().map(String::as_str) == Some("--circuit") {
+        let name = args.get(1).expect(USAGE).clone();
+        args.drain(..2);
+        match name.as_str() {
+            "demo" => (name, DEMO_CIRCUIT_ID),
+            "range" => (name, RANGE_CIRCUIT_ID),
+            "merkle" => (name, MERKLE_CIRCUIT_ID),
+            _ => panic!("{}", USAGE),
+        }
+    } else {
+        ("demo".to_string(), DEMO_CIRCUIT_ID)
+    };
+    let (command, path) = match args.as_slice() {
+        [command, path, ..] => (command.as_str(), Path::new(path)),
+        _ => panic!("{}", USAGE),
+    };
+    let load = || Ceremony::load(path, circuit_id).unwrap();
+
+    match command {
+        "init" => {
+            let params = args.get(2).expect(USAGE);
+            let params = load_proving_key(params, circuit_id).unwrap();
+            let ceremony = Ceremony::initialize(circuit_id, params);
+            ceremony.save(path).unwrap();
+            println!("started a ceremony for {}", circuit_id);
+        }
+        "contribute" => {
+            let mut ceremony = load();
+            let hash = ceremony.contribute(&mut rand::thread_rng()).unwrap();
+            ceremony.save(path).unwrap();
+            println!("contribution {}", hex::encode(hash));
+        }
+        "finalize" => {
+            let (beacon, iterations) = match &args[2..] {
+                [beacon, iterations] => (hex::decode(beacon).unwrap(), iterations.parse().unwrap()),
+                _ => panic!("{}", USAGE),
+            };
+            let mut ceremony = load();
+            let hash = ceremony.finalize(&beacon, iterations).unwrap();
+            ceremony.save(path).unwrap();
+            println!("beacon contribution {}", hex::encode(hash));
+        }
+        "verify" => {
+            for (k, hash) in load().verify().unwrap().iter().enumerate() {
+                println!("contribution {}: {}", k, hex::encode(hash));
+            }
+        }
+        "export" => {
+            let dir = Path::new(args.get(2).expect(USAGE));
+            std::fs::create_dir_all(dir).unwrap();
+            let (pk, vk) = load().keys().unwrap();
+            save_proving_key(dir.join(format!("{}.pk", name)), circuit_id, &pk).unwrap();
+            save_verifying_key(dir.join(format!("{}.vk", name)), circuit_id, &vk).unwrap();
+            println!("wrote the keys of {} to {}", circuit_id, dir.display());
+        }
+        _ => panic!("{}", USAGE),
+    }
+}
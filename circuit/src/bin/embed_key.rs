@@ -0,0 +1,60 @@
+use ark_bn254::{Fq, Fq2};
+use ark_groth16::prepare_verifying_key;
+use circuit::keys::load_verifying_key;
+use circuit::merkle::MERKLE_CIRCUIT_ID;
+use circuit::range::RANGE_CIRCUIT_ID;
+use circuit::DEMO_CIRCUIT_ID;
+
+/// Prints the constants a contract embeds the verifying key of a key file with, the
+/// Montgomery limbs of the prepared `-gamma` and `-delta`, of `e(alpha, beta)` and of
+/// `gamma_abc_g1`, in the layout of `contract/src/range_key.rs` and `contract/src/merkle_key.rs`.
+///
+/// usage: embed_key <verifying key file> [demo|range|merkle]
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let path = args
+        .first()
+        .expect("usage: embed_key <verifying key file> [demo|range|merkle]");
+    let circuit_id = match args.get(1).map(String::as_str) {
+        None | Some("demo") => DEMO_CIRCUIT_ID,
+        Some("range") => RANGE_CIRCUIT_ID,
+        Some("merkle") => MERKLE_CIRCUIT_ID,
+        Some(other) => panic!("unknown circuit {}, expected demo, range or merkle", other),
+    };
+    let vk = load_verifying_key(path, circuit_id).unwrap();
+    let pvk = prepare_verifying_key(&vk);
+
+    let ell_coeffs = |coeffs: &[(Fq2, Fq2, Fq2)]| {
+        coeffs
+            .iter()
+            .flat_map(|(c0, c1, c2)| vec![c0.c0, c0.c1, c1.c0, c1.c1, c2.c0, c2.c1])
+            .collect::<Vec<_>>()
+    };
+    print_limbs("GAMMA", &ell_coeffs(&pvk.gamma_g2_neg_pc.ell_coeffs));
+    print_limbs("DELTA", &ell_coeffs(&pvk.delta_g2_neg_pc.ell_coeffs));
+
+    let t = pvk.alpha_g1_beta_g2;
+    let alpha_g1_beta_g2 = [t.c0, t.c1]
+        .iter()
+        .flat_map(|c| vec![c.c0, c.c1, c.c2])
+        .flat_map(|c| vec![c.c0, c.c1])
+        .collect::<Vec<_>>();
+    print_limbs("ALPHA_G1_BETA_G2", &alpha_g1_beta_g2);
+
+    let gamma_abc_g1 = vk
+        .gamma_abc_g1
+        .iter()
+        .flat_map(|point| vec![point.x, point.y])
+        .collect::<Vec<_>>();
+    print_limbs("GAMMA_ABC", &gamma_abc_g1);
+}
+
+fn print_limbs(name: &str, elements: &[Fq]) {
+    println!("const {}: [u64; {}] = [", name, elements.len() * 4);
+    for element in elements {
+        for limb in (element.0).0.iter() {
+            println!("    {},", limb);
+        }
+    }
+    println!("];\n");
+}
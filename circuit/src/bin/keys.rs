@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use ark_bn254::Fr;
+use circuit::keys::{save_proving_key, save_verifying_key};
+use circuit::merkle::{MerkleMembership, MERKLE_CIRCUIT_ID, MERKLE_DEPTH};
+use circuit::poseidon::Poseidon;
+use circuit::range::{CommittedRange, RANGE_BITS, RANGE_CIRCUIT_ID};
+use circuit::{demo_proving_key, setup, DEMO_CIRCUIT_ID};
+
+/// Writes the proving and verifying keys of the demo circuit to `demo.pk` and `demo.vk` in the
+/// given directory, `keys` by default.
+///
+/// With `range` or `merkle` as second argument, writes `range.pk` and `range.vk`, or
+/// `merkle.pk` and `merkle.vk`, from a fresh setup of the range or the membership circuit
+/// instead, the phase-1 parameters a ceremony for it starts from.
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let dir = Path::new(args.first().map_or("keys", String::as_str));
+    std::fs::create_dir_all(dir).unwrap();
+    let (name, circuit_id, pk) = match args.get(1).map(String::as_str) {
+        None | Some("demo") => ("demo", DEMO_CIRCUIT_ID, demo_proving_key()),
+        Some("range") => {
+            let circuit = CommittedRange::<Fr>::new(RANGE_BITS, None);
+            let (pk, _) = setup(circuit, &mut rand::thread_rng()).unwrap();
+            ("range", RANGE_CIRCUIT_ID, pk)
+        }
+        Some("merkle") => {
+            let circuit = MerkleMembership::<Fr, _>::new(Poseidon::new(2), MERKLE_DEPTH, None);
+            let (pk, _) = setup(circuit, &mut rand::thread_rng()).unwrap();
+            ("merkle", MERKLE_CIRCUIT_ID, pk)
+        }
+        Some(other) => panic!("unknown circuit {}, expected demo, range or merkle", other),
+    };
+    save_proving_key(dir.join(format!("{}.pk", name)), circuit_id, &pk).unwrap();
+    save_verifying_key(dir.join(format!("{}.vk", name)), circuit_id, &pk.vk).unwrap();
+    println!("wrote the keys of {} to {}", circuit_id, dir.display());
+}
@@ -0,0 +1,10 @@
+use circuit::vectors::known_answers;
+
+/// Writes the known answers of the demo proof to the given path, `known_answers.json` by default.
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "known_answers.json".to_string());
+    known_answers().unwrap().write_json(&path).unwrap();
+    println!("wrote known answers to {}", path);
+}
@@ -0,0 +1,367 @@
+//! BLAKE2b-256, the hash of Casper's account hashes and dictionary item keys, as constraints.
+//!
+//! The words of the state are 64 [`Bit`]s, rotations are free, an exclusive or takes one
+//! constraint per bit and an addition modulo `2^64` one per bit of the sum, carries included.
+//! Bits known when the circuit is built, e.g. the initialization vector or a public prefix of
+//! the message, stay constants and cost nothing.
+//!
+//! [`Blake2bPreimage`] proves the knowledge of a message whose digest is public, optionally
+//! starting with a public prefix, like the algorithm name of an account hash.
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use blake2::digest::{Update, VariableOutput};
+use blake2::VarBlake2b;
+
+use crate::gadget::{Bit, Lc, Var};
+
+pub const DIGEST_LEN: usize = 32;
+const BLOCK_LEN: usize = 128;
+const ROUNDS: usize = 12;
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// The BLAKE2b-256 digest of `message`, `casper_types::crypto::blake2b`.
+pub fn blake2b256(message: &[u8]) -> [u8; DIGEST_LEN] {
+    let mut digest = [0u8; DIGEST_LEN];
+    let mut hasher = VarBlake2b::new(DIGEST_LEN).unwrap();
+    hasher.update(message);
+    hasher.finalize_variable(|hash| digest.copy_from_slice(hash));
+    digest
+}
+
+/// The public inputs encoding a digest, its two halves as little-endian integers.
+pub fn digest_inputs<F: PrimeField>(digest: &[u8; DIGEST_LEN]) -> Vec<F> {
+    digest
+        .chunks(DIGEST_LEN / 2)
+        .map(F::from_le_bytes_mod_order)
+        .collect()
+}
+
+/// Constant bits of `bytes`, the bits of every byte from the least significant one.
+pub fn constant_bits<F: PrimeField>(bytes: &[u8]) -> Vec<Bit<F>> {
+    bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| Bit::Constant(byte >> i & 1 == 1)))
+        .collect()
+}
+
+/// Witness bits of `len` bytes, ordered as in [`constant_bits`].
+pub fn witness_bits<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    bytes: Option<&[u8]>,
+    len: usize,
+) -> Result<Vec<Bit<F>>, SynthesisError> {
+    (0..len * 8)
+        .map(|i| Bit::witness(cs, bytes.map(|bytes| bytes[i / 8] >> (i % 8) & 1 == 1)))
+        .collect()
+}
+
+/// A word of the state, its bits from the least significant one.
+#[derive(Clone, Debug)]
+struct Word<F: PrimeField>(Vec<Bit<F>>);
+
+impl<F: PrimeField> Word<F> {
+    fn constant(word: u64) -> Self {
+        Word(constant_bits(&word.to_le_bytes()))
+    }
+
+    fn xor(&self, cs: &ConstraintSystemRef<F>, other: &Word<F>) -> Result<Self, SynthesisError> {
+        let bits = self
+            .0
+            .iter()
+            .zip(&other.0)
+            .map(|(a, b)| a.xor(cs, b))
+            .collect::<Result<_, _>>()?;
+        Ok(Word(bits))
+    }
+
+    fn rotate_right(&self, n: usize) -> Self {
+        Word((0..64).map(|i| self.0[(i + n) % 64].clone()).collect())
+    }
+
+    /// The sum of `words` modulo `2^64`.
+    fn sum(cs: &ConstraintSystemRef<F>, words: &[&Word<F>]) -> Result<Self, SynthesisError> {
+        let mut constant = 0u128;
+        let mut value = Some(0u128);
+        let mut sum = Lc::zero();
+        let mut is_constant = true;
+        for word in words {
+            let mut weight = F::one();
+            for (i, bit) in word.0.iter().enumerate() {
+                match bit {
+                    Bit::Constant(bit) => constant += (*bit as u128) << i,
+                    Bit::Lc(lc) => {
+                        sum = sum + &lc.clone().scale(weight);
+                        is_constant = false;
+                    }
+                }
+                value = value
+                    .zip(bit.value())
+                    .map(|(v, bit)| v + ((bit as u128) << i));
+                weight.double_in_place();
+            }
+        }
+        if is_constant {
+            return Ok(Word::constant(constant as u64));
+        }
+
+        // the carries of up to `words.len() - 1` overflows
+        let carry_bits = (usize::BITS - (words.len() - 1).leading_zeros()) as usize;
+        let bits = (0..64 + carry_bits)
+            .map(|i| Bit::witness(cs, value.map(|value| value >> i & 1 == 1)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut decomposed = Lc::zero();
+        let mut weight = F::one();
+        for bit in &bits {
+            decomposed = decomposed + &bit.lc().scale(weight);
+            weight.double_in_place();
+        }
+        decomposed.enforce_equal(cs, &sum.add_constant(F::from(constant)))?;
+        Ok(Word(bits[..64].to_vec()))
+    }
+}
+
+/// The mixing function `G` on the words `a`, `b`, `c` and `d` of `v`.
+#[allow(clippy::too_many_arguments)]
+fn mix<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    v: &mut [Word<F>],
+    a: usize,
+    b: usize,
+    c: usize,
+    d: usize,
+    x: &Word<F>,
+    y: &Word<F>,
+) -> Result<(), SynthesisError> {
+    v[a] = Word::sum(cs, &[&v[a], &v[b], x])?;
+    v[d] = v[d].xor(cs, &v[a])?.rotate_right(32);
+    v[c] = Word::sum(cs, &[&v[c], &v[d]])?;
+    v[b] = v[b].xor(cs, &v[c])?.rotate_right(24);
+    v[a] = Word::sum(cs, &[&v[a], &v[b], y])?;
+    v[d] = v[d].xor(cs, &v[a])?.rotate_right(16);
+    v[c] = Word::sum(cs, &[&v[c], &v[d]])?;
+    v[b] = v[b].xor(cs, &v[c])?.rotate_right(63);
+    Ok(())
+}
+
+/// Compresses `block` into `h`, `counter` being the number of bytes hashed so far.
+fn compress<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    h: &mut [Word<F>],
+    block: &[Bit<F>],
+    counter: u128,
+    last: bool,
+) -> Result<(), SynthesisError> {
+    let m = block
+        .chunks(64)
+        .map(|bits| Word(bits.to_vec()))
+        .collect::<Vec<_>>();
+    let mut v = h.to_vec();
+    v.extend(IV.iter().map(|iv| Word::constant(*iv)));
+    v[12] = v[12].xor(cs, &Word::constant(counter as u64))?;
+    v[13] = v[13].xor(cs, &Word::constant((counter >> 64) as u64))?;
+    if last {
+        v[14] = v[14].xor(cs, &Word::constant(u64::MAX))?;
+    }
+
+    for round in 0..ROUNDS {
+        let s = &SIGMA[round % 10];
+        mix(cs, &mut v, 0, 4, 8, 12, &m[s[0]], &m[s[1]])?;
+        mix(cs, &mut v, 1, 5, 9, 13, &m[s[2]], &m[s[3]])?;
+        mix(cs, &mut v, 2, 6, 10, 14, &m[s[4]], &m[s[5]])?;
+        mix(cs, &mut v, 3, 7, 11, 15, &m[s[6]], &m[s[7]])?;
+        mix(cs, &mut v, 0, 5, 10, 15, &m[s[8]], &m[s[9]])?;
+        mix(cs, &mut v, 1, 6, 11, 12, &m[s[10]], &m[s[11]])?;
+        mix(cs, &mut v, 2, 7, 8, 13, &m[s[12]], &m[s[13]])?;
+        mix(cs, &mut v, 3, 4, 9, 14, &m[s[14]], &m[s[15]])?;
+    }
+
+    for i in 0..8 {
+        h[i] = h[i].xor(cs, &v[i])?.xor(cs, &v[i + 8])?;
+    }
+    Ok(())
+}
+
+/// Constrains [`blake2b256`] of the bytes encoded by `message`, see [`constant_bits`].
+pub fn blake2b256_gadget<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    message: &[Bit<F>],
+) -> Result<Vec<Bit<F>>, SynthesisError> {
+    assert_eq!(message.len() % 8, 0, "the message has to be made of bytes");
+    let len = message.len() / 8;
+    let mut h = IV.iter().map(|iv| Word::constant(*iv)).collect::<Vec<_>>();
+    // no key and a 32 byte digest
+    h[0] = h[0].xor(cs, &Word::constant(0x0101_0000 | DIGEST_LEN as u64))?;
+
+    // the empty message is hashed as one block of padding
+    let mut blocks = message.chunks(BLOCK_LEN * 8).collect::<Vec<_>>();
+    if blocks.is_empty() {
+        blocks.push(&[]);
+    }
+    for (k, bits) in blocks.iter().enumerate() {
+        let mut block = bits.to_vec();
+        block.resize(BLOCK_LEN * 8, Bit::Constant(false));
+        let last = k == blocks.len() - 1;
+        let counter = if last { len } else { (k + 1) * BLOCK_LEN };
+        compress(cs, &mut h, &block, counter as u128, last)?;
+    }
+    Ok(h.into_iter()
+        .flat_map(|word| word.0)
+        .take(DIGEST_LEN * 8)
+        .collect())
+}
+
+/// Proves the knowledge of a message with a public prefix and a public BLAKE2b-256 digest.
+///
+/// The public inputs are the digest as encoded by [`digest_inputs`].
+#[derive(Clone, Debug)]
+pub struct Blake2bPreimage {
+    /// The public start of the message.
+    pub prefix: Vec<u8>,
+    /// The length of the secret rest of the message.
+    pub len: usize,
+    /// The secret rest of the message when proving, `None` during the setup.
+    pub secret: Option<Vec<u8>>,
+}
+
+impl Blake2bPreimage {
+    pub fn new(prefix: Vec<u8>, len: usize, secret: Option<Vec<u8>>) -> Self {
+        Blake2bPreimage {
+            prefix,
+            len,
+            secret,
+        }
+    }
+
+    /// The preimage of the account hash of a public key of `algorithm`, e.g. `ed25519` with 32
+    /// byte keys or `secp256k1` with 33 byte keys.
+    pub fn account_hash(algorithm: &str, key_len: usize, public_key: Option<Vec<u8>>) -> Self {
+        let mut prefix = algorithm.as_bytes().to_vec();
+        prefix.push(0);
+        Blake2bPreimage::new(prefix, key_len, public_key)
+    }
+
+    /// The preimage of the key of an item of the dictionary with the address `seed`.
+    pub fn dictionary_item_key(seed: [u8; 32], item_len: usize, item: Option<Vec<u8>>) -> Self {
+        Blake2bPreimage::new(seed.to_vec(), item_len, item)
+    }
+
+    /// The digest of the message, if assigned.
+    pub fn digest(&self) -> Option<[u8; DIGEST_LEN]> {
+        let secret = self.secret.as_ref()?;
+        Some(blake2b256(&[self.prefix.as_slice(), secret].concat()))
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for Blake2bPreimage {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        if let Some(secret) = &self.secret {
+            if secret.len() != self.len {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+        let inputs = match self.digest() {
+            Some(digest) => digest_inputs(&digest).into_iter().map(Some).collect(),
+            None => vec![None; 2],
+        };
+        let inputs = inputs
+            .into_iter()
+            .map(|input| Var::input(&cs, input))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut message = constant_bits(&self.prefix);
+        message.extend(witness_bits(&cs, self.secret.as_deref(), self.len)?);
+        let digest = blake2b256_gadget(&cs, &message)?;
+        for (half, input) in digest.chunks(DIGEST_LEN * 4).zip(inputs) {
+            let mut packed = Lc::zero();
+            let mut weight = F::one();
+            for bit in half {
+                packed = packed + &bit.lc().scale(weight);
+                weight.double_in_place();
+            }
+            packed.enforce_equal(&cs, &Lc::from(input))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use super::*;
+
+    fn digest_gadget(message: &[u8]) -> [u8; DIGEST_LEN] {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let bits = witness_bits(&cs, Some(message), message.len()).unwrap();
+        let digest = blake2b256_gadget(&cs, &bits).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+        let mut bytes = [0u8; DIGEST_LEN];
+        for (i, bit) in digest.iter().enumerate() {
+            bytes[i / 8] |= (bit.value().unwrap() as u8) << (i % 8);
+        }
+        bytes
+    }
+
+    #[test]
+    fn gadget_matches_blake2b() {
+        assert_eq!(
+            hex::encode(blake2b256(b"")),
+            "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8"
+        );
+        assert_eq!(
+            hex::encode(blake2b256(b"abc")),
+            "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+        );
+        assert_eq!(digest_gadget(b""), blake2b256(b""));
+        assert_eq!(digest_gadget(b"abc"), blake2b256(b"abc"));
+        // the last byte of a block and the first byte of the next one
+        for len in [127, 128, 129] {
+            let message = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+            assert_eq!(digest_gadget(&message), blake2b256(&message));
+        }
+    }
+
+    #[test]
+    fn preimage_of_account_hash() {
+        let public_key = vec![7u8; 32];
+        let circuit = Blake2bPreimage::account_hash("ed25519", 32, Some(public_key.clone()));
+        let digest = blake2b256(&[b"ed25519\0".as_ref(), &public_key].concat());
+        assert_eq!(circuit.digest(), Some(digest));
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        circuit.generate_constraints(cs.clone()).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+        let inputs = cs.borrow().unwrap().instance_assignment[1..].to_vec();
+        assert_eq!(inputs, digest_inputs::<Fr>(&digest));
+        // the prefix costs nothing, the rest of the block is mostly additions
+        assert_eq!(cs.num_constraints(), 49_587);
+
+        // nor is any other digest
+        cs.borrow_mut().unwrap().instance_assignment[2] += Fr::from(1u64);
+        assert!(!cs.is_satisfied().unwrap());
+    }
+}
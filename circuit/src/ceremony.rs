@@ -0,0 +1,429 @@
+//! Phase-2 trusted setup ceremony.
+//!
+//! Keys generated from a known seed are forgeable, anyone who can recompute `delta` can create
+//! proofs of false statements. In the ceremony every participant multiplies `delta` by a secret
+//! of their own and divides the queries depending on it, `h_query` and `l_query`, by the same
+//! secret. A participant publishes `s`, `s * secret` and `r * secret`, where `r` is hashed from
+//! the transcript so far, which lets anyone check that the new `delta` is the old one times the
+//! same secret without learning it. The ceremony ends with a contribution derived from a public
+//! random beacon.
+//!
+//! The ceremony starts from the phase-1 parameters passed to [`Ceremony::initialize`] and only
+//! changes `delta`. Whoever knows their secrets `tau`, `alpha` and `beta` can forge proofs
+//! whatever `delta` is, so the keys are no more trustworthy than the party or the phase-1
+//! ceremony that produced them.
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use ark_bn254::{Bn254, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::msm::VariableBaseMSM;
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, PrimeField, UniformRand, Zero};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::rand::{Rng, SeedableRng};
+use blake2::{Blake2b, Digest};
+use rand_chacha::ChaCha20Rng;
+
+use crate::keys::{read_key, write_key, KeyFileError, KeyKind};
+
+/// A public random value the last secret is derived from, hashed `iterations` times.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Beacon {
+    pub value: Vec<u8>,
+    pub iterations: u64,
+}
+
+impl Beacon {
+    fn rng(&self) -> ChaCha20Rng {
+        let mut digest = self.value.clone();
+        for _ in 0..self.iterations {
+            digest = hash(&[&digest]);
+        }
+        rng_from(&digest)
+    }
+
+    /// The secret of the contribution, which anyone can recompute.
+    pub fn secret(&self) -> Fr {
+        secret(&mut self.rng())
+    }
+}
+
+/// The public part of a contribution.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Contribution {
+    /// The hash of the ceremony before this contribution.
+    pub transcript: Vec<u8>,
+    /// `delta` in G1 after this contribution.
+    pub delta_after: G1Affine,
+    pub s: G1Affine,
+    pub s_delta: G1Affine,
+    /// `r * secret`, `r` is hashed from the transcript, `s` and `s_delta`.
+    pub r_delta: G2Affine,
+    /// The beacon the secret was derived from, set for the final contribution.
+    pub beacon: Option<Beacon>,
+}
+
+impl Contribution {
+    /// The hash a participant checks their contribution is part of the ceremony with.
+    pub fn hash(&self) -> Vec<u8> {
+        hash(&[&serialize(self)])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CeremonyError {
+    /// The ceremony ended with a beacon, it takes no more contributions.
+    Finalized,
+    /// The ceremony hasn't been finalized with a beacon yet.
+    NotFinalized,
+    /// Contribution `k` doesn't follow the transcript before it.
+    TranscriptMismatch(usize),
+    /// The secret of contribution `k` isn't the one `delta` was multiplied by.
+    InvalidContribution(usize),
+    /// The secret of contribution `k` isn't derived from its beacon.
+    InvalidBeacon(usize),
+    /// The parameters don't follow from the initial ones and the contributions.
+    InvalidParameters,
+}
+
+impl fmt::Display for CeremonyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CeremonyError::Finalized => write!(f, "the ceremony is finalized"),
+            CeremonyError::NotFinalized => write!(f, "the ceremony isn't finalized"),
+            CeremonyError::TranscriptMismatch(k) => {
+                write!(f, "contribution {} doesn't follow the transcript", k)
+            }
+            CeremonyError::InvalidContribution(k) => write!(f, "contribution {} is invalid", k),
+            CeremonyError::InvalidBeacon(k) => {
+                write!(f, "contribution {} isn't derived from its beacon", k)
+            }
+            CeremonyError::InvalidParameters => {
+                write!(f, "the parameters don't match the contributions")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CeremonyError {}
+
+/// The transcript of a ceremony together with the current parameters.
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Ceremony {
+    pub circuit_id: String,
+    initial: ProvingKey<Bn254>,
+    params: ProvingKey<Bn254>,
+    contributions: Vec<Contribution>,
+}
+
+impl Ceremony {
+    /// Starts a ceremony from the phase-1 parameters of the circuit `circuit_id`.
+    pub fn initialize(circuit_id: &str, params: ProvingKey<Bn254>) -> Ceremony {
+        Ceremony {
+            circuit_id: circuit_id.to_string(),
+            initial: params.clone(),
+            params,
+            contributions: vec![],
+        }
+    }
+
+    pub fn contributions(&self) -> &[Contribution] {
+        &self.contributions
+    }
+
+    pub fn is_finalized(&self) -> bool {
+        matches!(
+            self.contributions.last(),
+            Some(Contribution {
+                beacon: Some(_),
+                ..
+            })
+        )
+    }
+
+    /// Contributes a secret drawn from `rng` and returns the hash of the contribution.
+    ///
+    /// The secret isn't kept, `rng` has to be a cryptographically secure generator.
+    pub fn contribute<R: Rng>(&mut self, rng: &mut R) -> Result<Vec<u8>, CeremonyError> {
+        if self.is_finalized() {
+            return Err(CeremonyError::Finalized);
+        }
+        let secret = secret(rng);
+        Ok(self.apply(secret, rng, None))
+    }
+
+    /// Ends the ceremony with a contribution derived from `beacon` hashed `iterations` times,
+    /// e.g. a future block hash, so the last contribution can't be chosen by a participant.
+    pub fn finalize(&mut self, beacon: &[u8], iterations: u64) -> Result<Vec<u8>, CeremonyError> {
+        if self.is_finalized() {
+            return Err(CeremonyError::Finalized);
+        }
+        let beacon = Beacon {
+            value: beacon.to_vec(),
+            iterations,
+        };
+        let mut rng = beacon.rng();
+        let secret = secret(&mut rng);
+        Ok(self.apply(secret, &mut rng, Some(beacon)))
+    }
+
+    fn apply<R: Rng>(&mut self, secret: Fr, rng: &mut R, beacon: Option<Beacon>) -> Vec<u8> {
+        let transcript = self.transcript();
+        let s = G1Projective::rand(rng).into_affine();
+        let s_delta = s.mul(secret).into_affine();
+        let r = hash_to_g2(&transcript, &s, &s_delta);
+        let r_delta = r.mul(secret).into_affine();
+
+        let inverse = secret.inverse().unwrap();
+        let params = &mut self.params;
+        params.delta_g1 = params.delta_g1.mul(secret).into_affine();
+        params.vk.delta_g2 = params.vk.delta_g2.mul(secret).into_affine();
+        params.h_query = scale(&params.h_query, inverse);
+        params.l_query = scale(&params.l_query, inverse);
+
+        let contribution = Contribution {
+            transcript,
+            delta_after: params.delta_g1,
+            s,
+            s_delta,
+            r_delta,
+            beacon,
+        };
+        let hash = contribution.hash();
+        self.contributions.push(contribution);
+        hash
+    }
+
+    fn initial_transcript(&self) -> Vec<u8> {
+        hash(&[self.circuit_id.as_bytes(), &serialize(&self.initial)])
+    }
+
+    /// The hash of the ceremony so far, the next contribution builds on it.
+    pub fn transcript(&self) -> Vec<u8> {
+        self.contributions
+            .iter()
+            .fold(self.initial_transcript(), |transcript, contribution| {
+                next_transcript(&transcript, contribution)
+            })
+    }
+
+    /// Checks every contribution and the parameters they lead to, returning the hashes of the
+    /// contributions in order.
+    pub fn verify(&self) -> Result<Vec<Vec<u8>>, CeremonyError> {
+        let mut transcript = self.initial_transcript();
+        let mut delta = self.initial.delta_g1;
+        let mut hashes = vec![];
+        let last = self.contributions.len().saturating_sub(1);
+        for (k, contribution) in self.contributions.iter().enumerate() {
+            if contribution.transcript != transcript {
+                return Err(CeremonyError::TranscriptMismatch(k));
+            }
+            let r = hash_to_g2(&transcript, &contribution.s, &contribution.s_delta);
+            let valid = !contribution.s.is_zero()
+                && !contribution.s_delta.is_zero()
+                && !contribution.r_delta.is_zero()
+                && same_ratio(
+                    (contribution.s, contribution.s_delta),
+                    (r, contribution.r_delta),
+                )
+                && same_ratio((delta, contribution.delta_after), (r, contribution.r_delta));
+            if !valid || (contribution.beacon.is_some() && k != last) {
+                return Err(CeremonyError::InvalidContribution(k));
+            }
+            if let Some(beacon) = &contribution.beacon {
+                if delta.mul(beacon.secret()).into_affine() != contribution.delta_after {
+                    return Err(CeremonyError::InvalidBeacon(k));
+                }
+            }
+            delta = contribution.delta_after;
+            transcript = next_transcript(&transcript, contribution);
+            hashes.push(contribution.hash());
+        }
+
+        if self.params.delta_g1 != delta || !self.follows_initial() {
+            return Err(CeremonyError::InvalidParameters);
+        }
+        Ok(hashes)
+    }
+
+    /// Checks that the parameters are the initial ones with another `delta`.
+    fn follows_initial(&self) -> bool {
+        let (initial, params) = (&self.initial, &self.params);
+        let unchanged = params.vk.alpha_g1 == initial.vk.alpha_g1
+            && params.vk.beta_g2 == initial.vk.beta_g2
+            && params.vk.gamma_g2 == initial.vk.gamma_g2
+            && params.vk.gamma_abc_g1 == initial.vk.gamma_abc_g1
+            && params.beta_g1 == initial.beta_g1
+            && params.a_query == initial.a_query
+            && params.b_g1_query == initial.b_g1_query
+            && params.b_g2_query == initial.b_g2_query
+            && params.h_query.len() == initial.h_query.len()
+            && params.l_query.len() == initial.l_query.len();
+        if !unchanged {
+            return false;
+        }
+
+        // h_query and l_query are divided by the same factor delta is multiplied by, checked
+        // on random linear combinations of the queries
+        let mut rng = rng_from(&hash(&[&serialize(initial), &serialize(params)]));
+        let h = merge(&initial.h_query, &params.h_query, &mut rng);
+        let l = merge(&initial.l_query, &params.l_query, &mut rng);
+        same_ratio(
+            (initial.delta_g1, params.delta_g1),
+            (initial.vk.delta_g2, params.vk.delta_g2),
+        ) && same_ratio(h, (params.vk.delta_g2, initial.vk.delta_g2))
+            && same_ratio(l, (params.vk.delta_g2, initial.vk.delta_g2))
+    }
+
+    /// The keys produced by a finalized ceremony, after verifying it.
+    pub fn keys(&self) -> Result<(ProvingKey<Bn254>, VerifyingKey<Bn254>), CeremonyError> {
+        if !self.is_finalized() {
+            return Err(CeremonyError::NotFinalized);
+        }
+        self.verify()?;
+        Ok((self.params.clone(), self.params.vk.clone()))
+    }
+
+    /// Writes the ceremony to a key file.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), KeyFileError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_key(&mut writer, KeyKind::Ceremony, &self.circuit_id, self)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Reads the ceremony of `circuit_id` from a key file.
+    pub fn load<P: AsRef<Path>>(path: P, circuit_id: &str) -> Result<Ceremony, KeyFileError> {
+        let reader = BufReader::new(File::open(path)?);
+        read_key(reader, KeyKind::Ceremony, circuit_id)
+    }
+}
+
+fn hash(parts: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = Blake2b::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().to_vec()
+}
+
+fn serialize<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+    let mut dst = vec![];
+    value.serialize_uncompressed(&mut dst).unwrap();
+    dst
+}
+
+fn next_transcript(transcript: &[u8], contribution: &Contribution) -> Vec<u8> {
+    hash(&[transcript, &serialize(contribution)])
+}
+
+fn rng_from(digest: &[u8]) -> ChaCha20Rng {
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&digest[..32]);
+    ChaCha20Rng::from_seed(seed)
+}
+
+fn secret<R: Rng>(rng: &mut R) -> Fr {
+    loop {
+        let secret = Fr::rand(rng);
+        if !secret.is_zero() {
+            return secret;
+        }
+    }
+}
+
+/// A point of G2 nobody knows the discrete logarithm of, derived from the transcript and the
+/// contribution's `s` and `s_delta`.
+fn hash_to_g2(transcript: &[u8], s: &G1Affine, s_delta: &G1Affine) -> G2Affine {
+    let digest = hash(&[transcript, &serialize(s), &serialize(s_delta)]);
+    G2Projective::rand(&mut rng_from(&digest)).into_affine()
+}
+
+fn scale(points: &[G1Affine], factor: Fr) -> Vec<G1Affine> {
+    let mut points = points
+        .iter()
+        .map(|point| point.mul(factor))
+        .collect::<Vec<_>>();
+    G1Projective::batch_normalization(&mut points);
+    points.into_iter().map(Into::into).collect()
+}
+
+/// Folds both vectors with the same random coefficients.
+fn merge<R: Rng>(a: &[G1Affine], b: &[G1Affine], rng: &mut R) -> (G1Affine, G1Affine) {
+    let coefficients = (0..a.len())
+        .map(|_| Fr::rand(rng).into_repr())
+        .collect::<Vec<_>>();
+    (
+        VariableBaseMSM::multi_scalar_mul(a, &coefficients).into_affine(),
+        VariableBaseMSM::multi_scalar_mul(b, &coefficients).into_affine(),
+    )
+}
+
+/// Checks `b / a == d / c`, i.e. `e(a, d) == e(b, c)`.
+fn same_ratio(g1: (G1Affine, G1Affine), g2: (G2Affine, G2Affine)) -> bool {
+    Bn254::pairing(g1.0, g2.1) == Bn254::pairing(g1.1, g2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{demo_constants, export_for_contract, prove, setup, Circuit, DEMO_CIRCUIT_ID};
+    use ark_groth16::{prepare_verifying_key, verify_proof};
+
+    #[test]
+    fn ceremony_produces_sound_keys() {
+        let rng = &mut ChaCha20Rng::from_seed([7u8; 32]);
+        let constants = demo_constants();
+        let circuit = Circuit::<Fr> {
+            xl: None,
+            xr: None,
+            constants: &constants,
+        };
+        let (params, _) = setup(circuit, rng).unwrap();
+        let mut ceremony = Ceremony::initialize(DEMO_CIRCUIT_ID, params);
+        assert_eq!(ceremony.keys(), Err(CeremonyError::NotFinalized));
+
+        let first = ceremony.contribute(rng).unwrap();
+        let second = ceremony.contribute(rng).unwrap();
+        ceremony.finalize(b"block hash", 16).unwrap();
+        assert_eq!(ceremony.contribute(rng), Err(CeremonyError::Finalized));
+        assert_eq!(ceremony.verify().unwrap()[..2], [first, second]);
+
+        let (pk, vk) = ceremony.keys().unwrap();
+        assert_ne!(vk.delta_g2, ceremony.initial.vk.delta_g2);
+        let circuit = Circuit {
+            xl: Some(Fr::from(1u64)),
+            xr: Some(Fr::from(2u64)),
+            constants: &constants,
+        };
+        let bundle = prove(circuit, &pk, rng).unwrap();
+        assert!(verify_proof(
+            &prepare_verifying_key(&vk),
+            &bundle.proof,
+            &bundle.public_inputs
+        )
+        .unwrap());
+        export_for_contract(&bundle, &vk).unwrap();
+
+        // a contribution that scales delta by another secret than the one it proves
+        let mut forged = ceremony.clone();
+        forged.contributions[1].delta_after = forged.contributions[1]
+            .delta_after
+            .mul(Fr::from(2u64))
+            .into_affine();
+        assert_eq!(forged.verify(), Err(CeremonyError::InvalidContribution(1)));
+
+        // parameters that don't follow the contributions
+        let mut forged = ceremony.clone();
+        forged.params.h_query[0] = forged.params.h_query[1];
+        assert_eq!(forged.verify(), Err(CeremonyError::InvalidParameters));
+
+        // a beacon contribution with a secret of its own
+        let mut forged = ceremony;
+        forged.contributions[2].beacon.as_mut().unwrap().iterations = 15;
+        assert_eq!(forged.verify(), Err(CeremonyError::InvalidBeacon(2)));
+    }
+}
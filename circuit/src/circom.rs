@@ -0,0 +1,463 @@
+//! Circuits compiled by circom.
+//!
+//! [`R1cs`] reads the binary `.r1cs` format written by `circom --r1cs` and [`read_witness`] the
+//! `.wtns` format written by the witness generators. Both store field elements as little-endian
+//! integers in normal form. [`CircomCircuit`] turns them into `ark_relations` constraints over
+//! `ark_bn254::Fr`, so a circom circuit can be set up and proven with [`crate::setup`] and
+//! [`crate::prove`].
+//!
+//! Wire 0 is the constant one, followed by the public outputs, the public inputs and then the
+//! private wires, so the public inputs of a proof are the outputs followed by the public inputs.
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+
+use ark_bn254::Fr;
+use ark_ff::{BigInteger, FpParameters, FromBytes, PrimeField};
+use ark_relations::lc;
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystemRef, LinearCombination, SynthesisError, Variable,
+};
+
+const R1CS_MAGIC: [u8; 4] = *b"r1cs";
+const WTNS_MAGIC: [u8; 4] = *b"wtns";
+const R1CS_HEADER: u32 = 1;
+const R1CS_CONSTRAINTS: u32 = 2;
+const WTNS_HEADER: u32 = 1;
+const WTNS_VALUES: u32 = 2;
+/// Bytes of an element of `Fr`.
+const FIELD_SIZE: usize = 32;
+
+#[derive(Debug)]
+pub enum CircomError {
+    Io(io::Error),
+    /// The file doesn't start with the magic bytes of its format.
+    NotACircomFile,
+    UnsupportedVersion(u32),
+    /// The circuit is defined over another field than the scalar field of BN254.
+    WrongField,
+    MissingSection(u32),
+    /// A wire index, field element or length is out of range.
+    Malformed,
+    /// The circuit uses custom gates, which only PLONK can prove.
+    CustomGates,
+}
+
+impl fmt::Display for CircomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircomError::Io(error) => write!(f, "{}", error),
+            CircomError::NotACircomFile => write!(f, "not a circom file"),
+            CircomError::UnsupportedVersion(version) => {
+                write!(f, "unsupported version {}", version)
+            }
+            CircomError::WrongField => write!(f, "the circuit isn't defined over BN254"),
+            CircomError::MissingSection(section) => write!(f, "missing section {}", section),
+            CircomError::Malformed => write!(f, "malformed file"),
+            CircomError::CustomGates => write!(f, "custom gates aren't supported"),
+        }
+    }
+}
+
+impl std::error::Error for CircomError {}
+
+impl From<io::Error> for CircomError {
+    fn from(error: io::Error) -> Self {
+        CircomError::Io(error)
+    }
+}
+
+/// A linear combination, as pairs of a wire and its coefficient.
+pub type Terms = Vec<(usize, Fr)>;
+
+/// A constraint `a * b = c`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constraint {
+    pub a: Terms,
+    pub b: Terms,
+    pub c: Terms,
+}
+
+/// The constraint system of a circom circuit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct R1cs {
+    pub num_wires: usize,
+    pub num_public_outputs: usize,
+    pub num_public_inputs: usize,
+    pub num_private_inputs: usize,
+    pub constraints: Vec<Constraint>,
+}
+
+impl R1cs {
+    /// The number of public inputs of a proof, the outputs followed by the public inputs.
+    pub fn num_public(&self) -> usize {
+        self.num_public_outputs + self.num_public_inputs
+    }
+
+    pub fn read<R: Read>(reader: R) -> Result<R1cs, CircomError> {
+        let mut sections = read_sections(reader, R1CS_MAGIC, &[1])?;
+        if sections.contains_key(&4) || sections.contains_key(&5) {
+            return Err(CircomError::CustomGates);
+        }
+
+        let header = sections
+            .remove(&R1CS_HEADER)
+            .ok_or(CircomError::MissingSection(R1CS_HEADER))?;
+        let mut header = Cursor(&header);
+        read_prime(&mut header)?;
+        let num_wires = header.u32()? as usize;
+        let num_public_outputs = header.u32()? as usize;
+        let num_public_inputs = header.u32()? as usize;
+        let num_private_inputs = header.u32()? as usize;
+        let _num_labels = header.u64()?;
+        let num_constraints = header.u32()? as usize;
+        if num_wires == 0 || 1 + num_public_outputs + num_public_inputs > num_wires {
+            return Err(CircomError::Malformed);
+        }
+
+        let constraints = sections
+            .remove(&R1CS_CONSTRAINTS)
+            .ok_or(CircomError::MissingSection(R1CS_CONSTRAINTS))?;
+        let mut src = Cursor(&constraints);
+        let mut read_terms = || -> Result<Terms, CircomError> {
+            let len = src.u32()? as usize;
+            let mut terms = Vec::with_capacity(len.min(num_wires));
+            for _ in 0..len {
+                let wire = src.u32()? as usize;
+                if wire >= num_wires {
+                    return Err(CircomError::Malformed);
+                }
+                terms.push((wire, src.field()?));
+            }
+            Ok(terms)
+        };
+        let mut constraints = Vec::with_capacity(num_constraints.min(constraints.len()));
+        for _ in 0..num_constraints {
+            constraints.push(Constraint {
+                a: read_terms()?,
+                b: read_terms()?,
+                c: read_terms()?,
+            });
+        }
+
+        Ok(R1cs {
+            num_wires,
+            num_public_outputs,
+            num_public_inputs,
+            num_private_inputs,
+            constraints,
+        })
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<R1cs, CircomError> {
+        R1cs::read(BufReader::new(File::open(path)?))
+    }
+}
+
+/// Reads the assignment of every wire from a `.wtns` file.
+pub fn read_witness<R: Read>(reader: R) -> Result<Vec<Fr>, CircomError> {
+    let mut sections = read_sections(reader, WTNS_MAGIC, &[1, 2])?;
+    let header = sections
+        .remove(&WTNS_HEADER)
+        .ok_or(CircomError::MissingSection(WTNS_HEADER))?;
+    let mut header = Cursor(&header);
+    read_prime(&mut header)?;
+    let len = header.u32()? as usize;
+
+    let values = sections
+        .remove(&WTNS_VALUES)
+        .ok_or(CircomError::MissingSection(WTNS_VALUES))?;
+    if values.len() != len * FIELD_SIZE {
+        return Err(CircomError::Malformed);
+    }
+    let mut src = Cursor(&values);
+    (0..len).map(|_| src.field()).collect()
+}
+
+pub fn load_witness<P: AsRef<Path>>(path: P) -> Result<Vec<Fr>, CircomError> {
+    read_witness(BufReader::new(File::open(path)?))
+}
+
+/// A circom circuit, with the assignment of its wires when proving.
+#[derive(Clone, Debug)]
+pub struct CircomCircuit {
+    pub r1cs: R1cs,
+    pub witness: Option<Vec<Fr>>,
+}
+
+impl CircomCircuit {
+    /// The public inputs of the witness, the outputs followed by the public inputs.
+    pub fn public_inputs(&self) -> Option<Vec<Fr>> {
+        let witness = self.witness.as_ref()?;
+        Some(witness.get(1..=self.r1cs.num_public())?.to_vec())
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for CircomCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let r1cs = self.r1cs;
+        let witness = self.witness;
+        if let Some(witness) = &witness {
+            if witness.len() != r1cs.num_wires || witness[0] != Fr::from(1u64) {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+        let value = |wire: usize| {
+            witness
+                .as_ref()
+                .map(|witness| witness[wire])
+                .ok_or(SynthesisError::AssignmentMissing)
+        };
+
+        let mut variables = vec![Variable::One];
+        for wire in 1..r1cs.num_wires {
+            let variable = if wire <= r1cs.num_public() {
+                cs.new_input_variable(|| value(wire))?
+            } else {
+                cs.new_witness_variable(|| value(wire))?
+            };
+            variables.push(variable);
+        }
+
+        let combination = |terms: &Terms| {
+            terms
+                .iter()
+                .fold(lc!(), |lc: LinearCombination<Fr>, (wire, coefficient)| {
+                    lc + (*coefficient, variables[*wire])
+                })
+        };
+        for constraint in &r1cs.constraints {
+            cs.enforce_constraint(
+                combination(&constraint.a),
+                combination(&constraint.b),
+                combination(&constraint.c),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the sections of a circom file by type, checking its magic bytes and version.
+fn read_sections<R: Read>(
+    mut reader: R,
+    magic: [u8; 4],
+    versions: &[u32],
+) -> Result<HashMap<u32, Vec<u8>>, CircomError> {
+    let mut src = vec![];
+    reader.read_to_end(&mut src)?;
+    let mut src = Cursor(&src);
+    if src.bytes(4)? != magic {
+        return Err(CircomError::NotACircomFile);
+    }
+    let version = src.u32()?;
+    if !versions.contains(&version) {
+        return Err(CircomError::UnsupportedVersion(version));
+    }
+    let num_sections = src.u32()?;
+    let mut sections = HashMap::new();
+    for _ in 0..num_sections {
+        let section = src.u32()?;
+        let len = usize::try_from(src.u64()?).map_err(|_| CircomError::Malformed)?;
+        sections.insert(section, src.bytes(len)?.to_vec());
+    }
+    Ok(sections)
+}
+
+/// Checks the field of a header is `Fr`.
+fn read_prime(src: &mut Cursor) -> Result<(), CircomError> {
+    if src.u32()? as usize != FIELD_SIZE {
+        return Err(CircomError::WrongField);
+    }
+    let modulus = <Fr as PrimeField>::Params::MODULUS.to_bytes_le();
+    if src.bytes(FIELD_SIZE)? != modulus.as_slice() {
+        return Err(CircomError::WrongField);
+    }
+    Ok(())
+}
+
+struct Cursor<'a>(&'a [u8]);
+
+impl<'a> Cursor<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], CircomError> {
+        if self.0.len() < len {
+            return Err(CircomError::Malformed);
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, CircomError> {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(self.bytes(4)?);
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn u64(&mut self) -> Result<u64, CircomError> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.bytes(8)?);
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// A field element in normal form, refusing values that aren't reduced.
+    fn field(&mut self) -> Result<Fr, CircomError> {
+        let repr = <Fr as PrimeField>::BigInt::read(self.bytes(FIELD_SIZE)?)?;
+        Fr::from_repr(repr).ok_or(CircomError::Malformed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_groth16::{prepare_verifying_key, verify_proof};
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{prove, setup};
+
+    fn section(dst: &mut Vec<u8>, section: u32, data: &[u8]) {
+        dst.extend(&section.to_le_bytes());
+        dst.extend(&(data.len() as u64).to_le_bytes());
+        dst.extend(data);
+    }
+
+    fn prime() -> Vec<u8> {
+        let mut dst = (FIELD_SIZE as u32).to_le_bytes().to_vec();
+        dst.extend(<Fr as PrimeField>::Params::MODULUS.to_bytes_le());
+        dst
+    }
+
+    fn field(value: u64) -> Vec<u8> {
+        Fr::from(value).into_repr().to_bytes_le()
+    }
+
+    /// `out = a * b + 3` with a public output, a public input `a` and a private input `b`.
+    fn r1cs() -> Vec<u8> {
+        let mut header = prime();
+        for value in [5u32, 1, 1, 1] {
+            header.extend(&value.to_le_bytes());
+        }
+        header.extend(&0u64.to_le_bytes());
+        header.extend(&2u32.to_le_bytes());
+
+        let mut constraints = vec![];
+        let mut terms = |terms: &[(u32, u64)]| {
+            constraints.extend(&(terms.len() as u32).to_le_bytes());
+            for (wire, coefficient) in terms {
+                constraints.extend(&wire.to_le_bytes());
+                constraints.extend(field(*coefficient));
+            }
+        };
+        // a * b = t
+        terms(&[(2, 1)]);
+        terms(&[(3, 1)]);
+        terms(&[(4, 1)]);
+        // (t + 3) * 1 = out
+        terms(&[(4, 1), (0, 3)]);
+        terms(&[(0, 1)]);
+        terms(&[(1, 1)]);
+
+        let mut dst = b"r1cs".to_vec();
+        dst.extend(&1u32.to_le_bytes());
+        dst.extend(&2u32.to_le_bytes());
+        section(&mut dst, 2, &constraints);
+        section(&mut dst, 1, &header);
+        dst
+    }
+
+    fn wtns(values: &[u64]) -> Vec<u8> {
+        let mut header = prime();
+        header.extend(&(values.len() as u32).to_le_bytes());
+        let values = values
+            .iter()
+            .flat_map(|value| field(*value))
+            .collect::<Vec<_>>();
+
+        let mut dst = b"wtns".to_vec();
+        dst.extend(&2u32.to_le_bytes());
+        dst.extend(&2u32.to_le_bytes());
+        section(&mut dst, 1, &header);
+        section(&mut dst, 2, &values);
+        dst
+    }
+
+    #[test]
+    fn circom_circuit_is_proven() {
+        let r1cs = R1cs::read(r1cs().as_slice()).unwrap();
+        assert_eq!(r1cs.num_public(), 2);
+        assert_eq!(r1cs.constraints.len(), 2);
+        let witness = read_witness(wtns(&[1, 23, 4, 5, 20]).as_slice()).unwrap();
+
+        let rng = &mut ChaCha20Rng::from_seed([1u8; 32]);
+        let (pk, vk) = setup(
+            CircomCircuit {
+                r1cs: r1cs.clone(),
+                witness: None,
+            },
+            rng,
+        )
+        .unwrap();
+        let circuit = CircomCircuit {
+            r1cs: r1cs.clone(),
+            witness: Some(witness),
+        };
+        assert_eq!(
+            circuit.public_inputs(),
+            Some(vec![Fr::from(23u64), Fr::from(4u64)])
+        );
+        let bundle = prove(circuit, &pk, rng).unwrap();
+        assert_eq!(bundle.public_inputs, vec![Fr::from(23u64), Fr::from(4u64)]);
+        assert!(verify_proof(
+            &prepare_verifying_key(&vk),
+            &bundle.proof,
+            &bundle.public_inputs
+        )
+        .unwrap());
+
+        let witness = read_witness(wtns(&[1, 24, 4, 5, 20]).as_slice()).unwrap();
+        let circuit = CircomCircuit {
+            r1cs,
+            witness: Some(witness),
+        };
+        assert!(prove(circuit, &pk, rng).is_err());
+    }
+
+    #[test]
+    fn malformed_files_are_refused() {
+        let mut src = r1cs();
+        src[0] = b'x';
+        assert!(matches!(
+            R1cs::read(src.as_slice()),
+            Err(CircomError::NotACircomFile)
+        ));
+
+        let src = r1cs();
+        assert!(matches!(
+            R1cs::read(&src[..src.len() - 1]),
+            Err(CircomError::Malformed)
+        ));
+
+        let mut src = r1cs();
+        let modulus = <Fr as PrimeField>::Params::MODULUS.to_bytes_le();
+        let prime = src
+            .windows(FIELD_SIZE)
+            .position(|window| window == modulus.as_slice())
+            .unwrap();
+        src[prime] ^= 1;
+        assert!(matches!(
+            R1cs::read(src.as_slice()),
+            Err(CircomError::WrongField)
+        ));
+
+        let mut src = wtns(&[1, 2]);
+        let last = src.len() - 1;
+        src[last] = 0xff;
+        assert!(matches!(
+            read_witness(src.as_slice()),
+            Err(CircomError::Malformed)
+        ));
+    }
+}
@@ -1,11 +1,15 @@
-use ark_ff::Field;
+use ark_ff::{Field, PrimeField};
 use ark_relations::{
-    lc, ns,
-    r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError, Variable},
+    ns,
+    r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
 };
 
+use crate::gadget::{pow_add, Lc, Var};
+
 pub const MIMC_ROUNDS: usize = 322;
 
+/// The MiMC Feistel permutation of the demo circuit, with exponent 3, no key and constants drawn
+/// from the demo seed. See [`crate::mimc::Mimc`] for the circomlib-compatible family.
 pub fn mimc<F: Field>(mut xl: F, mut xr: F, constants: &[F]) -> F {
     assert_eq!(constants.len(), MIMC_ROUNDS);
 
@@ -23,77 +27,33 @@ pub fn mimc<F: Field>(mut xl: F, mut xr: F, constants: &[F]) -> F {
     xl
 }
 
+#[derive(Clone)]
 pub struct Circuit<'a, F: Field> {
     pub xl: Option<F>,
     pub xr: Option<F>,
     pub constants: &'a [F],
 }
 
-impl<'a, F: Field> ConstraintSynthesizer<F> for Circuit<'a, F> {
+impl<'a, F: PrimeField> ConstraintSynthesizer<F> for Circuit<'a, F> {
     fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
         assert_eq!(self.constants.len(), MIMC_ROUNDS);
 
-        // Allocate the first component of the preimage.
-        let mut xl_value = self.xl;
-        let mut xl =
-            cs.new_witness_variable(|| xl_value.ok_or(SynthesisError::AssignmentMissing))?;
-
-        // Allocate the second component of the preimage.
-        let mut xr_value = self.xr;
-        let mut xr =
-            cs.new_witness_variable(|| xr_value.ok_or(SynthesisError::AssignmentMissing))?;
+        // Allocate the preimage.
+        let mut xl = Var::witness(&cs, self.xl)?;
+        let mut xr = Var::witness(&cs, self.xr)?;
 
         for i in 0..MIMC_ROUNDS {
             // xL, xR := xR + (xL + Ci)^3, xL
             let ns = ns!(cs, "round");
             let cs = ns.cs();
 
-            // tmp = (xL + Ci)^2
-            let tmp_value = xl_value.map(|mut e| {
-                e.add_assign(&self.constants[i]);
-                e.square_in_place();
-                e
-            });
-            let tmp =
-                cs.new_witness_variable(|| tmp_value.ok_or(SynthesisError::AssignmentMissing))?;
-
-            cs.enforce_constraint(
-                lc!() + xl + (self.constants[i], Variable::One),
-                lc!() + xl + (self.constants[i], Variable::One),
-                lc!() + tmp,
-            )?;
-
-            // new_xL = xR + (xL + Ci)^3
-            // new_xL = xR + tmp * (xL + Ci)
-            // new_xL - xR = tmp * (xL + Ci)
-            let new_xl_value = xl_value.map(|mut e| {
-                e.add_assign(&self.constants[i]);
-                e.mul_assign(&tmp_value.unwrap());
-                e.add_assign(&xr_value.unwrap());
-                e
-            });
+            // This is the last round, xL is our image and so
+            // we allocate a public input.
+            let t = Lc::from(xl).add_constant(self.constants[i]);
+            let new_xl = pow_add(&cs, &t, 3, &Lc::from(xr), i == MIMC_ROUNDS - 1)?;
 
-            let new_xl = if i == (MIMC_ROUNDS - 1) {
-                // This is the last round, xL is our image and so
-                // we allocate a public input.
-                cs.new_input_variable(|| new_xl_value.ok_or(SynthesisError::AssignmentMissing))?
-            } else {
-                cs.new_witness_variable(|| new_xl_value.ok_or(SynthesisError::AssignmentMissing))?
-            };
-
-            cs.enforce_constraint(
-                lc!() + tmp,
-                lc!() + xl + (self.constants[i], Variable::One),
-                lc!() + new_xl - xr,
-            )?;
-
-            // xR = xL
             xr = xl;
-            xr_value = xl_value;
-
-            // xL = new_xL
             xl = new_xl;
-            xl_value = new_xl_value;
         }
 
         Ok(())
@@ -0,0 +1,322 @@
+//! EdDSA signatures over BabyJubJub with a Poseidon challenge, as circomlib's `EdDSAPoseidon`.
+//!
+//! A signature of the field element `m` by the key `A` is a point `R8` and a scalar `S` below
+//! the subgroup order `l` such that `S B8 = R8 + 8 h A`, where `B8` is [`Point::base8`] and
+//! `h = Poseidon(R8, A, m)`. [`EddsaPoseidonVerifier`] checks it in a circuit, so a holder of a
+//! signed attestation proves that an authority signed it without revealing the signature.
+//!
+//! Keys and signatures are derived as circomlibjs' `signPoseidon` does, except that BLAKE2b-512
+//! replaces BLAKE-512, so [`SecretKey`] is incompatible with circomlibjs: the same secret gives
+//! another public key and other signatures, and secrets can't be moved from one to the other.
+//! Only the verification is shared, [`verify`] and [`EddsaPoseidonVerifier`] never hash the
+//! secret and accept a signature made by circomlibjs under the public key circomlibjs derived.
+use std::convert::TryInto;
+
+use ark_bn254::Fr;
+use ark_ff::{to_bytes, Field, FpParameters, One, PrimeField};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_std::rand::Rng;
+use blake2::{Blake2b, Digest};
+use num_bigint::BigUint;
+
+use crate::babyjubjub::{fixed_base_mul, scalar_bits, subgroup_order, to_biguint, Point, PointVar};
+use crate::gadget::{enforce_at_most, to_bits, Bit, Lc, Var};
+use crate::poseidon::Poseidon;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub r8: Point,
+    /// The scalar `S`, below the subgroup order.
+    pub s: Fr,
+}
+
+/// A secret key, whose BLAKE2b-512 hash gives the signing scalar and the nonce prefix.
+///
+/// circomlibjs hashes the secret with BLAKE-512 instead, the same bytes are another key there.
+#[derive(Clone)]
+pub struct SecretKey([u8; 32]);
+
+impl SecretKey {
+    pub fn new(bytes: [u8; 32]) -> Self {
+        SecretKey(bytes)
+    }
+
+    pub fn generate<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        SecretKey(rng.gen())
+    }
+
+    /// The scalar, a multiple of 8 below `2^255` with bit 254 set, and the nonce prefix.
+    fn expand(&self) -> (BigUint, [u8; 32]) {
+        let mut hash = [0u8; 64];
+        hash.copy_from_slice(&Blake2b::digest(&self.0));
+        hash[0] &= 0xf8;
+        hash[31] &= 0x7f;
+        hash[31] |= 0x40;
+        let scalar = BigUint::from_bytes_le(&hash[..32]);
+        (scalar, hash[32..].try_into().unwrap())
+    }
+
+    pub fn public_key(&self) -> Point {
+        let (scalar, _) = self.expand();
+        Point::base8().scalar_mul(&(scalar >> 3))
+    }
+
+    pub fn sign(&self, message: Fr) -> Signature {
+        let (scalar, prefix) = self.expand();
+        let public_key = Point::base8().scalar_mul(&(scalar.clone() >> 3));
+        let order = subgroup_order();
+
+        let mut hasher = Blake2b::new();
+        hasher.update(prefix);
+        hasher.update(to_bytes!(message).unwrap());
+        let r = BigUint::from_bytes_le(&hasher.finalize()) % &order;
+        let r8 = Point::base8().scalar_mul(&r);
+        let h = to_biguint(challenge(&r8, &public_key, message));
+        let s = (r + h * scalar) % order;
+        Signature {
+            r8,
+            s: Fr::from_le_bytes_mod_order(&s.to_bytes_le()),
+        }
+    }
+}
+
+/// `Poseidon(R8, A, m)`.
+pub fn challenge(r8: &Point, public_key: &Point, message: Fr) -> Fr {
+    Poseidon::new(5).hash(&[r8.x, r8.y, public_key.x, public_key.y, message])
+}
+
+/// Verifies the signature of `message` by `public_key`, rejecting keys of small order.
+pub fn verify(public_key: &Point, message: Fr, signature: &Signature) -> bool {
+    let s = to_biguint(signature.s);
+    if !public_key.is_on_curve() || !signature.r8.is_on_curve() || s >= subgroup_order() {
+        return false;
+    }
+    let a8 = public_key.double().double().double();
+    if a8 == Point::identity() {
+        return false;
+    }
+    let h = to_biguint(challenge(&signature.r8, public_key, message));
+    Point::base8().scalar_mul(&s) == signature.r8 + a8.scalar_mul(&h)
+}
+
+/// A message with its signature.
+#[derive(Clone, Debug)]
+pub struct SignedMessage {
+    pub public_key: Point,
+    pub message: Fr,
+    pub signature: Signature,
+}
+
+/// Proves the knowledge of a signature of the public message by the public key.
+///
+/// The public inputs are the coordinates of the key followed by the message. The key is only
+/// checked to be on the curve and not of small order, the verifier compares it with the keys of
+/// the authorities it trusts.
+#[derive(Clone, Debug)]
+pub struct EddsaPoseidonVerifier {
+    pub poseidon: Poseidon<Fr>,
+    /// The signed message when proving, `None` during the setup.
+    pub signed: Option<SignedMessage>,
+}
+
+impl EddsaPoseidonVerifier {
+    pub fn new(signed: Option<SignedMessage>) -> Self {
+        EddsaPoseidonVerifier {
+            poseidon: Poseidon::new(5),
+            signed,
+        }
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for EddsaPoseidonVerifier {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        let signed = self.signed.as_ref();
+        let public_key = PointVar::input(&cs, signed.map(|signed| signed.public_key))?;
+        let message = Lc::from(Var::input(&cs, signed.map(|signed| signed.message))?);
+        let r8 = PointVar::witness(&cs, signed.map(|signed| signed.signature.r8))?;
+
+        // S < l, so that no other scalar of the same point verifies
+        let order = subgroup_order();
+        let order_bits = order.bits() as usize;
+        let s_value = signed.map(|signed| to_biguint(signed.signature.s));
+        let s = (0..order_bits)
+            .map(|i| Bit::witness(&cs, s_value.as_ref().map(|s| s.bit(i as u64))))
+            .collect::<Result<Vec<_>, _>>()?;
+        enforce_at_most(&cs, &s, &scalar_bits(&(order - 1u64), order_bits))?;
+
+        let h = self.poseidon.hash_gadget(
+            &cs,
+            &[
+                r8.x.clone(),
+                r8.y.clone(),
+                public_key.x.clone(),
+                public_key.y.clone(),
+                message,
+            ],
+        )?;
+        // the canonical bits of h, below the modulus
+        let modulus_bits = <Fr as PrimeField>::Params::MODULUS_BITS as usize;
+        let h = to_bits(&cs, &h, modulus_bits)?;
+        let modulus = to_biguint(-Fr::one());
+        enforce_at_most(&cs, &h, &scalar_bits(&modulus, modulus_bits))?;
+
+        // A isn't of small order: 8 A is in the subgroup of order l, where only the identity has
+        // x = 0
+        let a8 = public_key.double(&cs)?.double(&cs)?.double(&cs)?;
+        let inverse = a8.x.value.map(|x| x.inverse().unwrap_or_default());
+        cs.enforce_constraint(
+            a8.x.lc.clone(),
+            Lc::from(Var::witness(&cs, inverse)?).lc,
+            Lc::constant(Fr::one()).lc,
+        )?;
+
+        let left = fixed_base_mul(&cs, Point::base8(), &s)?;
+        let right = r8.add(&cs, &a8.scalar_mul(&cs, &h)?)?;
+        left.enforce_equal(&cs, &right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_groth16::{prepare_verifying_key, verify_proof};
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{prove, setup};
+
+    fn signed(message: u64) -> SignedMessage {
+        let key = SecretKey::new([50u8; 32]);
+        let message = Fr::from(message);
+        SignedMessage {
+            public_key: key.public_key(),
+            message,
+            signature: key.sign(message),
+        }
+    }
+
+    fn is_satisfied(signed: SignedMessage) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        EddsaPoseidonVerifier::new(Some(signed))
+            .generate_constraints(cs.clone())
+            .unwrap();
+        cs.is_satisfied().unwrap()
+    }
+
+    #[test]
+    fn signatures_verify() {
+        let rng = &mut ChaCha20Rng::seed_from_u64(50);
+        let key = SecretKey::generate(rng);
+        let public_key = key.public_key();
+        assert!(public_key.is_on_curve());
+        let message = Fr::from(1234u64);
+        let signature = key.sign(message);
+        assert!(verify(&public_key, message, &signature));
+        assert_eq!(key.sign(message), signature);
+
+        assert!(!verify(&public_key, Fr::from(1235u64), &signature));
+        let other = SecretKey::generate(rng).public_key();
+        assert!(!verify(&other, message, &signature));
+        // S + l is the same scalar but isn't canonical
+        let s = to_biguint(signature.s) + subgroup_order();
+        let forged = Signature {
+            s: Fr::from_le_bytes_mod_order(&s.to_bytes_le()),
+            ..signature
+        };
+        assert!(!verify(&public_key, message, &forged));
+        // a key of small order accepts signatures by anyone
+        let small = Point::new(Fr::from(0u64), -Fr::from(1u64));
+        let unsigned = Signature {
+            r8: Point::base8(),
+            s: Fr::from(1u64),
+        };
+        assert!(!verify(&small, message, &unsigned));
+    }
+
+    #[test]
+    fn keys_are_derived_with_blake2b() {
+        // circomlibjs derives the scalar from the BLAKE-512 hash of the secret instead, its keys
+        // and signatures for the same secret differ from these
+        let key = SecretKey::new([50u8; 32]);
+        let mut hash = Blake2b::digest(&[50u8; 32]).to_vec();
+        hash[0] &= 0xf8;
+        hash[31] &= 0x7f;
+        hash[31] |= 0x40;
+        let scalar = BigUint::from_bytes_le(&hash[..32]) >> 3;
+        assert_eq!(key.public_key(), Point::base8().scalar_mul(&scalar));
+    }
+
+    #[test]
+    fn verification_does_not_depend_on_the_key_derivation() {
+        // a key and a nonce derived otherwise, e.g. by circomlibjs
+        let scalar = BigUint::from(8 * 123_456_789u64);
+        let r = BigUint::from(987_654_321u64);
+        let public_key = Point::base8().scalar_mul(&(scalar.clone() >> 3));
+        let message = Fr::from(1234u64);
+        let r8 = Point::base8().scalar_mul(&r);
+        let h = to_biguint(challenge(&r8, &public_key, message));
+        let s = (r + h * scalar) % subgroup_order();
+        let signature = Signature {
+            r8,
+            s: Fr::from_le_bytes_mod_order(&s.to_bytes_le()),
+        };
+        assert!(verify(&public_key, message, &signature));
+        assert!(is_satisfied(SignedMessage {
+            public_key,
+            message,
+            signature,
+        }));
+    }
+
+    #[test]
+    fn signatures_are_constrained() {
+        let valid = signed(7);
+        let cs = ConstraintSystem::new_ref();
+        EddsaPoseidonVerifier::new(Some(valid.clone()))
+            .generate_constraints(cs.clone())
+            .unwrap();
+        assert!(cs.is_satisfied().unwrap());
+        // mostly the multiplication by 8 h, 14 constraints per bit
+        assert_eq!(cs.num_constraints(), 6410);
+
+        let mut tampered = valid.clone();
+        tampered.message = Fr::from(8u64);
+        assert!(!is_satisfied(tampered));
+
+        let mut tampered = valid.clone();
+        let s = to_biguint(valid.signature.s) + subgroup_order();
+        tampered.signature.s = Fr::from_le_bytes_mod_order(&s.to_bytes_le());
+        assert!(!is_satisfied(tampered));
+
+        let mut tampered = valid;
+        tampered.public_key = Point::new(Fr::from(0u64), -Fr::from(1u64));
+        tampered.signature = Signature {
+            r8: Point::base8(),
+            s: Fr::from(1u64),
+        };
+        assert!(!is_satisfied(tampered));
+    }
+
+    #[test]
+    fn signature_is_proven() {
+        let mut rng = ChaCha20Rng::seed_from_u64(50);
+        let (pk, vk) = setup(EddsaPoseidonVerifier::new(None), &mut rng).unwrap();
+        let signed = signed(42);
+        let bundle = prove(
+            EddsaPoseidonVerifier::new(Some(signed.clone())),
+            &pk,
+            &mut rng,
+        )
+        .unwrap();
+        let public_key = signed.public_key;
+        assert_eq!(
+            bundle.public_inputs,
+            vec![public_key.x, public_key.y, signed.message]
+        );
+        let pvk = prepare_verifying_key(&vk);
+        assert!(verify_proof(&pvk, &bundle.proof, &bundle.public_inputs).unwrap());
+        let other = [public_key.x, public_key.y, Fr::from(43u64)];
+        assert!(!verify_proof(&pvk, &bundle.proof, &other).unwrap());
+    }
+}
@@ -0,0 +1,277 @@
+//! Building blocks of the circuits' constraints.
+//!
+//! A [`Var`] is a variable of the constraint system and an [`Lc`] a linear combination of
+//! them, both carry their value while proving and `None` during the setup. A [`Bit`] is a
+//! boolean combination, or a constant the operations on it are folded into.
+use std::ops::{Add, Sub};
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSystemRef, LinearCombination, SynthesisError, Variable};
+
+/// A variable together with its value, if assigned.
+#[derive(Clone, Copy, Debug)]
+pub struct Var<F: PrimeField> {
+    pub variable: Variable,
+    pub value: Option<F>,
+}
+
+impl<F: PrimeField> Var<F> {
+    pub fn witness(cs: &ConstraintSystemRef<F>, value: Option<F>) -> Result<Self, SynthesisError> {
+        let variable =
+            cs.new_witness_variable(|| value.ok_or(SynthesisError::AssignmentMissing))?;
+        Ok(Var { variable, value })
+    }
+
+    pub fn input(cs: &ConstraintSystemRef<F>, value: Option<F>) -> Result<Self, SynthesisError> {
+        let variable = cs.new_input_variable(|| value.ok_or(SynthesisError::AssignmentMissing))?;
+        Ok(Var { variable, value })
+    }
+
+    /// The constant one.
+    pub fn one() -> Self {
+        Var {
+            variable: Variable::One,
+            value: Some(F::one()),
+        }
+    }
+}
+
+/// A linear combination together with its value, if assigned.
+#[derive(Clone, Debug)]
+pub struct Lc<F: PrimeField> {
+    pub lc: LinearCombination<F>,
+    pub value: Option<F>,
+}
+
+impl<F: PrimeField> From<Var<F>> for Lc<F> {
+    fn from(var: Var<F>) -> Self {
+        Lc {
+            lc: lc!() + var.variable,
+            value: var.value,
+        }
+    }
+}
+
+impl<'a, F: PrimeField> Add<&'a Lc<F>> for Lc<F> {
+    type Output = Lc<F>;
+
+    fn add(mut self, other: &'a Lc<F>) -> Lc<F> {
+        self.lc = self.lc + &other.lc;
+        self.value = self.value.zip(other.value).map(|(a, b)| a + b);
+        self
+    }
+}
+
+impl<'a, F: PrimeField> Sub<&'a Lc<F>> for Lc<F> {
+    type Output = Lc<F>;
+
+    fn sub(mut self, other: &'a Lc<F>) -> Lc<F> {
+        self.lc = self.lc - &other.lc;
+        self.value = self.value.zip(other.value).map(|(a, b)| a - b);
+        self
+    }
+}
+
+impl<F: PrimeField> Lc<F> {
+    pub fn zero() -> Self {
+        Lc {
+            lc: lc!(),
+            value: Some(F::zero()),
+        }
+    }
+
+    pub fn constant(constant: F) -> Self {
+        Lc::zero().add_constant(constant)
+    }
+
+    pub fn scale(mut self, coefficient: F) -> Self {
+        self.lc *= coefficient;
+        self.value = self.value.map(|value| value * coefficient);
+        self
+    }
+
+    pub fn add_constant(mut self, constant: F) -> Self {
+        if !constant.is_zero() {
+            self.lc += (constant, Variable::One);
+            self.value = self.value.map(|value| value + constant);
+        }
+        self
+    }
+
+    /// Allocates a witness equal to this combination.
+    pub fn to_var(&self, cs: &ConstraintSystemRef<F>) -> Result<Var<F>, SynthesisError> {
+        let var = Var::witness(cs, self.value)?;
+        cs.enforce_constraint(lc!() + var.variable, lc!() + Variable::One, self.lc.clone())?;
+        Ok(var)
+    }
+
+    /// Enforces that both combinations are equal.
+    pub fn enforce_equal(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        other: &Lc<F>,
+    ) -> Result<(), SynthesisError> {
+        cs.enforce_constraint(self.lc.clone(), lc!() + Variable::One, other.lc.clone())
+    }
+}
+
+/// A boolean, constant or constrained to 0 or 1.
+#[derive(Clone, Debug)]
+pub enum Bit<F: PrimeField> {
+    Constant(bool),
+    Lc(Lc<F>),
+}
+
+impl<F: PrimeField> Bit<F> {
+    /// Allocates a witness constrained to be boolean.
+    pub fn witness(
+        cs: &ConstraintSystemRef<F>,
+        value: Option<bool>,
+    ) -> Result<Self, SynthesisError> {
+        let var = Var::witness(cs, value.map(F::from))?;
+        cs.enforce_constraint(
+            lc!() + var.variable,
+            lc!() + var.variable,
+            lc!() + var.variable,
+        )?;
+        Ok(Bit::Lc(Lc::from(var)))
+    }
+
+    pub fn value(&self) -> Option<bool> {
+        match self {
+            Bit::Constant(bit) => Some(*bit),
+            Bit::Lc(lc) => lc.value.map(|value| value.is_one()),
+        }
+    }
+
+    pub fn lc(&self) -> Lc<F> {
+        match self {
+            Bit::Constant(bit) => Lc::constant(F::from(*bit)),
+            Bit::Lc(lc) => lc.clone(),
+        }
+    }
+
+    /// The exclusive or, one constraint unless one of the bits is constant.
+    pub fn xor(&self, cs: &ConstraintSystemRef<F>, other: &Bit<F>) -> Result<Self, SynthesisError> {
+        match (self, other) {
+            (Bit::Constant(a), Bit::Constant(b)) => Ok(Bit::Constant(a ^ b)),
+            (Bit::Constant(false), bit) | (bit, Bit::Constant(false)) => Ok(bit.clone()),
+            (Bit::Constant(true), Bit::Lc(lc)) | (Bit::Lc(lc), Bit::Constant(true)) => {
+                Ok(Bit::Lc(Lc::constant(F::one()) - lc))
+            }
+            (Bit::Lc(a), Bit::Lc(b)) => {
+                // 2a * b = a + b - (a ^ b)
+                let value = self.value().zip(other.value()).map(|(a, b)| F::from(a ^ b));
+                let xor = Var::witness(cs, value)?;
+                cs.enforce_constraint(
+                    a.clone().scale(F::from(2u64)).lc,
+                    b.lc.clone(),
+                    (a.clone() + b).lc - xor.variable,
+                )?;
+                Ok(Bit::Lc(Lc::from(xor)))
+            }
+        }
+    }
+
+    /// The conjunction, one constraint unless one of the bits is constant.
+    pub fn and(&self, cs: &ConstraintSystemRef<F>, other: &Bit<F>) -> Result<Self, SynthesisError> {
+        match (self, other) {
+            (Bit::Constant(a), Bit::Constant(b)) => Ok(Bit::Constant(*a && *b)),
+            (Bit::Constant(false), _) | (_, Bit::Constant(false)) => Ok(Bit::Constant(false)),
+            (Bit::Constant(true), bit) | (bit, Bit::Constant(true)) => Ok(bit.clone()),
+            (Bit::Lc(a), Bit::Lc(b)) => Ok(Bit::Lc(Lc::from(mul(cs, a, b)?))),
+        }
+    }
+}
+
+/// Decomposes `value` into `n` little-endian bits.
+///
+/// The bits are only unique if `n` is below the size of the field, otherwise they have to be
+/// bounded with [`enforce_at_most`].
+pub fn to_bits<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    value: &Lc<F>,
+    n: usize,
+) -> Result<Vec<Bit<F>>, SynthesisError> {
+    let bits = value.value.map(|value| value.into_repr().to_bits_le());
+    let mut sum = Lc::zero();
+    let mut weight = F::one();
+    let mut decomposition = Vec::with_capacity(n);
+    for i in 0..n {
+        let bit = Bit::witness(cs, bits.as_ref().map(|bits| bits[i]))?;
+        sum = sum + &bit.lc().scale(weight);
+        weight.double_in_place();
+        decomposition.push(bit);
+    }
+    sum.enforce_equal(cs, value)?;
+    Ok(decomposition)
+}
+
+/// Enforces that the little-endian `bits` are at most the constant `bound`, of the same length.
+///
+/// From the most significant bit down, a bit has to be zero where the bound is zero as long as
+/// the bits above match the bound, at most one constraint per bit.
+pub fn enforce_at_most<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    bits: &[Bit<F>],
+    bound: &[bool],
+) -> Result<(), SynthesisError> {
+    assert_eq!(bits.len(), bound.len());
+    // whether the bits above are those of the bound
+    let mut matching = Bit::Constant(true);
+    for (bit, bound) in bits.iter().zip(bound).rev() {
+        if *bound {
+            matching = matching.and(cs, bit)?;
+        } else {
+            cs.enforce_constraint(matching.lc().lc, bit.lc().lc, lc!())?;
+        }
+    }
+    Ok(())
+}
+
+/// Allocates the product of `a` and `b`.
+pub fn mul<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    a: &Lc<F>,
+    b: &Lc<F>,
+) -> Result<Var<F>, SynthesisError> {
+    let product = Var::witness(cs, a.value.zip(b.value).map(|(a, b)| a * b))?;
+    cs.enforce_constraint(a.lc.clone(), b.lc.clone(), lc!() + product.variable)?;
+    Ok(product)
+}
+
+/// Allocates `t^exponent + add` for an exponent of 3, 5 or 7, as an input if `public`.
+///
+/// The powers below the exponent take one constraint each and the last multiplication is
+/// folded into the constraint defining the result.
+pub fn pow_add<F: PrimeField>(
+    cs: &ConstraintSystemRef<F>,
+    t: &Lc<F>,
+    exponent: u64,
+    add: &Lc<F>,
+    public: bool,
+) -> Result<Var<F>, SynthesisError> {
+    let square = Lc::from(mul(cs, t, t)?);
+    let below = match exponent {
+        3 => square,
+        5 => Lc::from(mul(cs, &square, &square)?),
+        7 => {
+            let fourth = Lc::from(mul(cs, &square, &square)?);
+            Lc::from(mul(cs, &fourth, &square)?)
+        }
+        _ => return Err(SynthesisError::Unsatisfiable),
+    };
+    let value = below
+        .value
+        .zip(t.value)
+        .zip(add.value)
+        .map(|((below, t), add)| below * t + add);
+    let result = if public {
+        Var::input(cs, value)?
+    } else {
+        Var::witness(cs, value)?
+    };
+    cs.enforce_constraint(below.lc, t.lc.clone(), lc!() + result.variable - &add.lc)?;
+    Ok(result)
+}
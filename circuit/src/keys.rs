@@ -0,0 +1,241 @@
+//! Key files of a circuit.
+//!
+//! A key file starts with a header made of the magic bytes `G16K`, the format version as a
+//! little-endian `u16`, the kind of key and the identifier of the circuit the key belongs to,
+//! prefixed by its length as one byte. The key follows, serialized uncompressed with ark's
+//! `CanonicalSerialize`. Loading checks the header before reading the key, so a key of another
+//! circuit or an older format is refused instead of producing proofs the contract rejects.
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use ark_bn254::Bn254;
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
+const MAGIC: [u8; 4] = *b"G16K";
+/// The version of the key file format written by this crate.
+pub const KEY_FILE_VERSION: u16 = 1;
+
+/// The kind of key stored in a key file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyKind {
+    Proving = 0,
+    Verifying = 1,
+    /// The transcript of a setup ceremony, see [`crate::ceremony`].
+    Ceremony = 2,
+}
+
+#[derive(Debug)]
+pub enum KeyFileError {
+    Io(io::Error),
+    Serialization(SerializationError),
+    /// The file doesn't start with the magic bytes.
+    NotAKeyFile,
+    UnsupportedVersion(u16),
+    WrongKind {
+        expected: KeyKind,
+        found: u8,
+    },
+    /// The key belongs to another circuit.
+    CircuitMismatch {
+        expected: String,
+        found: String,
+    },
+}
+
+impl fmt::Display for KeyFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyFileError::Io(error) => write!(f, "{}", error),
+            KeyFileError::Serialization(error) => write!(f, "{}", error),
+            KeyFileError::NotAKeyFile => write!(f, "not a key file"),
+            KeyFileError::UnsupportedVersion(version) => {
+                write!(f, "unsupported key file version {}", version)
+            }
+            KeyFileError::WrongKind { expected, found } => {
+                write!(f, "expected a {:?} key, found kind {}", expected, found)
+            }
+            KeyFileError::CircuitMismatch { expected, found } => write!(
+                f,
+                "key of circuit {:?}, expected circuit {:?}",
+                found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeyFileError {}
+
+impl From<io::Error> for KeyFileError {
+    fn from(error: io::Error) -> Self {
+        KeyFileError::Io(error)
+    }
+}
+
+impl From<SerializationError> for KeyFileError {
+    fn from(error: SerializationError) -> Self {
+        KeyFileError::Serialization(error)
+    }
+}
+
+fn write_header<W: Write>(
+    writer: &mut W,
+    kind: KeyKind,
+    circuit_id: &str,
+) -> Result<(), KeyFileError> {
+    let id_len = u8::try_from(circuit_id.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "circuit id too long"))?;
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&KEY_FILE_VERSION.to_le_bytes())?;
+    writer.write_all(&[kind as u8, id_len])?;
+    writer.write_all(circuit_id.as_bytes())?;
+    Ok(())
+}
+
+fn read_header<R: Read>(
+    reader: &mut R,
+    kind: KeyKind,
+    circuit_id: &str,
+) -> Result<(), KeyFileError> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(KeyFileError::NotAKeyFile);
+    }
+    let mut version = [0u8; 2];
+    reader.read_exact(&mut version)?;
+    let version = u16::from_le_bytes(version);
+    if version != KEY_FILE_VERSION {
+        return Err(KeyFileError::UnsupportedVersion(version));
+    }
+    let mut fields = [0u8; 2];
+    reader.read_exact(&mut fields)?;
+    let [found_kind, id_len] = fields;
+    if found_kind != kind as u8 {
+        return Err(KeyFileError::WrongKind {
+            expected: kind,
+            found: found_kind,
+        });
+    }
+    let mut found_id = vec![0u8; id_len as usize];
+    reader.read_exact(&mut found_id)?;
+    if found_id != circuit_id.as_bytes() {
+        return Err(KeyFileError::CircuitMismatch {
+            expected: circuit_id.to_string(),
+            found: String::from_utf8_lossy(&found_id).into_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// Writes a key of `circuit_id` with its header.
+pub fn write_key<W: Write, T: CanonicalSerialize>(
+    mut writer: W,
+    kind: KeyKind,
+    circuit_id: &str,
+    key: &T,
+) -> Result<(), KeyFileError> {
+    write_header(&mut writer, kind, circuit_id)?;
+    key.serialize_uncompressed(&mut writer)?;
+    Ok(())
+}
+
+/// Reads a key of `circuit_id`, refusing files of another kind, version or circuit.
+pub fn read_key<R: Read, T: CanonicalDeserialize>(
+    mut reader: R,
+    kind: KeyKind,
+    circuit_id: &str,
+) -> Result<T, KeyFileError> {
+    read_header(&mut reader, kind, circuit_id)?;
+    Ok(T::deserialize_uncompressed(&mut reader)?)
+}
+
+pub fn save_proving_key<P: AsRef<Path>>(
+    path: P,
+    circuit_id: &str,
+    pk: &ProvingKey<Bn254>,
+) -> Result<(), KeyFileError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_key(&mut writer, KeyKind::Proving, circuit_id, pk)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn load_proving_key<P: AsRef<Path>>(
+    path: P,
+    circuit_id: &str,
+) -> Result<ProvingKey<Bn254>, KeyFileError> {
+    let reader = BufReader::new(File::open(path)?);
+    read_key(reader, KeyKind::Proving, circuit_id)
+}
+
+pub fn save_verifying_key<P: AsRef<Path>>(
+    path: P,
+    circuit_id: &str,
+    vk: &VerifyingKey<Bn254>,
+) -> Result<(), KeyFileError> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_key(&mut writer, KeyKind::Verifying, circuit_id, vk)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn load_verifying_key<P: AsRef<Path>>(
+    path: P,
+    circuit_id: &str,
+) -> Result<VerifyingKey<Bn254>, KeyFileError> {
+    let reader = BufReader::new(File::open(path)?);
+    read_key(reader, KeyKind::Verifying, circuit_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{demo_parameters, demo_rng, DEMO_CIRCUIT_ID};
+
+    #[test]
+    fn key_files_round_trip_and_refuse_mismatches() {
+        let (_, pk) = demo_parameters(&mut demo_rng());
+        let mut file = vec![];
+        write_key(&mut file, KeyKind::Proving, DEMO_CIRCUIT_ID, &pk).unwrap();
+        let loaded: ProvingKey<Bn254> =
+            read_key(file.as_slice(), KeyKind::Proving, DEMO_CIRCUIT_ID).unwrap();
+        assert_eq!(loaded, pk);
+
+        let mut file = vec![];
+        write_key(&mut file, KeyKind::Verifying, DEMO_CIRCUIT_ID, &pk.vk).unwrap();
+        let loaded: VerifyingKey<Bn254> =
+            read_key(file.as_slice(), KeyKind::Verifying, DEMO_CIRCUIT_ID).unwrap();
+        assert_eq!(loaded, pk.vk);
+
+        assert!(matches!(
+            read_key::<_, ProvingKey<Bn254>>(file.as_slice(), KeyKind::Proving, DEMO_CIRCUIT_ID),
+            Err(KeyFileError::WrongKind { .. })
+        ));
+        assert!(matches!(
+            read_key::<_, VerifyingKey<Bn254>>(file.as_slice(), KeyKind::Verifying, "other"),
+            Err(KeyFileError::CircuitMismatch { .. })
+        ));
+        file[4] = 2;
+        assert!(matches!(
+            read_key::<_, VerifyingKey<Bn254>>(
+                file.as_slice(),
+                KeyKind::Verifying,
+                DEMO_CIRCUIT_ID
+            ),
+            Err(KeyFileError::UnsupportedVersion(2))
+        ));
+        file[0] = 0;
+        assert!(matches!(
+            read_key::<_, VerifyingKey<Bn254>>(
+                file.as_slice(),
+                KeyKind::Verifying,
+                DEMO_CIRCUIT_ID
+            ),
+            Err(KeyFileError::NotAKeyFile)
+        ));
+    }
+}
@@ -1,17 +1,36 @@
-use ark_bn254::{Bn254, Fq12Parameters, Fq2Parameters, Fr, G1Affine};
+use ark_bn254::{Bn254, Fq12Parameters, Fq2Parameters, Fr, G1Affine, G1Projective, G2Affine};
 use ark_ec::bn::{BnParameters, G1Prepared, G2Prepared, TwistType};
+use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{to_bytes, Field, Fp12, Fp12ParamsWrapper, Fp2, QuadExtField};
 use ark_groth16::{
-    create_random_proof, generate_random_parameters, prepare_inputs, prepare_verifying_key,
+    create_random_proof, prepare_inputs, prepare_verifying_key, PreparedVerifyingKey, Proof,
+    ProvingKey,
 };
 use ark_relations::r1cs::Result as R1CSResult;
 use ark_std::rand;
 use ark_std::rand::Rng;
 use num_traits::One;
 
-use crate::circuit::{mimc, Circuit, MIMC_ROUNDS};
+pub use crate::circuit::{mimc, Circuit, MIMC_ROUNDS};
+pub use crate::prover::{
+    export_for_contract, prove, public_inputs, setup, ContractPayload, ProofBundle,
+};
 
+pub mod babyjubjub;
+pub mod blake2b;
+pub mod ceremony;
+pub mod circom;
 mod circuit;
+pub mod eddsa;
+pub mod gadget;
+mod keccak;
+pub mod keys;
+pub mod merkle;
+pub mod mimc;
+pub mod poseidon;
+pub mod prover;
+pub mod range;
+pub mod vectors;
 
 pub(crate) type EllCoeff<F> = (F, F, F);
 
@@ -34,18 +53,30 @@ pub fn ell(f: &mut Fp12<Fq12Parameters>, coeffs: &EllCoeff<Fp2<Fq2Parameters>>,
     }
 }
 
-pub fn initialize() -> R1CSResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
-    let rng = &mut {
-        use rand::SeedableRng;
-        // arbitrary seed
-        let seed = [
-            1, 0, 0, 0, 23, 0, 0, 0, 200, 1, 0, 0, 210, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0,
-        ];
-        rand::rngs::StdRng::from_seed(seed)
-    };
+fn demo_rng() -> rand::rngs::StdRng {
+    use rand::SeedableRng;
+    // arbitrary seed
+    let seed = [
+        1, 0, 0, 0, 23, 0, 0, 0, 200, 1, 0, 0, 210, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    rand::rngs::StdRng::from_seed(seed)
+}
+
+/// Identifies the demo circuit in key files.
+pub const DEMO_CIRCUIT_ID: &str = "mimc-322";
+
+fn mimc_constants<R: Rng>(rng: &mut R) -> Vec<Fr> {
+    (0..MIMC_ROUNDS).map(|_| rng.gen()).collect()
+}
+
+/// The round constants of the demo circuit.
+pub fn demo_constants() -> Vec<Fr> {
+    mimc_constants(&mut demo_rng())
+}
 
-    let constants = (0..MIMC_ROUNDS).map(|_| rng.gen()).collect::<Vec<_>>();
+fn demo_parameters<R: Rng>(rng: &mut R) -> (Vec<Fr>, ProvingKey<Bn254>) {
+    let constants = mimc_constants(rng);
 
     println!("Creating parameters...");
 
@@ -57,17 +88,63 @@ pub fn initialize() -> R1CSResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
             constants: &constants,
         };
 
-        generate_random_parameters::<Bn254, _, _>(c, rng).unwrap()
+        setup(c, rng).unwrap().0
     };
+    (constants, params)
+}
 
-    let pvk = prepare_verifying_key(&params.vk);
-    println!("Creating proofs...");
-    // Generate a random preimage and compute the image
+/// The proving key of the demo circuit, the key matching the verifying key embedded in the
+/// contract.
+pub fn demo_proving_key() -> ProvingKey<Bn254> {
+    demo_parameters(&mut demo_rng()).1
+}
+
+/// Proves the demo circuit for a random preimage under `pk`, e.g. the key loaded from the file
+/// written by the `keys` binary.
+pub fn demo_prove<R: Rng>(pk: &ProvingKey<Bn254>, rng: &mut R) -> R1CSResult<ProofBundle> {
+    let constants = demo_constants();
+    let (l, r, _) = demo_preimage(rng, &constants);
+    let c = Circuit {
+        xl: Some(l),
+        xr: Some(r),
+        constants: &constants,
+    };
+    prove(c, pk, rng)
+}
+
+/// The prepared verifying key of the demo circuit, the key embedded in the contract.
+pub fn demo_verifying_key() -> PreparedVerifyingKey<Bn254> {
+    let (_, params) = demo_parameters(&mut demo_rng());
+    prepare_verifying_key(&params.vk)
+}
+
+/// The prepared input of `public_input` under the demo verifying key.
+pub fn demo_prepared_input(public_input: Fr) -> R1CSResult<Vec<u8>> {
+    let prepared_input = prepare_inputs(&demo_verifying_key(), &[public_input])?;
+    Ok(to_bytes!(prepared_input).unwrap())
+}
+
+/// Generates a random preimage and computes the image, the public input of the demo proof.
+fn demo_preimage<R: Rng>(rng: &mut R, constants: &[Fr]) -> (Fr, Fr, Fr) {
     let l = rng.gen();
     let r = rng.gen();
-    let public_inputs = mimc(l, r, &constants);
+    (l, r, mimc(l, r, constants))
+}
+
+/// The public input of the demo proof.
+pub fn demo_public_input() -> Fr {
+    let rng = &mut demo_rng();
+    let (constants, _) = demo_parameters(rng);
+    demo_preimage(rng, &constants).2
+}
 
-    // proof_vec.truncate(0);
+/// Creates the demo proof together with its public input and the demo proving key.
+fn demo_bundle() -> R1CSResult<(ProofBundle, ProvingKey<Bn254>)> {
+    let rng = &mut demo_rng();
+    let (constants, params) = demo_parameters(rng);
+
+    println!("Creating proofs...");
+    let (l, r, _) = demo_preimage(rng, &constants);
 
     // Create an instance of our circuit (with the
     // witness)
@@ -78,20 +155,92 @@ pub fn initialize() -> R1CSResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
     };
 
     // Create a groth16 proof with our parameters.
-    let proof = create_random_proof(c, &params, rng).unwrap();
-    let prepared_input = prepare_inputs(&pvk, &[public_inputs])?;
-    let mut qap = Fp12::<Fq12Parameters>::one();
-    let r = offline_miller_loop(
-        &G1Prepared::<ark_bn254::Parameters>::from(proof.a.clone()),
-        &G2Prepared::<ark_bn254::Parameters>::from(proof.b.clone()),
+    let bundle = prove(c, &params, rng)?;
+    Ok((bundle, params))
+}
+
+/// Creates the demo proof together with its prepared input and the demo verifying key.
+fn demo_proof() -> R1CSResult<(Proof<Bn254>, G1Projective, PreparedVerifyingKey<Bn254>)> {
+    let (bundle, params) = demo_bundle()?;
+    let pvk = prepare_verifying_key(&params.vk);
+    let prepared_input = prepare_inputs(&pvk, &bundle.public_inputs)?;
+    Ok((bundle.proof, prepared_input, pvk))
+}
+
+/// The contract payload of the demo proof.
+pub fn demo_payload() -> R1CSResult<ContractPayload> {
+    let (bundle, params) = demo_bundle()?;
+    export_for_contract(&bundle, &params.vk)
+}
+
+/// The inputs of the Miller loops for the demo proof: `C`, the prepared input and `A` followed
+/// by `B`.
+///
+/// Prefer [`demo_payload`], or [`setup`], [`prove`] and [`export_for_contract`] for other
+/// circuits.
+pub fn initialize() -> R1CSResult<(Vec<u8>, Vec<u8>, Vec<u8>)> {
+    let payload = demo_payload()?;
+    Ok((payload.proof_c, payload.prepared_input, payload.proof_ab))
+}
+
+/// Computes the `qap` of a proof, the Miller loop of `(A, B)` the contract computes on chain.
+pub fn proof_qap(proof: &Proof<Bn254>) -> Vec<u8> {
+    let qap = offline_miller_loop(
+        &G1Prepared::<ark_bn254::Parameters>::from(proof.a),
+        &G2Prepared::<ark_bn254::Parameters>::from(proof.b),
         Fp12::<Fq12Parameters>::one(),
     );
-    qap *= r;
-    Ok((
-        to_bytes!(proof.c).unwrap(),
-        to_bytes!(prepared_input).unwrap(),
-        to_bytes!(qap).unwrap(),
-    ))
+    to_bytes!(qap).unwrap()
+}
+
+/// The proof elements `A` and `B`.
+pub type ProofPair = (G1Affine, G2Affine);
+
+/// Creates `size` demo proofs for batch verification.
+///
+/// Returns the batch entries expected by the contract's `batch_commit`, i.e. the prepared input
+/// followed by the proof elements `A`, `B` and `C` of every proof, together with the `(A, B)`
+/// pairs to check the contract's [`batch_qap`] against.
+pub fn initialize_batch(size: usize) -> R1CSResult<(Vec<u8>, Vec<ProofPair>)> {
+    let rng = &mut demo_rng();
+    let (constants, params) = demo_parameters(rng);
+
+    let pvk = prepare_verifying_key(&params.vk);
+    println!("Creating proofs...");
+    let mut entries = vec![];
+    let mut pairs = vec![];
+    for _ in 0..size {
+        let l = rng.gen();
+        let r = rng.gen();
+        let public_inputs = mimc(l, r, &constants);
+        let c = Circuit {
+            xl: Some(l),
+            xr: Some(r),
+            constants: &constants,
+        };
+
+        let proof = create_random_proof(c, &params, rng).unwrap();
+        let prepared_input = prepare_inputs(&pvk, &[public_inputs])?;
+        entries.extend(to_bytes!(prepared_input, proof.a, proof.b, proof.c).unwrap());
+        pairs.push((proof.a, proof.b));
+    }
+    Ok((entries, pairs))
+}
+
+/// Computes the `qap` of a batch, the product of the Miller loops of `(r_i * A_i, B_i)` the
+/// contract computes on chain.
+///
+/// `challenges` are the `r_i` the contract derives from the committed batch.
+pub fn batch_qap(pairs: &[ProofPair], challenges: &[Fr]) -> Vec<u8> {
+    let mut qap = Fp12::<Fq12Parameters>::one();
+    for ((a, b), r) in pairs.iter().zip(challenges) {
+        qap *= offline_miller_loop(
+            &G1Prepared::<ark_bn254::Parameters>::from(a.mul(*r).into_affine()),
+            &G2Prepared::<ark_bn254::Parameters>::from(*b),
+            Fp12::<Fq12Parameters>::one(),
+        );
+    }
+    to_bytes!(qap).unwrap()
 }
 
 fn offline_miller_loop(
@@ -129,10 +278,43 @@ fn offline_miller_loop(
 
 #[cfg(test)]
 mod tests {
-    use crate::initialize;
+    use ark_groth16::verify_proof;
+
+    use super::*;
 
     #[test]
     fn it_works() {
         println!("{:?}", initialize());
     }
+
+    #[test]
+    fn exported_proof_verifies() {
+        let rng = &mut demo_rng();
+        let constants = (0..MIMC_ROUNDS).map(|_| rng.gen()).collect::<Vec<_>>();
+        let (pk, vk) = setup(
+            Circuit::<Fr> {
+                xl: None,
+                xr: None,
+                constants: &constants,
+            },
+            rng,
+        )
+        .unwrap();
+        let (l, r, image) = demo_preimage(rng, &constants);
+        let circuit = Circuit {
+            xl: Some(l),
+            xr: Some(r),
+            constants: &constants,
+        };
+        let bundle = prove(circuit, &pk, rng).unwrap();
+        assert_eq!(bundle.public_inputs, vec![image]);
+        assert!(verify_proof(&prepare_verifying_key(&vk), &bundle.proof, &[image]).unwrap());
+
+        let payload = export_for_contract(&bundle, &vk).unwrap();
+        assert_eq!(payload.public_inputs, to_bytes!(image).unwrap());
+        assert_eq!(
+            payload.submit().len(),
+            payload.public_inputs.len() + payload.proof_ab.len() + payload.proof_c.len()
+        );
+    }
 }
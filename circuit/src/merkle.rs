@@ -0,0 +1,331 @@
+//! Membership in a set committed to by the root of a Merkle tree.
+//!
+//! The members are the leaves of a binary tree of fixed depth, missing leaves are zero. A
+//! [`MerklePath`] holds the siblings from a leaf up to the root and [`MerkleMembership`] proves
+//! that a secret leaf hashes up to the public root, without revealing the leaf or its position.
+//! The nodes are hashed with a [`MerkleHash`], implemented for Poseidon and MiMCSponge.
+//!
+//! The keys of Poseidon trees of depth [`MERKLE_DEPTH`] come out of a setup ceremony, see
+//! [`merkle_keys`], and the contract built with the `root` feature embeds their verifying key.
+use ark_bn254::Bn254;
+use ark_ff::PrimeField;
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::gadget::{mul, Lc, Var};
+use crate::keys::{read_key, KeyFileError, KeyKind};
+use crate::mimc::Mimc;
+use crate::poseidon::Poseidon;
+
+/// The depth of the Poseidon trees the ceremony keys are for, `2^16` members.
+pub const MERKLE_DEPTH: usize = 16;
+/// Identifies `MerkleMembership::new(Poseidon::new(2), MERKLE_DEPTH, _)` in key files.
+pub const MERKLE_CIRCUIT_ID: &str = "merkle-poseidon-16";
+
+/// The proving and verifying keys of `MerkleMembership::new(Poseidon::new(2), MERKLE_DEPTH, _)`,
+/// exported by the ceremony in `keys/`.
+pub fn merkle_keys() -> Result<(ProvingKey<Bn254>, VerifyingKey<Bn254>), KeyFileError> {
+    let pk = read_key(
+        &include_bytes!("../keys/merkle.pk")[..],
+        KeyKind::Proving,
+        MERKLE_CIRCUIT_ID,
+    )?;
+    let vk = read_key(
+        &include_bytes!("../keys/merkle.vk")[..],
+        KeyKind::Verifying,
+        MERKLE_CIRCUIT_ID,
+    )?;
+    Ok((pk, vk))
+}
+
+/// Hashes two children into their parent node.
+pub trait MerkleHash<F: PrimeField> {
+    fn compress(&self, left: F, right: F) -> F;
+
+    /// Constrains [`MerkleHash::compress`].
+    fn compress_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        left: &Lc<F>,
+        right: &Lc<F>,
+    ) -> Result<Lc<F>, SynthesisError>;
+}
+
+/// circomlib's `Poseidon(2)`, the parameters have to be those of two inputs.
+impl<F: PrimeField> MerkleHash<F> for Poseidon<F> {
+    fn compress(&self, left: F, right: F) -> F {
+        self.hash(&[left, right])
+    }
+
+    fn compress_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        left: &Lc<F>,
+        right: &Lc<F>,
+    ) -> Result<Lc<F>, SynthesisError> {
+        self.hash_gadget(cs, &[left.clone(), right.clone()])
+    }
+}
+
+/// circomlib's `MiMCSponge(2, 220, 1)` with a zero key, as used by Tornado Cash.
+impl<F: PrimeField> MerkleHash<F> for Mimc<F> {
+    fn compress(&self, left: F, right: F) -> F {
+        self.sponge(&[left, right], F::zero(), 1)[0]
+    }
+
+    fn compress_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        left: &Lc<F>,
+        right: &Lc<F>,
+    ) -> Result<Lc<F>, SynthesisError> {
+        let mut squeezed =
+            self.sponge_gadget(cs, &[left.clone(), right.clone()], &Lc::zero(), 1)?;
+        Ok(squeezed.swap_remove(0))
+    }
+}
+
+/// The siblings of a leaf from the bottom of the tree up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath<F: PrimeField> {
+    /// The position of the leaf, bit `k` is set if its ancestor at height `k` is a right child.
+    pub index: u64,
+    pub siblings: Vec<F>,
+}
+
+impl<F: PrimeField> MerklePath<F> {
+    /// The root `leaf` hashes up to along this path.
+    pub fn root<H: MerkleHash<F>>(&self, hash: &H, leaf: F) -> F {
+        self.siblings
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (height, sibling)| {
+                if self.index >> height & 1 == 1 {
+                    hash.compress(*sibling, node)
+                } else {
+                    hash.compress(node, *sibling)
+                }
+            })
+    }
+}
+
+/// A tree over the leaves of a set, filled up with zero leaves.
+#[derive(Clone, Debug)]
+pub struct MerkleTree<F: PrimeField, H: MerkleHash<F>> {
+    hash: H,
+    /// The nodes of every height that have a leaf below them, the leaves first.
+    levels: Vec<Vec<F>>,
+    /// The node of every height that only has zero leaves below it.
+    zeros: Vec<F>,
+}
+
+impl<F: PrimeField, H: MerkleHash<F>> MerkleTree<F, H> {
+    /// Builds the tree of `depth` over `leaves`, which have to fit in its `2^depth` leaves.
+    pub fn new(hash: H, depth: usize, leaves: &[F]) -> Self {
+        assert!(depth < 64, "the depth has to be below 64");
+        assert!(
+            leaves.len() as u64 <= 1 << depth,
+            "the leaves don't fit in a tree of depth {}",
+            depth
+        );
+        let mut zeros = vec![F::zero()];
+        let mut levels = vec![leaves.to_vec()];
+        for height in 0..depth {
+            let zero = zeros[height];
+            let parents = levels[height]
+                .chunks(2)
+                .map(|pair| hash.compress(pair[0], pair.get(1).copied().unwrap_or(zero)))
+                .collect();
+            levels.push(parents);
+            zeros.push(hash.compress(zero, zero));
+        }
+        MerkleTree {
+            hash,
+            levels,
+            zeros,
+        }
+    }
+
+    pub fn hash(&self) -> &H {
+        &self.hash
+    }
+
+    pub fn depth(&self) -> usize {
+        self.levels.len() - 1
+    }
+
+    pub fn root(&self) -> F {
+        let depth = self.depth();
+        self.levels[depth]
+            .first()
+            .copied()
+            .unwrap_or(self.zeros[depth])
+    }
+
+    /// The path of leaf `index`, which may be a zero leaf.
+    pub fn path(&self, index: u64) -> MerklePath<F> {
+        assert!(index >> self.depth() == 0, "the index is out of the tree");
+        let siblings = (0..self.depth())
+            .map(|height| {
+                let sibling = (index >> height ^ 1) as usize;
+                self.levels[height]
+                    .get(sibling)
+                    .copied()
+                    .unwrap_or(self.zeros[height])
+            })
+            .collect();
+        MerklePath { index, siblings }
+    }
+}
+
+/// Proves that a secret leaf is in the tree of the public root.
+///
+/// The root is the only public input. Leaves are usually commitments, e.g. the hash of a
+/// secret, so that the members can be published without linking a proof to one of them.
+#[derive(Clone, Debug)]
+pub struct MerkleMembership<F: PrimeField, H: MerkleHash<F>> {
+    pub hash: H,
+    pub depth: usize,
+    /// The leaf and its path when proving, `None` during the setup.
+    pub member: Option<(F, MerklePath<F>)>,
+}
+
+impl<F: PrimeField, H: MerkleHash<F>> MerkleMembership<F, H> {
+    pub fn new(hash: H, depth: usize, member: Option<(F, MerklePath<F>)>) -> Self {
+        MerkleMembership {
+            hash,
+            depth,
+            member,
+        }
+    }
+}
+
+impl<F: PrimeField, H: MerkleHash<F>> ConstraintSynthesizer<F> for MerkleMembership<F, H> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        if let Some((_, path)) = &self.member {
+            if path.siblings.len() != self.depth || path.index >> self.depth != 0 {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+        let root = Var::input(
+            &cs,
+            self.member
+                .as_ref()
+                .map(|(leaf, path)| path.root(&self.hash, *leaf)),
+        )?;
+
+        let leaf = self.member.as_ref().map(|(leaf, _)| *leaf);
+        let mut node = Lc::from(Var::witness(&cs, leaf)?);
+        for height in 0..self.depth {
+            let path = self.member.as_ref().map(|(_, path)| path);
+            let bit = Var::witness(&cs, path.map(|path| F::from(path.index >> height & 1)))?;
+            cs.enforce_constraint(
+                lc!() + bit.variable,
+                lc!() + bit.variable,
+                lc!() + bit.variable,
+            )?;
+            let sibling = Lc::from(Var::witness(&cs, path.map(|path| path.siblings[height]))?);
+            // swaps the node and its sibling if the bit is set
+            let swap = Lc::from(mul(&cs, &Lc::from(bit), &(sibling.clone() - &node))?);
+            let left = node + &swap;
+            let right = sibling - &swap;
+            node = self.hash.compress_gadget(&cs, &left, &right)?;
+        }
+        node.enforce_equal(&cs, &Lc::from(root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_groth16::{prepare_verifying_key, verify_proof};
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{export_for_contract, prove, setup};
+
+    fn leaves(n: u64) -> Vec<Fr> {
+        (0..n).map(|i| Fr::from(i + 10)).collect()
+    }
+
+    #[test]
+    fn paths_lead_to_the_root() {
+        let poseidon = Poseidon::<Fr>::new(2);
+        let empty = MerkleTree::new(poseidon.clone(), 3, &[]);
+        let zero1 = poseidon.compress(Fr::from(0u64), Fr::from(0u64));
+        let zero2 = poseidon.compress(zero1, zero1);
+        assert_eq!(empty.root(), poseidon.compress(zero2, zero2));
+
+        let tree = MerkleTree::new(poseidon.clone(), 3, &leaves(5));
+        let leaves = leaves(5);
+        let left = poseidon.compress(
+            poseidon.compress(leaves[0], leaves[1]),
+            poseidon.compress(leaves[2], leaves[3]),
+        );
+        let right = poseidon.compress(poseidon.compress(leaves[4], Fr::from(0u64)), zero1);
+        assert_eq!(tree.root(), poseidon.compress(left, right));
+        for index in 0..8 {
+            let leaf = leaves.get(index).copied().unwrap_or_default();
+            let path = tree.path(index as u64);
+            assert_eq!(path.root(&poseidon, leaf), tree.root());
+        }
+        assert_ne!(tree.path(1).root(&poseidon, leaves[0]), tree.root());
+    }
+
+    #[test]
+    fn membership_is_constrained() {
+        let mimc = Mimc::<Fr>::mimc_sponge();
+        let tree = MerkleTree::new(mimc.clone(), 2, &leaves(3));
+        let member = (Fr::from(12u64), tree.path(2));
+        let cs = ConstraintSystem::new_ref();
+        MerkleMembership::new(mimc.clone(), 2, Some(member))
+            .generate_constraints(cs.clone())
+            .unwrap();
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(cs.borrow().unwrap().instance_assignment[1], tree.root());
+        // a bit and a swap per level besides the two permutations of the sponge
+        assert_eq!(cs.num_constraints(), 2 * (2 + 2 * 3 * 220) + 1);
+
+        // any leaf hashes up to some root, just not to the root of the tree
+        let cs = ConstraintSystem::new_ref();
+        let member = (Fr::from(13u64), tree.path(2));
+        MerkleMembership::new(mimc, 2, Some(member))
+            .generate_constraints(cs.clone())
+            .unwrap();
+        assert_ne!(cs.borrow().unwrap().instance_assignment[1], tree.root());
+    }
+
+    #[test]
+    fn membership_is_proven() {
+        let mut rng = ChaCha20Rng::seed_from_u64(47);
+        let tree = MerkleTree::new(Poseidon::new(2), 4, &leaves(9));
+        let (pk, vk) = setup(MerkleMembership::new(Poseidon::new(2), 4, None), &mut rng).unwrap();
+
+        let member = (Fr::from(17u64), tree.path(7));
+        let circuit = MerkleMembership::new(Poseidon::new(2), 4, Some(member));
+        let bundle = prove(circuit, &pk, &mut rng).unwrap();
+        assert_eq!(bundle.public_inputs, vec![tree.root()]);
+        let pvk = prepare_verifying_key(&vk);
+        assert!(verify_proof(&pvk, &bundle.proof, &bundle.public_inputs).unwrap());
+        let other = tree.path(6).root(tree.hash(), Fr::from(17u64));
+        assert!(!verify_proof(&pvk, &bundle.proof, &[other]).unwrap());
+        export_for_contract(&bundle, &vk).unwrap();
+    }
+
+    #[test]
+    fn ceremony_keys_prove_membership() {
+        let mut rng = ChaCha20Rng::seed_from_u64(47);
+        let (pk, vk) = merkle_keys().unwrap();
+        assert_eq!(pk.vk, vk);
+        let tree = MerkleTree::new(Poseidon::new(2), MERKLE_DEPTH, &leaves(9));
+        let member = (Fr::from(15u64), tree.path(5));
+        let circuit = MerkleMembership::new(Poseidon::new(2), MERKLE_DEPTH, Some(member));
+        let bundle = prove(circuit, &pk, &mut rng).unwrap();
+        assert_eq!(bundle.public_inputs, vec![tree.root()]);
+        let pvk = prepare_verifying_key(&vk);
+        assert!(verify_proof(&pvk, &bundle.proof, &bundle.public_inputs).unwrap());
+    }
+}
@@ -0,0 +1,303 @@
+//! The MiMC family with circomlib's round constants.
+//!
+//! [`Mimc`] holds the round constants and the exponent of the round function, and computes the
+//! two modes of circomlib both natively and as constraints:
+//!
+//! - the cipher `MiMC-n/n` with exponent 7, hashing inputs in Miyaguchi–Preneel mode like
+//!   circomlib's `MultiMiMC7`,
+//! - the Feistel permutation `MiMC-2n/n` with exponent 5, absorbed in a sponge like circomlib's
+//!   `MiMCSponge`.
+//!
+//! The constants are derived from a seed string: the first one is zero and the `i`-th one is the
+//! big-endian Keccak-256 chain of the seed hashed `i + 1` times, reduced into the field.
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+use crate::gadget::{pow_add, Lc};
+use crate::keccak::keccak256;
+
+/// Seed and rounds of circomlib's `MiMC7`.
+pub const MIMC7_SEED: &str = "mimc";
+pub const MIMC7_ROUNDS: usize = 91;
+/// Seed and rounds of circomlib's `MiMCSponge`.
+pub const MIMC_SPONGE_SEED: &str = "mimcsponge";
+pub const MIMC_SPONGE_ROUNDS: usize = 220;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mimc<F: PrimeField> {
+    pub constants: Vec<F>,
+    /// The exponent of the round function, 3, 5 or 7.
+    pub exponent: u64,
+}
+
+impl<F: PrimeField> Mimc<F> {
+    pub fn new(constants: Vec<F>, exponent: u64) -> Self {
+        assert!(
+            matches!(exponent, 3 | 5 | 7),
+            "the exponent has to be 3, 5 or 7"
+        );
+        Mimc {
+            constants,
+            exponent,
+        }
+    }
+
+    /// Derives `rounds` constants from `seed` as circomlib does.
+    pub fn from_seed(seed: &str, rounds: usize, exponent: u64) -> Self {
+        let mut constants = Vec::with_capacity(rounds);
+        let mut digest = keccak256(seed.as_bytes());
+        for i in 0..rounds {
+            if i == 0 {
+                constants.push(F::zero());
+            } else {
+                digest = keccak256(&digest);
+                constants.push(F::from_be_bytes_mod_order(&digest));
+            }
+        }
+        Mimc::new(constants, exponent)
+    }
+
+    /// circomlib's `MiMC7`.
+    pub fn mimc7() -> Self {
+        Mimc::from_seed(MIMC7_SEED, MIMC7_ROUNDS, 7)
+    }
+
+    /// circomlib's `MiMCSponge`, whose last round constant is zero.
+    pub fn mimc_sponge() -> Self {
+        let mut mimc = Mimc::from_seed(MIMC_SPONGE_SEED, MIMC_SPONGE_ROUNDS, 5);
+        if let Some(last) = mimc.constants.last_mut() {
+            *last = F::zero();
+        }
+        mimc
+    }
+
+    pub fn rounds(&self) -> usize {
+        self.constants.len()
+    }
+
+    /// The input of round `i`, the constant of the first round is left out.
+    fn round_input(&self, i: usize, x: F, k: F) -> F {
+        if i == 0 {
+            x + k
+        } else {
+            x + k + self.constants[i]
+        }
+    }
+
+    /// Encrypts `x` under the key `k`.
+    pub fn cipher(&self, x: F, k: F) -> F {
+        let mut r = x;
+        for i in 0..self.rounds() {
+            r = self.round_input(i, r, k).pow([self.exponent]);
+        }
+        r + k
+    }
+
+    /// Hashes `inputs` with the cipher in Miyaguchi–Preneel mode, starting from `key`.
+    pub fn hash(&self, inputs: &[F], key: F) -> F {
+        inputs.iter().fold(key, |r, x| r + *x + self.cipher(*x, r))
+    }
+
+    /// Permutes `(xl, xr)` under the key `k`, the branches aren't swapped in the last round.
+    pub fn feistel(&self, mut xl: F, mut xr: F, k: F) -> (F, F) {
+        let rounds = self.rounds();
+        for i in 0..rounds {
+            let t = self.round_input(i, xl, k).pow([self.exponent]);
+            if i < rounds - 1 {
+                let next = xr + t;
+                xr = xl;
+                xl = next;
+            } else {
+                xr += t;
+            }
+        }
+        (xl, xr)
+    }
+
+    /// Absorbs `inputs` into the Feistel permutation and squeezes `outputs` elements.
+    pub fn sponge(&self, inputs: &[F], key: F, outputs: usize) -> Vec<F> {
+        let (mut r, mut c) = (F::zero(), F::zero());
+        for x in inputs {
+            let state = self.feistel(r + x, c, key);
+            r = state.0;
+            c = state.1;
+        }
+        let mut squeezed = vec![r];
+        for _ in 1..outputs {
+            let state = self.feistel(r, c, key);
+            r = state.0;
+            c = state.1;
+            squeezed.push(r);
+        }
+        squeezed
+    }
+
+    fn round_input_lc(&self, i: usize, x: &Lc<F>, k: &Lc<F>) -> Lc<F> {
+        let t = x.clone() + k;
+        if i == 0 {
+            t
+        } else {
+            t.add_constant(self.constants[i])
+        }
+    }
+
+    /// Constrains [`Mimc::cipher`].
+    pub fn cipher_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        x: &Lc<F>,
+        k: &Lc<F>,
+    ) -> Result<Lc<F>, SynthesisError> {
+        let mut r = x.clone();
+        for i in 0..self.rounds() {
+            let t = self.round_input_lc(i, &r, k);
+            r = Lc::from(pow_add(cs, &t, self.exponent, &Lc::zero(), false)?);
+        }
+        Ok(r + k)
+    }
+
+    /// Constrains [`Mimc::hash`].
+    pub fn hash_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        inputs: &[Lc<F>],
+        key: &Lc<F>,
+    ) -> Result<Lc<F>, SynthesisError> {
+        let mut r = key.clone();
+        for x in inputs {
+            let encrypted = self.cipher_gadget(cs, x, &r)?;
+            r = r + x + &encrypted;
+        }
+        Ok(r)
+    }
+
+    /// Constrains [`Mimc::feistel`].
+    pub fn feistel_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        xl: &Lc<F>,
+        xr: &Lc<F>,
+        k: &Lc<F>,
+    ) -> Result<(Lc<F>, Lc<F>), SynthesisError> {
+        let rounds = self.rounds();
+        let (mut xl, mut xr) = (xl.clone(), xr.clone());
+        for i in 0..rounds {
+            let t = self.round_input_lc(i, &xl, k);
+            let next = Lc::from(pow_add(cs, &t, self.exponent, &xr, false)?);
+            if i < rounds - 1 {
+                xr = xl;
+                xl = next;
+            } else {
+                xr = next;
+            }
+        }
+        Ok((xl, xr))
+    }
+
+    /// Constrains [`Mimc::sponge`].
+    pub fn sponge_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        inputs: &[Lc<F>],
+        key: &Lc<F>,
+        outputs: usize,
+    ) -> Result<Vec<Lc<F>>, SynthesisError> {
+        let (mut r, mut c) = (Lc::zero(), Lc::zero());
+        for x in inputs {
+            let state = self.feistel_gadget(cs, &(r.clone() + x), &c, key)?;
+            r = state.0;
+            c = state.1;
+        }
+        let mut squeezed = vec![r.clone()];
+        for _ in 1..outputs {
+            let state = self.feistel_gadget(cs, &r, &c, key)?;
+            r = state.0;
+            c = state.1;
+            squeezed.push(r.clone());
+        }
+        Ok(squeezed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use super::*;
+    use crate::gadget::Var;
+
+    fn hex(value: &str) -> Fr {
+        Fr::from_be_bytes_mod_order(&::hex::decode(value).unwrap())
+    }
+
+    fn witnesses(cs: &ConstraintSystemRef<Fr>, values: &[u64]) -> Vec<Lc<Fr>> {
+        values
+            .iter()
+            .map(|value| Lc::from(Var::witness(cs, Some(Fr::from(*value))).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn mimc7_matches_circomlib() {
+        // mimc7.hash(1, 2) of circomlibjs
+        let mimc = Mimc::<Fr>::mimc7();
+        let image = mimc.cipher(Fr::from(1u64), Fr::from(2u64));
+        assert_eq!(
+            image,
+            hex("176c6eefc3fdf8d6136002d8e6f7a885bbd1c4e3957b93ddc1ec3ae7859f1a08")
+        );
+
+        let cs = ConstraintSystem::new_ref();
+        let inputs = witnesses(&cs, &[1, 2]);
+        let gadget = mimc.cipher_gadget(&cs, &inputs[0], &inputs[1]).unwrap();
+        assert_eq!(gadget.value, Some(image));
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(cs.num_constraints(), 4 * MIMC7_ROUNDS);
+
+        let hash = mimc.hash(&[Fr::from(1u64), Fr::from(2u64)], Fr::from(0u64));
+        let cs = ConstraintSystem::new_ref();
+        let inputs = witnesses(&cs, &[1, 2]);
+        let gadget = mimc.hash_gadget(&cs, &inputs, &Lc::zero()).unwrap();
+        assert_eq!(gadget.value, Some(hash));
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn mimc_sponge_matches_circomlib() {
+        // mimcsponge.multiHash([1, 2]) of circomlibjs
+        let mimc = Mimc::<Fr>::mimc_sponge();
+        let hash = mimc.sponge(&[Fr::from(1u64), Fr::from(2u64)], Fr::from(0u64), 1);
+        assert_eq!(
+            hash,
+            vec![hex(
+                "2bcea035a1251603f1ceaf73cd4ae89427c47075bb8e3a944039ff1e3d6d2a6f"
+            )]
+        );
+
+        let cs = ConstraintSystem::new_ref();
+        let inputs = witnesses(&cs, &[1, 2]);
+        let gadget = mimc.sponge_gadget(&cs, &inputs, &Lc::zero(), 1).unwrap();
+        assert_eq!(gadget[0].value, Some(hash[0]));
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(cs.num_constraints(), 2 * 3 * MIMC_SPONGE_ROUNDS);
+
+        let (xl, xr) = mimc.feistel(Fr::from(1u64), Fr::from(2u64), Fr::from(3u64));
+        let cs = ConstraintSystem::new_ref();
+        let inputs = witnesses(&cs, &[1, 2, 3]);
+        let state = mimc
+            .feistel_gadget(&cs, &inputs[0], &inputs[1], &inputs[2])
+            .unwrap();
+        assert_eq!((state.0.value, state.1.value), (Some(xl), Some(xr)));
+        assert!(cs.is_satisfied().unwrap());
+
+        // a wrong intermediate value breaks the constraints
+        let cs = ConstraintSystem::new_ref();
+        let inputs = witnesses(&cs, &[1, 2, 3]);
+        let mut tampered = inputs[1].clone();
+        tampered.value = Some(Fr::from(5u64));
+        mimc.feistel_gadget(&cs, &inputs[0], &tampered, &inputs[2])
+            .unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+}
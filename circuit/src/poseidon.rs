@@ -0,0 +1,355 @@
+//! The Poseidon hash with circomlib's parameters.
+//!
+//! The permutation works on a state of `t = inputs + 1` elements with the S-box `x^5`, 8 full
+//! rounds and the number of partial rounds circomlib uses for `t`. The round constants and the
+//! MDS matrix are generated by the Grain LFSR of the Poseidon reference implementation, which is
+//! how circomlib and arkworks derive them, so hashes agree with circomlib's `Poseidon(n)`. Like
+//! circomlib, the MDS matrix is the first Cauchy matrix drawn.
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::gadget::{pow_add, Lc, Var};
+
+pub const FULL_ROUNDS: usize = 8;
+/// The partial rounds of circomlib for `t = 2` to `t = 17`.
+pub const PARTIAL_ROUNDS: [usize; 16] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+];
+const ALPHA: u64 = 5;
+
+/// The Grain LFSR generating the parameters.
+struct Grain {
+    state: Vec<bool>,
+}
+
+impl Grain {
+    fn new(field_bits: u64, t: u64, full_rounds: u64, partial_rounds: u64) -> Self {
+        let mut state = vec![];
+        // a prime field, the S-box x^alpha, then the sizes
+        for (value, bits) in [
+            (1, 2),
+            (0, 4),
+            (field_bits, 12),
+            (t, 12),
+            (full_rounds, 10),
+            (partial_rounds, 10),
+        ] {
+            state.extend((0..bits).rev().map(|i| (value >> i) & 1 == 1));
+        }
+        state.extend([true; 30]);
+        let mut grain = Grain { state };
+        for _ in 0..160 {
+            grain.next_bit();
+        }
+        grain
+    }
+
+    fn next_bit(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.remove(0);
+        self.state.push(bit);
+        bit
+    }
+
+    /// Bits are drawn in pairs, the second bit is kept if the first one is set.
+    fn filtered_bit(&mut self) -> bool {
+        while !self.next_bit() {
+            self.next_bit();
+        }
+        self.next_bit()
+    }
+
+    /// A big-endian integer of `bits` bits, `None` if it isn't reduced.
+    fn integer<F: PrimeField>(&mut self, bits: usize) -> Option<F> {
+        let bits = (0..bits).map(|_| self.filtered_bit()).collect::<Vec<_>>();
+        let mut repr = F::BigInt::default();
+        for bit in bits {
+            repr.muln(1);
+            if bit {
+                repr.add_nocarry(&F::BigInt::from(1));
+            }
+        }
+        F::from_repr(repr)
+    }
+
+    fn field_element<F: PrimeField>(&mut self, bits: usize) -> F {
+        loop {
+            if let Some(element) = self.integer(bits) {
+                return element;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Poseidon<F: PrimeField> {
+    /// The width of the state.
+    pub t: usize,
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    /// `t` constants per round.
+    pub round_constants: Vec<F>,
+    pub mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> Poseidon<F> {
+    /// The parameters of circomlib's `Poseidon(inputs)`, for 1 to 16 inputs.
+    pub fn new(inputs: usize) -> Self {
+        assert!(
+            (1..=PARTIAL_ROUNDS.len()).contains(&inputs),
+            "Poseidon takes 1 to 16 inputs"
+        );
+        Poseidon::generate(inputs + 1, FULL_ROUNDS, PARTIAL_ROUNDS[inputs - 1])
+    }
+
+    /// Generates the round constants and the MDS matrix of a permutation of width `t`.
+    pub fn generate(t: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let bits = F::Params::MODULUS_BITS as usize;
+        let mut grain = Grain::new(
+            bits as u64,
+            t as u64,
+            full_rounds as u64,
+            partial_rounds as u64,
+        );
+        let round_constants = (0..(full_rounds + partial_rounds) * t)
+            .map(|_| grain.field_element(bits))
+            .collect();
+
+        // a Cauchy matrix 1 / (x_i + y_j) of distinct random elements
+        let mds = loop {
+            let points = (0..2 * t)
+                .map(|_| {
+                    let bits = (0..bits).map(|_| grain.filtered_bit()).collect::<Vec<_>>();
+                    F::from_be_bytes_mod_order(&pack(&bits))
+                })
+                .collect::<Vec<F>>();
+            let distinct = points
+                .iter()
+                .enumerate()
+                .all(|(i, a)| points[..i].iter().all(|b| a != b));
+            let (xs, ys) = points.split_at(t);
+            let mds = xs
+                .iter()
+                .map(|x| {
+                    ys.iter()
+                        .map(|y| (*x + y).inverse())
+                        .collect::<Option<Vec<F>>>()
+                })
+                .collect::<Option<Vec<_>>>();
+            match mds {
+                Some(mds) if distinct => break mds,
+                _ => continue,
+            }
+        };
+
+        Poseidon {
+            t,
+            full_rounds,
+            partial_rounds,
+            round_constants,
+            mds,
+        }
+    }
+
+    fn is_full_round(&self, round: usize) -> bool {
+        let half = self.full_rounds / 2;
+        round < half || round >= half + self.partial_rounds
+    }
+
+    pub fn permute(&self, mut state: Vec<F>) -> Vec<F> {
+        assert_eq!(state.len(), self.t);
+        for round in 0..self.full_rounds + self.partial_rounds {
+            for (i, element) in state.iter_mut().enumerate() {
+                *element += self.round_constants[round * self.t + i];
+            }
+            if self.is_full_round(round) {
+                for element in state.iter_mut() {
+                    *element = element.pow([ALPHA]);
+                }
+            } else {
+                state[0] = state[0].pow([ALPHA]);
+            }
+            state = self
+                .mds
+                .iter()
+                .map(|row| row.iter().zip(&state).map(|(m, x)| *m * x).sum())
+                .collect();
+        }
+        state
+    }
+
+    /// Hashes `t - 1` inputs, the first element of the permuted state `[0, inputs..]`.
+    pub fn hash(&self, inputs: &[F]) -> F {
+        assert_eq!(inputs.len() + 1, self.t);
+        let mut state = vec![F::zero()];
+        state.extend_from_slice(inputs);
+        self.permute(state)[0]
+    }
+
+    /// Constrains [`Poseidon::permute`], an S-box takes three constraints and the linear layers
+    /// none.
+    pub fn permute_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        mut state: Vec<Lc<F>>,
+    ) -> Result<Vec<Lc<F>>, SynthesisError> {
+        assert_eq!(state.len(), self.t);
+        let sbox = |x: &Lc<F>| -> Result<Lc<F>, SynthesisError> {
+            Ok(Lc::from(pow_add(cs, x, ALPHA, &Lc::zero(), false)?))
+        };
+        for round in 0..self.full_rounds + self.partial_rounds {
+            for (i, element) in state.iter_mut().enumerate() {
+                *element = element
+                    .clone()
+                    .add_constant(self.round_constants[round * self.t + i]);
+            }
+            if self.is_full_round(round) {
+                state = state.iter().map(sbox).collect::<Result<_, _>>()?;
+            } else {
+                state[0] = sbox(&state[0])?;
+            }
+            state = self
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(&state)
+                        .fold(Lc::zero(), |sum, (m, x)| sum + &x.clone().scale(*m))
+                })
+                .collect();
+        }
+        Ok(state)
+    }
+
+    /// Constrains [`Poseidon::hash`].
+    pub fn hash_gadget(
+        &self,
+        cs: &ConstraintSystemRef<F>,
+        inputs: &[Lc<F>],
+    ) -> Result<Lc<F>, SynthesisError> {
+        assert_eq!(inputs.len() + 1, self.t);
+        let mut state = vec![Lc::zero()];
+        state.extend_from_slice(inputs);
+        Ok(self.permute_gadget(cs, state)?.swap_remove(0))
+    }
+}
+
+/// Proves the knowledge of a preimage of the public Poseidon hash.
+#[derive(Clone, Debug)]
+pub struct PoseidonPreimage<F: PrimeField> {
+    pub poseidon: Poseidon<F>,
+    /// `t - 1` elements when proving, `None` during the setup.
+    pub preimage: Option<Vec<F>>,
+}
+
+impl<F: PrimeField> PoseidonPreimage<F> {
+    pub fn new(inputs: usize, preimage: Option<Vec<F>>) -> Self {
+        PoseidonPreimage {
+            poseidon: Poseidon::new(inputs),
+            preimage,
+        }
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for PoseidonPreimage<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let inputs = self.poseidon.t - 1;
+        if let Some(preimage) = &self.preimage {
+            if preimage.len() != inputs {
+                return Err(SynthesisError::Unsatisfiable);
+            }
+        }
+        let image = Var::input(
+            &cs,
+            self.preimage
+                .as_ref()
+                .map(|preimage| self.poseidon.hash(preimage)),
+        )?;
+        let preimage = (0..inputs)
+            .map(|i| {
+                let value = self.preimage.as_ref().map(|preimage| preimage[i]);
+                Var::witness(&cs, value).map(Lc::from)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let hash = self.poseidon.hash_gadget(&cs, &preimage)?;
+        hash.enforce_equal(&cs, &Lc::from(image))
+    }
+}
+
+/// Packs big-endian bits into bytes, padding the first byte with zeros.
+fn pack(bits: &[bool]) -> Vec<u8> {
+    let padding = (8 - bits.len() % 8) % 8;
+    let mut padded = vec![false; padding];
+    padded.extend_from_slice(bits);
+    padded
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0u8, |acc, bit| acc << 1 | *bit as u8))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_groth16::{prepare_verifying_key, verify_proof};
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{export_for_contract, prove, setup};
+
+    fn hex(value: &str) -> Fr {
+        Fr::from_be_bytes_mod_order(&::hex::decode(value).unwrap())
+    }
+
+    #[test]
+    fn poseidon_matches_circomlib() {
+        // poseidon([1]) and poseidon([1, 2]) of circomlibjs
+        assert_eq!(
+            Poseidon::new(1).hash(&[Fr::from(1u64)]),
+            hex("29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133")
+        );
+        let poseidon = Poseidon::new(2);
+        let image = poseidon.hash(&[Fr::from(1u64), Fr::from(2u64)]);
+        assert_eq!(
+            image,
+            hex("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a")
+        );
+
+        let cs = ConstraintSystem::new_ref();
+        let inputs = [1u64, 2]
+            .iter()
+            .map(|value| Lc::from(Var::witness(&cs, Some(Fr::from(*value))).unwrap()))
+            .collect::<Vec<_>>();
+        let hash = poseidon.hash_gadget(&cs, &inputs).unwrap();
+        assert_eq!(hash.value, Some(image));
+        assert!(cs.is_satisfied().unwrap());
+        let sboxes = FULL_ROUNDS * poseidon.t + poseidon.partial_rounds;
+        assert_eq!(cs.num_constraints(), 3 * sboxes);
+    }
+
+    #[test]
+    fn poseidon_preimage_is_proven() {
+        let rng = &mut ChaCha20Rng::from_seed([3u8; 32]);
+        let (pk, vk) = setup(PoseidonPreimage::<Fr>::new(2, None), rng).unwrap();
+        let preimage = vec![Fr::from(1u64), Fr::from(2u64)];
+        let bundle = prove(PoseidonPreimage::new(2, Some(preimage)), &pk, rng).unwrap();
+        assert_eq!(
+            bundle.public_inputs,
+            vec![hex(
+                "115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a"
+            )]
+        );
+        assert!(verify_proof(
+            &prepare_verifying_key(&vk),
+            &bundle.proof,
+            &bundle.public_inputs
+        )
+        .unwrap());
+        export_for_contract(&bundle, &vk).unwrap();
+
+        let preimage = vec![Fr::from(1u64)];
+        assert!(prove(PoseidonPreimage::new(2, Some(preimage)), &pk, rng).is_err());
+    }
+}
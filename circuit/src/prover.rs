@@ -0,0 +1,104 @@
+//! Setup, proving and export of Groth16 proofs for the contract.
+//!
+//! [`setup`] generates the keys of a circuit, [`prove`] creates a proof together with its public
+//! inputs and [`export_for_contract`] encodes both into the inputs of the contract's entry points.
+use ark_bn254::{Bn254, Fr, G1Projective};
+use ark_ff::to_bytes;
+use ark_groth16::{
+    create_random_proof, generate_random_parameters, prepare_inputs, prepare_verifying_key, Proof,
+    ProvingKey, VerifyingKey,
+};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, Result as R1CSResult, SynthesisError,
+};
+use ark_std::rand::Rng;
+
+/// A proof and the public inputs it proves.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProofBundle {
+    pub proof: Proof<Bn254>,
+    /// The public inputs, without the leading one of the constraint system.
+    pub public_inputs: Vec<Fr>,
+}
+
+/// The inputs of the contract's entry points for one proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractPayload {
+    /// The public inputs as 32 byte little-endian field elements, the input of
+    /// `prepare_inputs`.
+    pub public_inputs: Vec<u8>,
+    /// The prepared input, the input of `gamma_miller_loop`.
+    pub prepared_input: Vec<u8>,
+    /// The proof's `C`, the input of `delta_miller_loop`.
+    pub proof_c: Vec<u8>,
+    /// The proof's `A` followed by its `B`, the input of `qap_miller_loop`.
+    pub proof_ab: Vec<u8>,
+}
+
+impl ContractPayload {
+    /// The input of `submit` and `submit_with_bounty`.
+    pub fn submit(&self) -> Vec<u8> {
+        [
+            self.public_inputs.as_slice(),
+            self.proof_ab.as_slice(),
+            self.proof_c.as_slice(),
+        ]
+        .concat()
+    }
+}
+
+/// Generates the proving and verifying keys of `circuit`.
+///
+/// Only the shape of the circuit is used, its assignment can be left empty.
+pub fn setup<C: ConstraintSynthesizer<Fr>, R: Rng>(
+    circuit: C,
+    rng: &mut R,
+) -> R1CSResult<(ProvingKey<Bn254>, VerifyingKey<Bn254>)> {
+    let pk = generate_random_parameters::<Bn254, _, _>(circuit, rng)?;
+    let vk = pk.vk.clone();
+    Ok((pk, vk))
+}
+
+/// Proves `circuit` under `pk`, the circuit has to be fully assigned.
+pub fn prove<C: ConstraintSynthesizer<Fr> + Clone, R: Rng>(
+    circuit: C,
+    pk: &ProvingKey<Bn254>,
+    rng: &mut R,
+) -> R1CSResult<ProofBundle> {
+    let public_inputs = public_inputs(circuit.clone())?;
+    let proof = create_random_proof(circuit, pk, rng)?;
+    Ok(ProofBundle {
+        proof,
+        public_inputs,
+    })
+}
+
+/// Synthesizes `circuit` and returns the public inputs it assigns, if it is satisfied.
+pub fn public_inputs<C: ConstraintSynthesizer<Fr>>(circuit: C) -> R1CSResult<Vec<Fr>> {
+    let cs = ConstraintSystem::new_ref();
+    circuit.generate_constraints(cs.clone())?;
+    if !cs.is_satisfied()? {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    let cs = cs.borrow().ok_or(SynthesisError::MissingCS)?;
+    Ok(cs.instance_assignment[1..].to_vec())
+}
+
+/// The prepared input of `public_inputs` under `vk`.
+pub fn prepared_input(vk: &VerifyingKey<Bn254>, public_inputs: &[Fr]) -> R1CSResult<G1Projective> {
+    prepare_inputs(&prepare_verifying_key(vk), public_inputs)
+}
+
+/// Encodes a proof and its public inputs into the inputs of the contract's entry points.
+pub fn export_for_contract(
+    bundle: &ProofBundle,
+    vk: &VerifyingKey<Bn254>,
+) -> R1CSResult<ContractPayload> {
+    let prepared_input = prepared_input(vk, &bundle.public_inputs)?;
+    Ok(ContractPayload {
+        public_inputs: to_bytes!(bundle.public_inputs).unwrap(),
+        prepared_input: to_bytes!(prepared_input).unwrap(),
+        proof_c: to_bytes!(bundle.proof.c).unwrap(),
+        proof_ab: to_bytes!(bundle.proof.a, bundle.proof.b).unwrap(),
+    })
+}
@@ -0,0 +1,162 @@
+//! Range proofs of committed values.
+//!
+//! [`CommittedRange`] proves that the value opened by a public Poseidon commitment lies in
+//! `[0, 2^bits)`. The value is decomposed into `bits` boolean witnesses, so the commitment is
+//! the hash of their weighted sum and no value outside the range has a satisfying assignment.
+//!
+//! The keys of the range `[0, 2^32)` come out of a setup ceremony, see [`range_keys`], and the
+//! contract built with the `range` feature embeds their verifying key.
+use ark_bn254::Bn254;
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_groth16::{ProvingKey, VerifyingKey};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::gadget::{Lc, Var};
+use crate::keys::{read_key, KeyFileError, KeyKind};
+use crate::poseidon::Poseidon;
+
+/// The number of bits of the range the ceremony keys are for.
+pub const RANGE_BITS: usize = 32;
+/// Identifies `CommittedRange::new(RANGE_BITS, _)` in key files.
+pub const RANGE_CIRCUIT_ID: &str = "committed-range-32";
+
+/// The proving and verifying keys of `CommittedRange::new(RANGE_BITS, _)`, exported by the
+/// ceremony in `keys/`.
+pub fn range_keys() -> Result<(ProvingKey<Bn254>, VerifyingKey<Bn254>), KeyFileError> {
+    let pk = read_key(
+        &include_bytes!("../keys/range.pk")[..],
+        KeyKind::Proving,
+        RANGE_CIRCUIT_ID,
+    )?;
+    let vk = read_key(
+        &include_bytes!("../keys/range.vk")[..],
+        KeyKind::Verifying,
+        RANGE_CIRCUIT_ID,
+    )?;
+    Ok((pk, vk))
+}
+
+/// Commits to `value` with the `blinding` factor, `Poseidon(value, blinding)`.
+pub fn commit<F: PrimeField>(value: F, blinding: F) -> F {
+    Poseidon::new(2).hash(&[value, blinding])
+}
+
+/// Proves that the value of the public commitment is below `2^bits`.
+#[derive(Clone, Debug)]
+pub struct CommittedRange<F: PrimeField> {
+    pub poseidon: Poseidon<F>,
+    pub bits: usize,
+    /// The value and the blinding factor when proving, `None` during the setup.
+    pub opening: Option<(F, F)>,
+}
+
+impl<F: PrimeField> CommittedRange<F> {
+    /// The range `[0, 2^bits)`, `bits` has to leave the range below the modulus.
+    pub fn new(bits: usize, opening: Option<(F, F)>) -> Self {
+        assert!(
+            bits <= F::Params::CAPACITY as usize,
+            "the range has to be below the modulus"
+        );
+        CommittedRange {
+            poseidon: Poseidon::new(2),
+            bits,
+            opening,
+        }
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CommittedRange<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let commitment = Var::input(
+            &cs,
+            self.opening
+                .map(|(value, blinding)| self.poseidon.hash(&[value, blinding])),
+        )?;
+
+        let value_bits = self
+            .opening
+            .map(|(value, _)| value.into_repr().to_bits_le());
+        let mut value = Lc::zero();
+        let mut weight = F::one();
+        for i in 0..self.bits {
+            let bit = Var::witness(&cs, value_bits.as_ref().map(|bits| F::from(bits[i] as u64)))?;
+            cs.enforce_constraint(
+                lc!() + bit.variable,
+                lc!() + bit.variable,
+                lc!() + bit.variable,
+            )?;
+            value = value + &Lc::from(bit).scale(weight);
+            weight.double_in_place();
+        }
+        let blinding = Var::witness(&cs, self.opening.map(|(_, blinding)| blinding))?;
+
+        let hash = self
+            .poseidon
+            .hash_gadget(&cs, &[value, Lc::from(blinding)])?;
+        hash.enforce_equal(&cs, &Lc::from(commitment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_groth16::{prepare_verifying_key, verify_proof};
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+    use crate::{prove, setup};
+
+    fn is_satisfied(bits: usize, value: u64) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        let opening = (Fr::from(value), Fr::from(7u64));
+        CommittedRange::new(bits, Some(opening))
+            .generate_constraints(cs.clone())
+            .unwrap();
+        assert_eq!(
+            cs.borrow().unwrap().instance_assignment[1],
+            commit(opening.0, opening.1)
+        );
+        assert_eq!(cs.num_constraints(), bits + 3 * 81 + 1);
+        cs.is_satisfied().unwrap()
+    }
+
+    #[test]
+    fn values_in_range_are_satisfied() {
+        assert!(is_satisfied(8, 0));
+        assert!(is_satisfied(8, 255));
+        assert!(!is_satisfied(8, 256));
+        assert!(!is_satisfied(8, 1000));
+        assert!(is_satisfied(32, u32::MAX as u64));
+        assert!(!is_satisfied(32, 1 << 32));
+    }
+
+    #[test]
+    fn range_is_proven() {
+        let mut rng = ChaCha20Rng::seed_from_u64(48);
+        let (pk, vk) = setup(CommittedRange::new(16, None), &mut rng).unwrap();
+        let opening = (Fr::from(40_000u64), Fr::from(123u64));
+        let bundle = prove(CommittedRange::new(16, Some(opening)), &pk, &mut rng).unwrap();
+        let commitment = commit(opening.0, opening.1);
+        assert_eq!(bundle.public_inputs, vec![commitment]);
+
+        let pvk = prepare_verifying_key(&vk);
+        assert!(verify_proof(&pvk, &bundle.proof, &[commitment]).unwrap());
+        let other = commit(Fr::from(40_001u64), opening.1);
+        assert!(!verify_proof(&pvk, &bundle.proof, &[other]).unwrap());
+    }
+
+    #[test]
+    fn ceremony_keys_prove_ranges() {
+        let mut rng = ChaCha20Rng::seed_from_u64(48);
+        let (pk, vk) = range_keys().unwrap();
+        assert_eq!(pk.vk, vk);
+        let opening = (Fr::from(u32::MAX as u64), Fr::from(5u64));
+        let circuit = CommittedRange::new(RANGE_BITS, Some(opening));
+        let bundle = prove(circuit, &pk, &mut rng).unwrap();
+        let pvk = prepare_verifying_key(&vk);
+        assert!(verify_proof(&pvk, &bundle.proof, &bundle.public_inputs).unwrap());
+    }
+}
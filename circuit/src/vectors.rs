@@ -0,0 +1,275 @@
+//! Known-answer vectors of the contract's verification stages for the demo proof.
+//!
+//! Every value the contract writes to a session while verifying the demo proof is recomputed
+//! here, together with the stage, step and slot it is written by, so a stored dictionary value
+//! can be compared against the expected one after each call.
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use ark_bn254::{Fq12, Fq2, G1Affine};
+use ark_ec::bn::{BnParameters, G2Prepared};
+use ark_ec::ProjectiveCurve;
+use ark_ff::biginteger::arithmetic::find_wnaf;
+use ark_ff::{to_bytes, Field, FromBytes};
+use ark_relations::r1cs::Result as R1CSResult;
+use num_traits::One;
+use serde::{Deserialize, Serialize};
+
+use crate::{demo_proof, ell, EllCoeff};
+
+/// A value written by `step` of `stage` to `slot` of a session.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswer {
+    pub stage: u8,
+    pub step: u8,
+    pub slot: String,
+    /// The uncompressed `Fp12` value, hex encoded.
+    pub value: String,
+}
+
+impl KnownAnswer {
+    fn new(stage: u8, step: usize, slot: &str, value: &Fq12) -> KnownAnswer {
+        KnownAnswer {
+            stage,
+            step: step as u8,
+            slot: slot.to_string(),
+            value: encode(value),
+        }
+    }
+
+    pub fn value(&self) -> Fq12 {
+        decode(&self.value)
+    }
+}
+
+/// The known answers of the demo proof, the inputs of the contract are hex encoded as they are
+/// passed to the entry points.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownAnswers {
+    pub proof_c: String,
+    pub prepared_input: String,
+    pub proof_ab: String,
+    /// Every value the contract stores, in the order the steps run.
+    pub steps: Vec<KnownAnswer>,
+    /// The intermediates `y0` to `y16` of the hard part of the final exponentiation.
+    pub hard_part: Vec<String>,
+}
+
+impl KnownAnswers {
+    /// The value stored by `step` of `stage`, if that step stores one.
+    pub fn find(&self, stage: u8, step: u8) -> Option<&KnownAnswer> {
+        self.steps
+            .iter()
+            .find(|answer| answer.stage == stage && answer.step == step)
+    }
+
+    pub fn write_json<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let file = File::create(path)?;
+        serde_json::to_writer_pretty(file, self)?;
+        Ok(())
+    }
+
+    pub fn read_json<P: AsRef<Path>>(path: P) -> io::Result<KnownAnswers> {
+        let file = File::open(path)?;
+        Ok(serde_json::from_reader(file)?)
+    }
+}
+
+fn encode(value: &Fq12) -> String {
+    hex::encode(to_bytes!(value).unwrap())
+}
+
+fn decode(value: &str) -> Fq12 {
+    Fq12::read(hex::decode(value).unwrap().as_slice()).unwrap()
+}
+
+/// Computes the known answers of the demo proof.
+pub fn known_answers() -> R1CSResult<KnownAnswers> {
+    let (proof, prepared_input, pvk) = demo_proof()?;
+    let mut steps = vec![];
+
+    // Miller loops, slots match `Stage::slot` of the contract.
+    let gamma = miller_loop(
+        &prepared_input.into_affine(),
+        &pvk.gamma_g2_neg_pc.ell_coeffs,
+        0,
+        "gamma",
+        &mut steps,
+    );
+    let delta = miller_loop(
+        &proof.c,
+        &pvk.delta_g2_neg_pc.ell_coeffs,
+        1,
+        "delta",
+        &mut steps,
+    );
+    let qap = miller_loop(
+        &proof.a,
+        &G2Prepared::<ark_bn254::Parameters>::from(proof.b).ell_coeffs,
+        19,
+        "qap",
+        &mut steps,
+    );
+
+    // prepare_final_data and the easy part
+    let f = qap * gamma * delta;
+    steps.push(KnownAnswer::new(2, 0, "final", &f));
+    let mut f1 = f;
+    f1.conjugate();
+    let f1 = f1 * f.inverse().unwrap();
+    steps.push(KnownAnswer::new(3, 0, "final", &f1));
+    let mut r = f1;
+    r.frobenius_map(2);
+    let r = r * f1;
+    steps.push(KnownAnswer::new(4, 0, "final", &r));
+
+    // hard part
+    let y0 = exp_by_neg_x(&r, 5, "y0", &mut steps);
+    let y1 = y0.cyclotomic_square();
+    steps.push(KnownAnswer::new(6, 0, "y1", &y1));
+    let y2 = y1.cyclotomic_square();
+    let y3 = y2 * y1;
+    steps.push(KnownAnswer::new(7, 0, "y3", &y3));
+    let y4 = exp_by_neg_x(&y3, 8, "y4", &mut steps);
+    let y5 = y4.cyclotomic_square();
+    let y6 = exp_by_neg_x(&y5, 9, "y6", &mut steps);
+    let mut y3_inverse = y3;
+    y3_inverse.conjugate();
+    let mut y6_inverse = y6;
+    y6_inverse.conjugate();
+    let y7 = y6_inverse * y4;
+    let y8 = y7 * y3_inverse;
+    steps.push(KnownAnswer::new(10, 0, "y8", &y8));
+    let y9 = y8 * y1;
+    steps.push(KnownAnswer::new(11, 0, "y9", &y9));
+    let y10 = y8 * y4;
+    let y11 = y10 * r;
+    steps.push(KnownAnswer::new(12, 0, "y11", &y11));
+    let mut y12 = y9;
+    y12.frobenius_map(1);
+    let y13 = y12 * y11;
+    steps.push(KnownAnswer::new(13, 0, "y13", &y13));
+    let mut y8_frobenius = y8;
+    y8_frobenius.frobenius_map(2);
+    let y14 = y8_frobenius * y13;
+    steps.push(KnownAnswer::new(14, 0, "y14", &y14));
+    let mut r_inverse = r;
+    r_inverse.conjugate();
+    let mut y15 = r_inverse * y9;
+    y15.frobenius_map(3);
+    steps.push(KnownAnswer::new(15, 0, "y15", &y15));
+    let y16 = y15 * y14;
+
+    let hard_part = [
+        y0, y1, y2, y3, y4, y5, y6, y7, y8, y9, y10, y11, y12, y13, y14, y15, y16,
+    ]
+    .iter()
+    .map(encode)
+    .collect();
+
+    Ok(KnownAnswers {
+        proof_c: hex::encode(to_bytes!(proof.c).unwrap()),
+        prepared_input: hex::encode(to_bytes!(prepared_input).unwrap()),
+        proof_ab: hex::encode(to_bytes!(proof.a, proof.b).unwrap()),
+        steps,
+        hard_part,
+    })
+}
+
+/// Runs a Miller loop step by step like the contract, recording the accumulator after each step.
+fn miller_loop(
+    p: &G1Affine,
+    coeffs: &[EllCoeff<Fq2>],
+    stage: u8,
+    slot: &str,
+    steps: &mut Vec<KnownAnswer>,
+) -> Fq12 {
+    let ate_loop_count = ark_bn254::Parameters::ATE_LOOP_COUNT;
+    let mut f = Fq12::one();
+    let mut j = 0;
+    for i in (1..ate_loop_count.len()).rev() {
+        let step = j;
+        if i != ate_loop_count.len() - 1 {
+            f.square_in_place();
+        }
+        ell(&mut f, &coeffs[j], p);
+        j += 1;
+        if ate_loop_count[i - 1] != 0 {
+            ell(&mut f, &coeffs[j], p);
+            j += 1;
+        }
+        steps.push(KnownAnswer::new(stage, step, slot, &f));
+    }
+    ell(&mut f, &coeffs[j], p);
+    ell(&mut f, &coeffs[j + 1], p);
+    steps.push(KnownAnswer::new(stage, j, slot, &f));
+    f
+}
+
+/// Computes `f^(-x)` one NAF digit per step like the contract, recording every step.
+fn exp_by_neg_x(f: &Fq12, stage: u8, slot: &str, steps: &mut Vec<KnownAnswer>) -> Fq12 {
+    let naf = find_wnaf(ark_bn254::Parameters::X)
+        .into_iter()
+        .rev()
+        .skip_while(|digit| *digit == 0)
+        .collect::<Vec<_>>();
+    let mut f_inverse = *f;
+    f_inverse.conjugate();
+
+    let mut res = Fq12::one();
+    for (j, digit) in naf.iter().enumerate() {
+        if j > 0 {
+            res.square_in_place();
+        }
+        if *digit > 0 {
+            res *= f;
+        } else if *digit < 0 {
+            res *= &f_inverse;
+        }
+        if j == naf.len() - 1 {
+            res.conjugate();
+        }
+        steps.push(KnownAnswer::new(stage, j, slot, &res));
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Bn254;
+    use ark_ec::PairingEngine;
+
+    use super::*;
+    use crate::demo_verifying_key;
+
+    #[test]
+    fn known_answers_match_the_pairing() {
+        let answers = known_answers().unwrap();
+        let pvk = demo_verifying_key();
+        let prepared_input =
+            ark_bn254::G1Projective::read(hex::decode(&answers.prepared_input).unwrap().as_slice())
+                .unwrap();
+        let gamma = answers.find(0, 89).unwrap().value();
+        assert_eq!(
+            gamma,
+            Bn254::miller_loop(&[(
+                prepared_input.into_affine().into(),
+                pvk.gamma_g2_neg_pc.clone()
+            )])
+        );
+
+        let (proof, _, _) = demo_proof().unwrap();
+        let qap = answers.find(19, 89).unwrap().value();
+        assert_eq!(to_bytes!(qap).unwrap(), crate::proof_qap(&proof));
+
+        let f = answers.find(2, 0).unwrap().value();
+        let y16 = decode(&answers.hard_part[16]);
+        assert_eq!(Bn254::final_exponentiation(&f), Some(y16));
+        assert_eq!(y16, pvk.alpha_g1_beta_g2);
+        assert_eq!(
+            answers.find(9, 62).unwrap().value(),
+            decode(&answers.hard_part[6])
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::gas::GasReport;
 use crate::utilities::get_current_time;
 use ark_ec::bn::BnParameters;
 use casper_engine_test_support::{
@@ -9,16 +10,25 @@ use casper_engine_test_support::{
     DEFAULT_UNBONDING_DELAY, DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
 };
 use casper_execution_engine::core::engine_state::{
+    self,
+    deploy_item::DeployItem,
     genesis::{ExecConfig, GenesisAccount},
     run_genesis_request::RunGenesisRequest,
 };
+use casper_execution_engine::core::execution;
 use casper_types::{
     account::AccountHash,
     bytesrepr::{Bytes, FromBytes},
-    runtime_args, CLTyped, ContractHash, Key, Motes, PublicKey, RuntimeArgs, SecretKey,
+    runtime_args, ApiError, CLTyped, ContractHash, Key, Motes, PublicKey, RuntimeArgs, SecretKey,
+    U512,
 };
+use circuit::vectors::KnownAnswers;
 use rand::Rng;
 use std::path::PathBuf;
+use verifier::batch::BATCH_TARGET_BITS;
+use verifier::relay::{relayed_session, schedule};
+use verifier::session::Session;
+use verifier::state::decode_entry;
 
 // contains methods that can simulate a real-world deployment (storing the contract in the blockchain)
 // and transactions to invoke the methods in the contract.
@@ -145,10 +155,21 @@ pub struct Client {
     pub hash: Hash,
     pub ali: AccountHash,
     pub bob: AccountHash,
+    pub gas: GasReport,
+    /// When set, every stored step is compared against these known answers.
+    pub known_answers: Option<KnownAnswers>,
+    /// Block time of the next deploys, in milliseconds.
+    pub block_time: u64,
 }
 
 impl Client {
     pub fn new() -> Client {
+        Client::with_contract("contract.wasm")
+    }
+
+    /// Installs the contract built into `session_code`, e.g. `contract_root.wasm` for the build
+    /// with the `root` feature.
+    pub fn with_contract(session_code: &str) -> Client {
         // ====================== ACCOUNTS SETUP ======================
         let ali = PublicKey::from(&SecretKey::ed25519_from_bytes([3u8; 32]).unwrap());
         let bob = PublicKey::from(&SecretKey::ed25519_from_bytes([6u8; 32]).unwrap());
@@ -174,7 +195,7 @@ impl Client {
         builder.run_genesis(&custom_run_genesis_request).commit();
 
         // ====================== CONTRACT DEPLOYMENT ======================
-        let session_code = PathBuf::from("contract.wasm");
+        let session_code = PathBuf::from(session_code);
         let session_args = runtime_args! {};
 
         let (builder, hash) = Config::deploy_contract(
@@ -191,6 +212,9 @@ impl Client {
             hash,
             ali: ali.to_account_hash(),
             bob: bob.to_account_hash(),
+            gas: GasReport::default(),
+            known_answers: None,
+            block_time: get_current_time() * 1000,
         }
     }
 
@@ -216,6 +240,18 @@ impl Client {
 
     /// call a contract's specific entry point.
     fn call(&mut self, sender: Sender, method: &str, args: RuntimeArgs) {
+        if let Err(error) = self.try_call(sender, method, args) {
+            panic!("{} reverted with {:?}", method, error);
+        }
+    }
+
+    /// call a contract's specific entry point and return the error it reverted with.
+    pub fn try_call(
+        &mut self,
+        sender: Sender,
+        method: &str,
+        args: RuntimeArgs,
+    ) -> Result<(), ApiError> {
         let Sender(address) = sender;
 
         // prepare the deploy item.
@@ -224,17 +260,134 @@ impl Client {
             .with_empty_payment_bytes(runtime_args! {
                 ARG_AMOUNT => *DEFAULT_PAYMENT
             })
-            .with_stored_session_hash(self.hash.into(), method, args)
+            .with_stored_session_hash(self.hash.into(), method, args.clone())
             .with_authorization_keys(&[address])
             .with_address(address)
             .build();
 
+        self.execute(deploy_item, method)?;
+
+        // record the gas consumed by this step.
+        let gas = self.builder.last_exec_gas_cost();
+        self.gas.record(method, &args, gas.value());
+
+        self.check_known_answer(address, method, &args);
+        Ok(())
+    }
+
+    /// run session code and return the error it reverted with.
+    pub fn try_run(
+        &mut self,
+        sender: Sender,
+        session_code: &str,
+        args: RuntimeArgs,
+    ) -> Result<(), ApiError> {
+        let Sender(address) = sender;
+        let mut rng = rand::thread_rng();
+
+        let deploy_item = DeployItemBuilder::new()
+            .with_empty_payment_bytes(runtime_args! {
+                ARG_AMOUNT => *DEFAULT_PAYMENT
+            })
+            .with_session_code(PathBuf::from(session_code), args.clone())
+            .with_deploy_hash(rng.gen())
+            .with_authorization_keys(&[address])
+            .with_address(address)
+            .build();
+
+        self.execute(deploy_item, session_code)?;
+
+        let gas = self.builder.last_exec_gas_cost();
+        self.gas.record(session_code, &args, gas.value());
+        Ok(())
+    }
+
+    /// execute a deploy at the current block time and return the error it reverted with.
+    fn execute(&mut self, deploy_item: DeployItem, name: &str) -> Result<(), ApiError> {
         // prepare the execute request.
-        // we can use .with_block_time() when setting the execute request.
-        let execute_request = ExecuteRequestBuilder::from_deploy_item(deploy_item).build();
+        let execute_request = ExecuteRequestBuilder::from_deploy_item(deploy_item)
+            .with_block_time(self.block_time)
+            .build();
 
         // executes the execute_request.
-        self.builder.exec(execute_request).commit().expect_success();
+        self.builder.exec(execute_request).commit();
+        match self.builder.get_error() {
+            None => Ok(()),
+            Some(engine_state::Error::Exec(execution::Error::Revert(error))) => Err(error),
+            Some(error) => panic!("{} failed without reverting: {:?}", name, error),
+        }
+    }
+
+    /// read the value a step stored in `slot` of the session of `account`.
+    pub fn session_value(&self, account: AccountHash, slot: &str) -> Option<Vec<u8>> {
+        self.stored_value(account.value(), slot)
+    }
+
+    /// read the value stored in `slot` of the session with the given id, e.g. a relayed session.
+    pub fn stored_value(&self, session_id: Hash, slot: &str) -> Option<Vec<u8>> {
+        let data = self
+            .builder
+            .get_contract(ContractHash::new(self.hash))?
+            .named_keys()
+            .get("data")?
+            .into_uref()?;
+        let key = Session::new(session_id).key(slot);
+        let value = self.builder.query_dictionary_item(None, data, &key).ok()?;
+        value.as_cl_value()?.clone().into_t().ok()
+    }
+
+    /// the balance of the purse the contract escrows bounties in.
+    pub fn escrow_balance(&self) -> U512 {
+        let purse = self
+            .builder
+            .get_contract(ContractHash::new(self.hash))
+            .and_then(|contract| contract.named_keys().get("escrow").cloned())
+            .and_then(|key| key.into_uref())
+            .expect("should have an escrow purse");
+        self.builder.get_purse_balance(purse)
+    }
+
+    /// compare the value stored by a verification step against the known answers, if set.
+    fn check_known_answer(&self, account: AccountHash, method: &str, args: &RuntimeArgs) {
+        let answers = match &self.known_answers {
+            Some(answers) => answers,
+            None => return,
+        };
+        let arg = |name: &str| -> u8 {
+            args.get(name)
+                .and_then(|value| value.clone().into_t().ok())
+                .unwrap_or_default()
+        };
+        let stage = match method {
+            "gamma_miller_loop" => 0,
+            "delta_miller_loop" => 1,
+            "qap_miller_loop" => 19,
+            "final_exponentiation" => arg("i"),
+            _ => return,
+        };
+        let step = arg("j");
+        let expected = match answers.find(stage, step) {
+            Some(expected) => expected,
+            None => return,
+        };
+
+        let stored = self
+            .session_value(account, &expected.slot)
+            .unwrap_or_else(|| panic!("{} step {} stored nothing", method, step));
+        let (stored_stage, stored_step, value) = decode_entry(&stored).unwrap();
+        assert_eq!(
+            (stored_stage as u8, stored_step),
+            (stage, step),
+            "{} step {} stored a value of another step",
+            method,
+            step
+        );
+        assert!(
+            value == expected.value(),
+            "{} step {} differs from the known answer",
+            method,
+            step
+        );
     }
 
     pub fn gamma_miller_loop(&mut self, prepared_input: Vec<u8>, key: AccountHash) {
@@ -269,170 +422,261 @@ impl Client {
         self._delta_miller_loop(0, j, proof_c, Sender(key));
     }
 
-    pub fn final_exponentiation(&mut self, qap: Vec<u8>) {
-        let gamma_key = "gamma".to_string();
-        let delta_key = "delta".to_string();
-        let final_key = "final".to_string();
-        // first, create account for y0..y16
-        let mut final_keys = vec![];
-        for i in 0..17 {
-            final_keys.push(["y".to_string(), i.to_string()].join(""));
+    /// Runs the Miller loop of the proof's `A` and `B`, given as `to_bytes!(a, b)`.
+    pub fn qap_miller_loop(&mut self, proof_ab: Vec<u8>, key: AccountHash) {
+        let mut j: u8 = 0;
+        for i in (1..ark_bn254::Parameters::ATE_LOOP_COUNT.len()).rev() {
+            self._qap_miller_loop(i as u8, j, proof_ab.clone(), Sender(key));
+
+            j += 1;
+            if ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] == 1
+                || ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] == -1
+            {
+                j += 1;
+            }
         }
 
-        // prepare_final_data
-        self._final_exponentiation(
-            2,
-            0,
-            qap,
-            vec![gamma_key, delta_key, final_key.clone()],
+        self._qap_miller_loop(0, j, proof_ab, Sender(key));
+    }
+
+    pub fn final_exponentiation(&mut self) {
+        self.final_exponentiation_until_y15();
+
+        // hard_part_y16
+        self._final_exponentiation(16, 0, vec![], Sender(self.ali));
+    }
+
+    /// Commits a batch of proofs, folds all of them and runs the Miller loop of every folded
+    /// proof's `(r_i * A_i, B_i)`.
+    pub fn batch_commit(&mut self, entries: Vec<u8>, size: u8) {
+        self.call(
+            Sender(self.ali),
+            "batch_commit",
+            runtime_args! {
+                "input" => Bytes::from(entries)
+            },
+        );
+
+        for i in 0..size {
+            self.call(Sender(self.ali), "batch_fold", runtime_args! { "i" => i });
+            self.batch_qap_miller_loop();
+        }
+    }
+
+    /// Runs the Miller loop of the last folded proof of the batch.
+    fn batch_qap_miller_loop(&mut self) {
+        let mut j: u8 = 0;
+        for i in (1..ark_bn254::Parameters::ATE_LOOP_COUNT.len()).rev() {
+            self.call(
+                Sender(self.ali),
+                "batch_qap_miller_loop",
+                runtime_args! { "i" => i as u8, "j" => j },
+            );
+
+            j += 1;
+            if ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] == 1
+                || ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] == -1
+            {
+                j += 1;
+            }
+        }
+
+        self.call(
             Sender(self.ali),
+            "batch_qap_miller_loop",
+            runtime_args! { "i" => 0u8, "j" => j },
         );
+    }
+
+    /// Runs both Miller loops over the folded batch.
+    pub fn batch_miller_loop(&mut self) {
+        let mut j: u8 = 0;
+        for i in (1..ark_bn254::Parameters::ATE_LOOP_COUNT.len()).rev() {
+            self._batch_miller_loop(i as u8, j);
+
+            j += 1;
+            if ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] == 1
+                || ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] == -1
+            {
+                j += 1;
+            }
+        }
+
+        self._batch_miller_loop(0, j);
+    }
+
+    /// Runs the final exponentiation of the batch over the Miller loops computed on chain.
+    pub fn batch_final_exponentiation(&mut self) {
+        self.final_exponentiation_until_y15();
+
+        // batch_target
+        for i in 0..BATCH_TARGET_BITS as u8 {
+            self._final_exponentiation(17, i, vec![], Sender(self.ali));
+        }
+
+        // batch_hard_part_y16
+        self._final_exponentiation(18, 0, vec![], Sender(self.ali));
+    }
+
+    /// Runs every final exponentiation stage before the comparison with the verifying key.
+    pub fn final_exponentiation_until_y15(&mut self) {
+        // prepare_final_data
+        self._final_exponentiation(2, 0, vec![], Sender(self.ali));
 
         // easy_part1
-        self._final_exponentiation(3, 0, vec![], vec![final_key.clone()], Sender(self.ali));
+        self._final_exponentiation(3, 0, vec![], Sender(self.ali));
 
         // easy_part2
-        self._final_exponentiation(4, 0, vec![], vec![final_key.clone()], Sender(self.ali));
+        self._final_exponentiation(4, 0, vec![], Sender(self.ali));
 
         // hard_part_y0
         for i in 0..63 {
-            self._final_exponentiation(
-                5,
-                i,
-                vec![],
-                vec![final_key.clone(), final_keys[0].clone()],
-                Sender(self.ali),
-            );
+            self._final_exponentiation(5, i, vec![], Sender(self.ali));
         }
 
         // hard_part_y1
-        self._final_exponentiation(
-            6,
-            64,
-            vec![],
-            vec![final_keys[0].clone(), final_keys[1].clone()],
-            Sender(self.ali),
-        );
+        self._final_exponentiation(6, 0, vec![], Sender(self.ali));
 
         // hard_part_y3
-        self._final_exponentiation(
-            7,
-            0,
-            vec![],
-            vec![final_keys[0].clone(), final_keys[3].clone()],
-            Sender(self.ali),
-        );
+        self._final_exponentiation(7, 0, vec![], Sender(self.ali));
 
         // hard_part_y4
         for i in 0..63 {
-            self._final_exponentiation(
-                8,
-                i,
-                vec![],
-                vec![final_keys[3].clone(), final_keys[4].clone()],
-                Sender(self.ali),
-            );
+            self._final_exponentiation(8, i, vec![], Sender(self.ali));
         }
 
         // hard_part_y6
         for i in 0..63 {
-            self._final_exponentiation(
-                9,
-                i,
-                vec![],
-                vec![final_keys[4].clone(), final_keys[6].clone()],
-                Sender(self.ali),
-            );
+            self._final_exponentiation(9, i, vec![], Sender(self.ali));
         }
 
-        // hard_part_y8
-        self._final_exponentiation(
-            10,
-            0,
-            vec![],
-            vec![
-                final_keys[3].clone(),
-                final_keys[4].clone(),
-                final_keys[6].clone(),
-                final_keys[8].clone(),
-            ],
-            Sender(self.ali),
-        );
+        // hard_part_y8, hard_part_y9, hard_part_y11, hard_part_y13, hard_part_y14 and
+        // hard_part_y15
+        for i in 10..16 {
+            self._final_exponentiation(i, 0, vec![], Sender(self.ali));
+        }
+    }
 
-        // hard_part_y9
-        self._final_exponentiation(
-            11,
-            0,
-            vec![],
-            vec![
-                final_keys[1].clone(),
-                final_keys[8].clone(),
-                final_keys[9].clone(),
-            ],
-            Sender(self.ali),
+    /// Submits public inputs and a proof for relayed verification, returns the session id.
+    pub fn submit(&mut self, input: Vec<u8>, sender: Sender) -> Hash {
+        let Sender(prover) = sender;
+        let session_id = relayed_session(&prover.value(), &input).id();
+        self.call(
+            Sender(prover),
+            "submit",
+            runtime_args! {
+                "input" => Bytes::from(input)
+            },
         );
+        session_id
+    }
 
-        // hard_part_y11
-        self._final_exponentiation(
-            12,
-            0,
-            vec![],
-            vec![
-                final_keys[4].clone(),
-                final_keys[8].clone(),
-                final_key.clone(),
-                final_keys[11].clone(),
-            ],
-            Sender(self.ali),
-        );
+    /// Runs the next step of a relayed session.
+    pub fn step(&mut self, session_id: Hash, sender: Sender) -> Result<(), ApiError> {
+        self.try_call(
+            sender,
+            "step",
+            runtime_args! {
+                "session_id" => session_id
+            },
+        )
+    }
 
-        // hard_part_y13
-        self._final_exponentiation(
-            13,
-            0,
-            vec![],
-            vec![
-                final_keys[9].clone(),
-                final_keys[11].clone(),
-                final_keys[13].clone(),
-            ],
-            Sender(self.ali),
-        );
+    /// Submits public inputs and a proof with a bounty of `amount` motes, paying `fee` per step
+    /// until `ttl` milliseconds from now. Returns the session id.
+    pub fn submit_with_bounty(
+        &mut self,
+        input: Vec<u8>,
+        amount: u64,
+        fee: u64,
+        ttl: u64,
+        sender: Sender,
+    ) -> Hash {
+        let Sender(prover) = sender;
+        let session_id = relayed_session(&prover.value(), &input).id();
+        let args = runtime_args! {
+            "contract_hash" => ContractHash::new(self.hash),
+            "input" => Bytes::from(input),
+            "amount" => amount,
+            "fee" => fee,
+            "ttl" => ttl
+        };
+        if let Err(error) = self.try_run(Sender(prover), "submit_with_bounty.wasm", args) {
+            panic!("submit_with_bounty reverted with {:?}", error);
+        }
+        session_id
+    }
 
-        // hard_part_y14
-        self._final_exponentiation(
-            14,
-            0,
-            vec![],
-            vec![
-                final_keys[8].clone(),
-                final_keys[13].clone(),
-                final_keys[14].clone(),
-            ],
-            Sender(self.ali),
-        );
+    /// Refunds the rest of an expired bounty to its prover.
+    pub fn refund(&mut self, session_id: Hash, sender: Sender) -> Result<(), ApiError> {
+        self.try_call(
+            sender,
+            "refund",
+            runtime_args! {
+                "session_id" => session_id
+            },
+        )
+    }
 
-        // hard_part_y15
-        self._final_exponentiation(
-            15,
-            0,
-            vec![],
-            vec![
-                final_keys[9].clone(),
-                final_key.clone(),
-                final_keys[15].clone(),
-            ],
-            Sender(self.ali),
-        );
+    /// Passes the public inputs of the proof `sender` is about to verify to the contract.
+    pub fn prepare_inputs(&mut self, inputs: Vec<u8>, sender: Sender) -> Result<(), ApiError> {
+        self.try_call(
+            sender,
+            "prepare_inputs",
+            runtime_args! {
+                "input" => Bytes::from(inputs)
+            },
+        )
+    }
 
-        // hard_part_y16
-        self._final_exponentiation(
-            16,
-            0,
-            vec![],
-            vec![final_keys[14].clone(), final_keys[15].clone()],
-            Sender(self.ali),
-        );
+    /// Registers the entry point of another contract to call once the proof of `sender` has
+    /// been accepted.
+    pub fn set_callback(
+        &mut self,
+        contract_hash: Hash,
+        entry_point: &str,
+        sender: Sender,
+    ) -> Result<(), ApiError> {
+        self.try_call(
+            sender,
+            "set_callback",
+            runtime_args! {
+                "contract_hash" => contract_hash,
+                "entry_point" => entry_point.to_string()
+            },
+        )
+    }
+
+    /// Runs the callback of `sender`'s session, a deploy of its own after the final check.
+    pub fn run_callback(&mut self, sender: Sender) -> Result<(), ApiError> {
+        self.try_call(sender, "run_callback", runtime_args! {})
+    }
+
+    /// Registers or revokes a Merkle root, only the account that installed the contract can.
+    pub fn set_root(
+        &mut self,
+        root: Vec<u8>,
+        accepted: bool,
+        sender: Sender,
+    ) -> Result<(), ApiError> {
+        self.try_call(
+            sender,
+            "set_root",
+            runtime_args! {
+                "root" => Bytes::from(root),
+                "accepted" => accepted
+            },
+        )
+    }
+
+    /// Runs every step of a relayed session, alternating between `ali` and `bob`.
+    pub fn relay(&mut self, session_id: Hash) {
+        let relayers = [self.ali, self.bob];
+        for k in 0..schedule().len() {
+            if let Err(error) = self.step(session_id, Sender(relayers[k % 2])) {
+                panic!("step {} reverted with {:?}", k, error);
+            }
+        }
     }
 
     fn _gamma_miller_loop(&mut self, i: u8, j: u8, prepared_input: Vec<u8>, sender: Sender) {
@@ -459,22 +703,35 @@ impl Client {
         );
     }
 
-    fn _final_exponentiation(
-        &mut self,
-        i: u8,
-        j: u8,
-        qap: Vec<u8>,
-        keys: Vec<String>,
-        sender: Sender,
-    ) {
+    fn _qap_miller_loop(&mut self, i: u8, j: u8, proof_ab: Vec<u8>, sender: Sender) {
+        self.call(
+            sender,
+            "qap_miller_loop",
+            runtime_args! {
+                "i" => i,
+                "j" => j,
+                "input" => Bytes::from(proof_ab)
+            },
+        );
+    }
+
+    fn _batch_miller_loop(&mut self, i: u8, j: u8) {
+        let args = runtime_args! {
+            "i" => i,
+            "j" => j
+        };
+        self.call(Sender(self.ali), "batch_gamma_miller_loop", args.clone());
+        self.call(Sender(self.ali), "batch_delta_miller_loop", args);
+    }
+
+    fn _final_exponentiation(&mut self, i: u8, j: u8, input: Vec<u8>, sender: Sender) {
         self.call(
             sender,
             "final_exponentiation",
             runtime_args! {
                 "i" => i,
                 "j" => j,
-                "input" => Bytes::from(qap),
-                "keys" => keys
+                "input" => Bytes::from(input)
             },
         );
     }
@@ -0,0 +1,226 @@
+use casper_types::{RuntimeArgs, U512};
+use serde::Serialize;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Names of the `final_exponentiation` stages, indexed by the `i` argument minus 2, the first
+/// stages being the Miller loops run by entry points of their own.
+const FINAL_EXPONENTIATION_STAGES: [&str; 17] = [
+    "prepare_final_data",
+    "easy_part1",
+    "easy_part2",
+    "hard_part_y0",
+    "hard_part_y1",
+    "hard_part_y3",
+    "hard_part_y4",
+    "hard_part_y6",
+    "hard_part_y8",
+    "hard_part_y9",
+    "hard_part_y11",
+    "hard_part_y13",
+    "hard_part_y14",
+    "hard_part_y15",
+    "hard_part_y16",
+    "batch_target",
+    "batch_hard_part_y16",
+];
+
+/// Gas consumed by all calls of one stage.
+///
+/// Gas is kept as the `U512` the execution engine reports, a stage of many calls can exceed a
+/// `u64` where a single call doesn't.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StageGas {
+    pub stage: String,
+    pub calls: u64,
+    pub gas: U512,
+}
+
+/// Gas consumed by every stage of the verifications run through a client, in call order.
+#[derive(Debug, Default, Serialize)]
+pub struct GasReport {
+    pub stages: Vec<StageGas>,
+}
+
+#[derive(Serialize)]
+struct GasReportFile<'a> {
+    stages: &'a [StageGas],
+    total: StageGas,
+}
+
+impl GasReport {
+    /// Records the gas of one call of `method` with `args`.
+    pub fn record(&mut self, method: &str, args: &RuntimeArgs, gas: U512) {
+        let stage = stage_name(method, args);
+        match self.stages.iter_mut().find(|s| s.stage == stage) {
+            Some(s) => {
+                s.calls += 1;
+                s.gas += gas;
+            }
+            None => self.stages.push(StageGas {
+                stage,
+                calls: 1,
+                gas,
+            }),
+        }
+    }
+
+    pub fn total(&self) -> StageGas {
+        StageGas {
+            stage: "total".to_string(),
+            calls: self.stages.iter().map(|s| s.calls).sum(),
+            gas: self
+                .stages
+                .iter()
+                .fold(U512::zero(), |total, s| total + s.gas),
+        }
+    }
+
+    /// Writes the stages and their total as JSON, gas amounts as decimal strings.
+    pub fn write_json<W: Write>(&self, writer: W) -> io::Result<()> {
+        let report = GasReportFile {
+            stages: &self.stages,
+            total: self.total(),
+        };
+        serde_json::to_writer_pretty(writer, &report)?;
+        Ok(())
+    }
+
+    /// Writes a line per stage and a last one for the total.
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "stage,calls,gas")?;
+        for s in self.stages.iter().chain(std::iter::once(&self.total())) {
+            writeln!(writer, "{},{},{}", s.stage, s.calls, s.gas)?;
+        }
+        Ok(())
+    }
+
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_json(File::create(path)?)
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        self.write_csv(File::create(path)?)
+    }
+}
+
+/// `final_exponentiation` calls are reported per stage, every other entry point by its name.
+fn stage_name(method: &str, args: &RuntimeArgs) -> String {
+    if method != "final_exponentiation" {
+        return method.to_string();
+    }
+    let i: u8 = args
+        .get("i")
+        .and_then(|i| i.clone().into_t().ok())
+        .unwrap_or_default();
+    (i as usize)
+        .checked_sub(2)
+        .and_then(|k| FINAL_EXPONENTIATION_STAGES.get(k))
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| format!("final_exponentiation_{}", i))
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::runtime_args;
+
+    use super::*;
+
+    fn report() -> GasReport {
+        let mut report = GasReport::default();
+        report.record("gamma_miller_loop", &RuntimeArgs::new(), U512::from(10));
+        report.record(
+            "final_exponentiation",
+            &runtime_args! { "i" => 16u8 },
+            U512::from(7),
+        );
+        report.record(
+            "gamma_miller_loop",
+            &RuntimeArgs::new(),
+            U512::from(u64::MAX),
+        );
+        report
+    }
+
+    #[test]
+    fn stages_add_up_calls_and_gas() {
+        let report = report();
+        let gamma = U512::from(u64::MAX) + U512::from(10);
+        assert_eq!(
+            report.stages,
+            vec![
+                StageGas {
+                    stage: "gamma_miller_loop".to_string(),
+                    calls: 2,
+                    gas: gamma,
+                },
+                StageGas {
+                    stage: "hard_part_y16".to_string(),
+                    calls: 1,
+                    gas: U512::from(7),
+                },
+            ]
+        );
+        assert_eq!(
+            report.total(),
+            StageGas {
+                stage: "total".to_string(),
+                calls: 3,
+                gas: gamma + U512::from(7),
+            }
+        );
+    }
+
+    #[test]
+    fn miller_loop_stages_are_not_final_exponentiation_stages() {
+        let mut report = GasReport::default();
+        for i in [0u8, 1, 2, 19] {
+            report.record(
+                "final_exponentiation",
+                &runtime_args! { "i" => i },
+                U512::from(1),
+            );
+        }
+        let stages = report
+            .stages
+            .iter()
+            .map(|s| s.stage.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stages,
+            vec![
+                "final_exponentiation_0",
+                "final_exponentiation_1",
+                "prepare_final_data",
+                "final_exponentiation_19",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_are_written_as_json_and_csv() {
+        let report = report();
+
+        let mut json = vec![];
+        report.write_json(&mut json).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json["stages"][1]["stage"], "hard_part_y16");
+        assert_eq!(json["stages"][1]["calls"], 1);
+        assert_eq!(json["total"]["calls"], 3);
+        assert_eq!(
+            json["total"]["gas"],
+            serde_json::to_value(report.total().gas).unwrap()
+        );
+
+        let mut csv = vec![];
+        report.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "stage,calls,gas\n\
+             gamma_miller_loop,2,18446744073709551625\n\
+             hard_part_y16,1,7\n\
+             total,3,18446744073709551632\n"
+        );
+    }
+}
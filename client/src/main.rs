@@ -1,26 +1,139 @@
-use circuit::initialize;
+use ark_bn254::Fr;
+use ark_ff::to_bytes;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use ark_std::UniformRand;
+use casper_types::crypto::blake2b;
+use circuit::keys::{load_proving_key, load_verifying_key};
+use circuit::range::{range_keys, CommittedRange, RANGE_BITS};
+use circuit::vectors::known_answers;
+use circuit::{
+    batch_qap, demo_payload, demo_prove, demo_verifying_key, export_for_contract, initialize_batch,
+    prove, DEMO_CIRCUIT_ID,
+};
+use rand::Rng;
+use std::path::Path;
+use verifier::batch::batch_challenge;
+use verifier::state::decode_entry;
 
-use crate::client::Client;
+use crate::client::{Client, Sender};
 
 pub mod client;
+pub mod gas;
 pub mod utilities;
 
+#[cfg(test)]
+mod tests;
+
 fn main() {
     // run a circuit demo
-    let (proof_c, prepared_input, qap) = initialize().unwrap();
+    let payload = demo_payload().unwrap();
     println!("run a circuit demo, get input and proof");
 
     let mut contract = Client::new();
+    if std::env::args().any(|arg| arg == "--known-answers") {
+        // compare the value stored by every step against the known answers of the demo proof
+        contract.known_answers = Some(known_answers().unwrap());
+    }
 
     // gamma miller loop
     println!("running gamma miller loop");
-    contract.gamma_miller_loop(prepared_input, contract.ali);
+    contract.gamma_miller_loop(payload.prepared_input.clone(), contract.ali);
 
-    // delta miller loop
+    // delta miller loop, every step of a verification has to come from the same account
     println!("running delta miller loop");
-    contract.delta_miller_loop(proof_c, contract.bob);
+    contract.delta_miller_loop(payload.proof_c.clone(), contract.ali);
+
+    // Miller loop of the proof's A and B
+    println!("running qap miller loop");
+    contract.qap_miller_loop(payload.proof_ab.clone(), contract.ali);
 
     // final exponentiation
     println!("running final exponentiation");
-    contract.final_exponentiation(qap);
+    contract.final_exponentiation();
+    contract.known_answers = None;
+
+    if std::env::args().any(|arg| arg == "--batch") {
+        // verify a batch of proofs with a single pairing check
+        let size = 4;
+        let (entries, pairs) = initialize_batch(size).unwrap();
+        let digest = blake2b(&entries);
+        let challenges = (0..size)
+            .map(|k| batch_challenge(&digest, k))
+            .collect::<Vec<_>>();
+        let qap = batch_qap(&pairs, &challenges);
+        println!("run a batch of {} proofs", size);
+
+        println!("committing and folding the batch");
+        contract.batch_commit(entries, size as u8);
+        let stored = contract.session_value(contract.ali, "qap").unwrap();
+        let (_, _, stored) = decode_entry(&stored).unwrap();
+        assert!(
+            to_bytes!(stored).unwrap() == qap,
+            "the Miller loops of the batch differ from the ones computed off chain"
+        );
+
+        println!("running batch miller loops");
+        contract.batch_miller_loop();
+
+        println!("running batch final exponentiation");
+        contract.batch_final_exponentiation();
+    }
+
+    if std::env::args().any(|arg| arg == "--relay") {
+        // submit the proof once and let two relayers take turns advancing the session
+        println!("submitting a proof for relayed verification");
+        let session_id = contract.submit(payload.submit(), Sender(contract.ali));
+
+        println!("relaying the session");
+        contract.relay(session_id);
+    }
+
+    if let Some(value) = std::env::args().skip_while(|arg| arg != "--range").nth(1) {
+        // prove that a committed value fits in 32 bits, against the contract embedding the key
+        // of the range ceremony, which prepares the commitment itself
+        let value: u64 = value.parse().expect("the value has to be a number");
+        let rng = &mut StdRng::from_seed(rand::thread_rng().gen());
+        let (pk, vk) = range_keys().unwrap();
+        let opening = (Fr::from(value), Fr::rand(rng));
+        let bundle = prove(CommittedRange::new(RANGE_BITS, Some(opening)), &pk, rng).unwrap();
+        let payload = export_for_contract(&bundle, &vk).unwrap();
+        println!("verifying a range proof for a commitment to {}", value);
+        let mut range_contract = Client::with_contract("contract_range.wasm");
+        let ali = range_contract.ali;
+        range_contract
+            .prepare_inputs(payload.public_inputs, Sender(ali))
+            .unwrap();
+        range_contract.gamma_miller_loop(payload.prepared_input, ali);
+        range_contract.delta_miller_loop(payload.proof_c, ali);
+        range_contract.qap_miller_loop(payload.proof_ab, ali);
+        range_contract.final_exponentiation();
+    }
+
+    if let Some(dir) = std::env::args().skip_while(|arg| arg != "--keys").nth(1) {
+        // prove with the key files written by `cargo run -p circuit --bin keys`
+        let dir = Path::new(&dir);
+        let vk = load_verifying_key(dir.join("demo.vk"), DEMO_CIRCUIT_ID).unwrap();
+        assert!(
+            vk == demo_verifying_key().vk,
+            "the key files don't match the key embedded in the contract"
+        );
+        let pk = load_proving_key(dir.join("demo.pk"), DEMO_CIRCUIT_ID).unwrap();
+        let rng = &mut StdRng::from_seed(rand::thread_rng().gen());
+        let bundle = demo_prove(&pk, rng).unwrap();
+        let payload = export_for_contract(&bundle, &vk).unwrap();
+        println!("verifying a proof created with the key files");
+        contract.gamma_miller_loop(payload.prepared_input, contract.ali);
+        contract.delta_miller_loop(payload.proof_c, contract.ali);
+        contract.qap_miller_loop(payload.proof_ab, contract.ali);
+        contract.final_exponentiation();
+    }
+
+    // gas report
+    for stage in contract.gas.stages.iter() {
+        println!("{}: {} calls, {} gas", stage.stage, stage.calls, stage.gas);
+    }
+    let total = contract.gas.total();
+    println!("total: {} calls, {} gas", total.calls, total.gas);
+    contract.gas.save_json("gas_report.json").unwrap();
+    contract.gas.save_csv("gas_report.csv").unwrap();
 }
@@ -0,0 +1,527 @@
+use ark_bn254::{Bn254, Fq, Fq12, Fr, G1Affine, G1Projective};
+use ark_ec::bn::BnParameters;
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{to_bytes, Field, FpParameters, FromBytes, One, PrimeField};
+use ark_groth16::{prepare_verifying_key, PreparedVerifyingKey};
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use casper_types::{
+    bytesrepr::Bytes, runtime_args, AccessRights, ApiError, Key, PublicKey, RuntimeArgs, SecretKey,
+    URef,
+};
+use circuit::blake2b::{digest_inputs, Blake2bPreimage};
+use circuit::merkle::{merkle_keys, MerkleMembership, MerkleTree, MERKLE_DEPTH};
+use circuit::poseidon::Poseidon;
+use circuit::range::{commit, range_keys, CommittedRange, RANGE_BITS};
+use circuit::vectors::known_answers;
+use circuit::{
+    demo_prepared_input, demo_public_input, demo_verifying_key, export_for_contract, initialize,
+    prove, public_inputs,
+};
+use num_bigint::BigUint;
+use verifier::error::Error;
+use verifier::state::Stage;
+
+use crate::client::{Client, Sender};
+
+fn reverted_with(error: Error) -> Result<(), ApiError> {
+    Err(ApiError::from(error))
+}
+
+fn step_args(i: u8, j: u8, input: Vec<u8>) -> RuntimeArgs {
+    runtime_args! {
+        "i" => i,
+        "j" => j,
+        "input" => Bytes::from(input)
+    }
+}
+
+/// Runs a whole verification as `ali` and returns the result of the final comparison.
+fn verify(
+    contract: &mut Client,
+    proof_c: Vec<u8>,
+    prepared_input: Vec<u8>,
+    proof_ab: Vec<u8>,
+) -> Result<(), ApiError> {
+    contract.gamma_miller_loop(prepared_input, contract.ali);
+    contract.delta_miller_loop(proof_c, contract.ali);
+    contract.qap_miller_loop(proof_ab, contract.ali);
+    contract.final_exponentiation_until_y15();
+    contract.try_call(
+        Sender(contract.ali),
+        "final_exponentiation",
+        step_args(16, 0, vec![]),
+    )
+}
+
+#[test]
+fn test_valid_proof() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let mut contract = Client::new();
+
+    assert_eq!(
+        verify(&mut contract, proof_c, prepared_input, proof_ab),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_known_answers() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let mut contract = Client::new();
+    contract.known_answers = Some(known_answers().unwrap());
+
+    assert_eq!(
+        verify(&mut contract, proof_c, prepared_input, proof_ab),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_tampered_proof_c() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let proof_c = G1Affine::read(proof_c.as_slice()).unwrap();
+    let proof_c = proof_c + G1Affine::prime_subgroup_generator();
+    let mut contract = Client::new();
+
+    assert_eq!(
+        verify(
+            &mut contract,
+            to_bytes!(proof_c).unwrap(),
+            prepared_input,
+            proof_ab
+        ),
+        reverted_with(Error::InvalidProof)
+    );
+}
+
+#[test]
+fn test_wrong_public_input() {
+    let (proof_c, _, proof_ab) = initialize().unwrap();
+    let prepared_input = demo_prepared_input(Fr::one()).unwrap();
+    let mut contract = Client::new();
+
+    assert_eq!(
+        verify(&mut contract, proof_c, prepared_input, proof_ab),
+        reverted_with(Error::InvalidProof)
+    );
+}
+
+/// The `qap` that passes the final check of `pvk` with any `C` and prepared input.
+///
+/// It is `T^k / (ML(PI, -gamma) ML(C, -delta))`, where `T` is `e(alpha, beta)` and `k` inverts
+/// the exponent of the final exponentiation modulo `r`. A contract taking the `qap` from the
+/// prover instead of computing the Miller loop of `A` and `B` accepts it.
+fn forged_qap(pvk: &PreparedVerifyingKey<Bn254>, proof_c: &[u8], prepared_input: &[u8]) -> Fq12 {
+    let p = BigUint::from_bytes_le(&to_bytes!(<Fq as PrimeField>::Params::MODULUS).unwrap());
+    let r = BigUint::from_bytes_le(&to_bytes!(<Fr as PrimeField>::Params::MODULUS).unwrap());
+    // the hard part raises to 2x(6x^2 + 3x + 1) times (p^4 - p^2 + 1) / r
+    let x = BigUint::from(ark_bn254::Parameters::X[0]);
+    let exponent = (p.pow(12) - 1u32) / r * 2u32 * &x * (6u32 * &x * &x + 3u32 * &x + 1u32);
+    let k = Fr::from_le_bytes_mod_order(&exponent.to_bytes_le())
+        .inverse()
+        .unwrap();
+
+    let prepared_input = G1Projective::read(prepared_input).unwrap().into_affine();
+    let proof_c = G1Affine::read(proof_c).unwrap();
+    let pairs = [
+        (prepared_input.into(), pvk.gamma_g2_neg_pc.clone()),
+        (proof_c.into(), pvk.delta_g2_neg_pc.clone()),
+    ];
+    let miller_loop = Bn254::miller_loop(pairs.iter());
+    pvk.alpha_g1_beta_g2.pow(k.into_repr()) * miller_loop.inverse().unwrap()
+}
+
+#[test]
+fn test_forged_qap() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let proof_c = G1Affine::read(proof_c.as_slice()).unwrap();
+    let proof_c = to_bytes!(proof_c + G1Affine::prime_subgroup_generator()).unwrap();
+    let pvk = demo_verifying_key();
+    let qap = forged_qap(&pvk, &proof_c, &prepared_input);
+
+    // the forgery passes the pairing check off chain for a tampered C
+    let pairs = [
+        (
+            G1Projective::read(prepared_input.as_slice())
+                .unwrap()
+                .into_affine()
+                .into(),
+            pvk.gamma_g2_neg_pc.clone(),
+        ),
+        (
+            G1Affine::read(proof_c.as_slice()).unwrap().into(),
+            pvk.delta_g2_neg_pc.clone(),
+        ),
+    ];
+    let f = qap * Bn254::miller_loop(pairs.iter());
+    assert_eq!(Bn254::final_exponentiation(&f), Some(pvk.alpha_g1_beta_g2));
+
+    // the contract computes the qap from A and B and takes none from the caller
+    let mut contract = Client::new();
+    let ali = contract.ali;
+    contract.gamma_miller_loop(prepared_input, ali);
+    contract.delta_miller_loop(proof_c, ali);
+    contract.qap_miller_loop(proof_ab, ali);
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "final_exponentiation",
+            step_args(2, 0, to_bytes!(qap).unwrap())
+        ),
+        reverted_with(Error::InvalidInput)
+    );
+    contract.final_exponentiation_until_y15();
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "final_exponentiation",
+            step_args(16, 0, vec![])
+        ),
+        reverted_with(Error::InvalidProof)
+    );
+}
+
+#[test]
+fn test_replayed_steps() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let mut contract = Client::new();
+    let ali = contract.ali;
+
+    for (i, j) in [(64, 0), (63, 2)] {
+        let args = step_args(i, j, prepared_input.clone());
+        assert_eq!(
+            contract.try_call(Sender(ali), "gamma_miller_loop", args),
+            Ok(())
+        );
+    }
+    let args = step_args(63, 2, prepared_input.clone());
+    assert_eq!(
+        contract.try_call(Sender(ali), "gamma_miller_loop", args),
+        reverted_with(Error::UnexpectedStage)
+    );
+
+    contract.gamma_miller_loop(prepared_input, ali);
+    contract.delta_miller_loop(proof_c, ali);
+    contract.qap_miller_loop(proof_ab, ali);
+    for i in 2..4 {
+        let args = step_args(i, 0, vec![]);
+        assert_eq!(
+            contract.try_call(Sender(ali), "final_exponentiation", args),
+            Ok(())
+        );
+    }
+    assert_eq!(
+        contract.try_call(Sender(ali), "final_exponentiation", step_args(3, 0, vec![])),
+        reverted_with(Error::UnexpectedStage)
+    );
+}
+
+#[test]
+fn test_out_of_order_stages() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let mut contract = Client::new();
+    let ali = contract.ali;
+    let bob = contract.bob;
+
+    assert_eq!(
+        contract.try_call(Sender(ali), "final_exponentiation", step_args(4, 0, vec![])),
+        reverted_with(Error::MissingState)
+    );
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "delta_miller_loop",
+            step_args(61, 5, proof_c.clone())
+        ),
+        reverted_with(Error::MissingState)
+    );
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "qap_miller_loop",
+            step_args(61, 5, proof_ab.clone())
+        ),
+        reverted_with(Error::MissingState)
+    );
+    assert_eq!(
+        contract.try_call(Sender(ali), "final_exponentiation", step_args(2, 0, vec![])),
+        reverted_with(Error::MissingState)
+    );
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "final_exponentiation",
+            step_args(40, 0, vec![])
+        ),
+        reverted_with(Error::InvalidStage)
+    );
+
+    // a session belongs to the account that started it
+    contract.gamma_miller_loop(prepared_input, ali);
+    contract.delta_miller_loop(proof_c, ali);
+    contract.qap_miller_loop(proof_ab, ali);
+    assert_eq!(
+        contract.try_call(Sender(bob), "final_exponentiation", step_args(2, 0, vec![])),
+        reverted_with(Error::MissingState)
+    );
+
+    assert_eq!(
+        contract.try_call(Sender(ali), "final_exponentiation", step_args(2, 0, vec![])),
+        Ok(())
+    );
+    assert_eq!(
+        contract.try_call(Sender(ali), "final_exponentiation", step_args(4, 0, vec![])),
+        reverted_with(Error::UnexpectedStage)
+    );
+}
+
+#[test]
+fn test_malformed_lengths() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let mut contract = Client::new();
+    let ali = contract.ali;
+
+    let truncated = prepared_input[..prepared_input.len() - 1].to_vec();
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "gamma_miller_loop",
+            step_args(64, 0, truncated)
+        ),
+        reverted_with(Error::InvalidInput)
+    );
+
+    let mut extended = proof_c;
+    extended.push(0);
+    assert_eq!(
+        contract.try_call(Sender(ali), "delta_miller_loop", step_args(64, 0, extended)),
+        reverted_with(Error::InvalidInput)
+    );
+
+    let truncated = proof_ab[..proof_ab.len() / 2].to_vec();
+    assert_eq!(
+        contract.try_call(Sender(ali), "qap_miller_loop", step_args(64, 0, truncated)),
+        reverted_with(Error::InvalidInput)
+    );
+
+    let args = runtime_args! { "input" => Bytes::from(vec![0u8; 100]) };
+    assert_eq!(
+        contract.try_call(Sender(ali), "batch_commit", args),
+        reverted_with(Error::InvalidBatch)
+    );
+}
+
+#[test]
+fn test_relayed_session() {
+    let (proof_c, _, proof_ab) = initialize().unwrap();
+    let input = [to_bytes!(demo_public_input()).unwrap(), proof_ab, proof_c].concat();
+    let mut contract = Client::new();
+    let bob = contract.bob;
+
+    assert_eq!(
+        contract.step([0u8; 32], Sender(bob)),
+        reverted_with(Error::MissingState)
+    );
+    let session_id = contract.submit(input, Sender(contract.ali));
+    contract.relay(session_id);
+    assert_eq!(
+        contract.step(session_id, Sender(bob)),
+        reverted_with(Error::UnexpectedStage)
+    );
+}
+
+#[test]
+fn test_relayer_bounty() {
+    let (proof_c, _, proof_ab) = initialize().unwrap();
+    let input = [to_bytes!(demo_public_input()).unwrap(), proof_ab, proof_c].concat();
+    let mut contract = Client::new();
+    let ali = contract.ali;
+    let bob = contract.bob;
+
+    let session_id = contract.submit_with_bounty(input, 1_000_000, 1_000, 60_000, Sender(ali));
+    assert_eq!(contract.escrow_balance(), 1_000_000.into());
+    assert_eq!(
+        contract.refund(session_id, Sender(bob)),
+        reverted_with(Error::BountyLocked)
+    );
+
+    contract.relay(session_id);
+    // the relayers were paid and the rest went back to the prover
+    assert_eq!(contract.escrow_balance(), 0.into());
+    assert_eq!(contract.stored_value(session_id, "bounty"), Some(vec![]));
+    assert_eq!(
+        contract.refund(session_id, Sender(bob)),
+        reverted_with(Error::MissingState)
+    );
+}
+
+#[test]
+fn test_only_admin_sets_roots() {
+    let root = to_bytes!(demo_public_input()).unwrap();
+    let mut contract = Client::new();
+    let ali = contract.ali;
+    let bob = contract.bob;
+
+    // ali installed the contract
+    assert_eq!(
+        contract.set_root(root.clone(), true, Sender(bob)),
+        reverted_with(Error::Forbidden)
+    );
+    assert_eq!(
+        contract.set_root(vec![0xff; 32], true, Sender(ali)),
+        reverted_with(Error::InvalidInput)
+    );
+    assert_eq!(contract.set_root(root.clone(), true, Sender(ali)), Ok(()));
+    assert_eq!(contract.set_root(root, false, Sender(ali)), Ok(()));
+}
+
+#[test]
+fn test_forged_membership_proof() {
+    let rng = &mut StdRng::seed_from_u64(47);
+    let (pk, vk) = merkle_keys().unwrap();
+    let leaves = (0..9u64).map(|i| Fr::from(i + 10)).collect::<Vec<_>>();
+    let tree = MerkleTree::new(Poseidon::new(2), MERKLE_DEPTH, &leaves);
+    let member = (leaves[5], tree.path(5));
+    let circuit = MerkleMembership::new(Poseidon::new(2), MERKLE_DEPTH, Some(member));
+    let bundle = prove(circuit, &pk, rng).unwrap();
+    let payload = export_for_contract(&bundle, &vk).unwrap();
+    let root = to_bytes!(tree.root()).unwrap();
+    assert_eq!(payload.public_inputs, root);
+
+    let mut contract = Client::with_contract("contract_root.wasm");
+    let ali = contract.ali;
+    assert_eq!(
+        contract.prepare_inputs(root.clone(), Sender(ali)),
+        reverted_with(Error::UnknownRoot)
+    );
+    assert_eq!(contract.set_root(root.clone(), true, Sender(ali)), Ok(()));
+
+    // without a leaf of the tree, a prover can only forge the qap for a C of its choice
+    let forged_c = G1Affine::read(payload.proof_c.as_slice()).unwrap();
+    let forged_c = to_bytes!(forged_c + G1Affine::prime_subgroup_generator()).unwrap();
+    let qap = forged_qap(
+        &prepare_verifying_key(&vk),
+        &forged_c,
+        &payload.prepared_input,
+    );
+    assert_eq!(contract.prepare_inputs(root.clone(), Sender(ali)), Ok(()));
+    contract.gamma_miller_loop(payload.prepared_input.clone(), ali);
+    contract.delta_miller_loop(forged_c, ali);
+    contract.qap_miller_loop(payload.proof_ab.clone(), ali);
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "final_exponentiation",
+            step_args(2, 0, to_bytes!(qap).unwrap())
+        ),
+        reverted_with(Error::InvalidInput)
+    );
+    contract.final_exponentiation_until_y15();
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "final_exponentiation",
+            step_args(16, 0, vec![])
+        ),
+        reverted_with(Error::InvalidProof)
+    );
+
+    // the proof of membership itself is accepted against the registered root
+    assert_eq!(contract.prepare_inputs(root, Sender(ali)), Ok(()));
+    assert_eq!(
+        verify(
+            &mut contract,
+            payload.proof_c,
+            payload.prepared_input,
+            payload.proof_ab
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn test_range_proof() {
+    let rng = &mut StdRng::seed_from_u64(48);
+    let (pk, vk) = range_keys().unwrap();
+    let opening = (Fr::from(1_000_000u64), Fr::from(77u64));
+    let bundle = prove(CommittedRange::new(RANGE_BITS, Some(opening)), &pk, rng).unwrap();
+    let payload = export_for_contract(&bundle, &vk).unwrap();
+    let mut contract = Client::with_contract("contract_range.wasm");
+    let ali = contract.ali;
+    assert_eq!(
+        contract.prepare_inputs(payload.public_inputs.clone(), Sender(ali)),
+        Ok(())
+    );
+    let result = verify(
+        &mut contract,
+        payload.proof_c.clone(),
+        payload.prepared_input.clone(),
+        payload.proof_ab.clone(),
+    );
+    assert_eq!(result, Ok(()));
+
+    // the proof doesn't open a commitment to another value, the contract prepares the
+    // commitment itself and rejects the prepared input of the proof
+    let other = to_bytes!(commit(Fr::from(1_000_001u64), opening.1)).unwrap();
+    assert_eq!(contract.prepare_inputs(other, Sender(ali)), Ok(()));
+    assert_eq!(
+        contract.try_call(
+            Sender(ali),
+            "gamma_miller_loop",
+            step_args(64, 0, payload.prepared_input)
+        ),
+        reverted_with(Error::InvalidInput)
+    );
+}
+
+#[test]
+fn test_reverting_callback() {
+    let (proof_c, prepared_input, proof_ab) = initialize().unwrap();
+    let mut contract = Client::new();
+    let ali = contract.ali;
+
+    // `refund` reverts without its `session_id` argument, as any target could
+    let target = contract.hash;
+    assert_eq!(contract.set_callback(target, "refund", Sender(ali)), Ok(()));
+    assert_eq!(
+        contract.run_callback(Sender(ali)),
+        reverted_with(Error::MissingState)
+    );
+    let public_input = to_bytes!(demo_public_input()).unwrap();
+    assert_eq!(contract.prepare_inputs(public_input, Sender(ali)), Ok(()));
+    assert_eq!(
+        verify(&mut contract, proof_c, prepared_input, proof_ab),
+        Ok(())
+    );
+    assert_eq!(
+        contract.run_callback(Sender(ali)),
+        Err(ApiError::MissingArgument)
+    );
+
+    // the final check ran in a deploy of its own and stays accepted, the callback registered
+    let y16 = contract.session_value(ali, Stage::HardPartY16.slot());
+    assert!(y16.map_or(false, |y16| !y16.is_empty()));
+    let callback = contract.session_value(ali, "callback");
+    assert!(callback.map_or(false, |callback| !callback.is_empty()));
+    assert_eq!(contract.session_value(ali, "callback_status"), None);
+}
+
+#[test]
+fn test_blake2b_matches_casper_hashes() {
+    let public_key = PublicKey::from(&SecretKey::ed25519_from_bytes([3u8; 32]).unwrap());
+    let key_bytes = Vec::<u8>::from(&public_key);
+    let circuit = Blake2bPreimage::account_hash("ed25519", key_bytes.len(), Some(key_bytes));
+    assert_eq!(
+        public_inputs(circuit).unwrap(),
+        digest_inputs::<Fr>(&public_key.to_account_hash().value())
+    );
+
+    let seed = URef::new([5u8; 32], AccessRights::READ_ADD_WRITE);
+    let item = b"inputs".to_vec();
+    let addr = match Key::dictionary(seed, &item) {
+        Key::Dictionary(addr) => addr,
+        key => panic!("not a dictionary key: {:?}", key),
+    };
+    let circuit = Blake2bPreimage::dictionary_item_key(seed.addr(), item.len(), Some(item));
+    assert_eq!(public_inputs(circuit).unwrap(), digest_inputs::<Fr>(&addr));
+}
@@ -0,0 +1,57 @@
+#![no_main]
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use verifier::batch::{
+    batch_commit_handler, batch_delta_miller_loop_handler, batch_fold_handler,
+    batch_gamma_miller_loop_handler, batch_qap_miller_loop_handler,
+};
+use verifier::final_exponentiation::final_exponentiation_handler;
+use verifier::pvk::EmbeddedKey;
+use verifier::session::Session;
+use verifier::storage::MemoryStorage;
+
+#[derive(Arbitrary, Debug)]
+enum Step {
+    Commit(Vec<u8>),
+    Fold(u8),
+    QapMillerLoop(u8, u8),
+    GammaMillerLoop(u8, u8),
+    DeltaMillerLoop(u8, u8),
+    FinalExponentiation(u8, u8),
+}
+
+fuzz_target!(|steps: Vec<Step>| {
+    let mut storage = MemoryStorage::new();
+    let session = Session::new([0u8; 32]);
+    for step in steps {
+        let _ = match step {
+            Step::Commit(input) => batch_commit_handler(&mut storage, &session, &input),
+            Step::Fold(k) => batch_fold_handler(&mut storage, &session, k as usize),
+            Step::QapMillerLoop(i, j) => {
+                batch_qap_miller_loop_handler(&mut storage, &session, i as usize, j as usize)
+            }
+            Step::GammaMillerLoop(i, j) => batch_gamma_miller_loop_handler(
+                &mut storage,
+                &EmbeddedKey,
+                &session,
+                i as usize,
+                j as usize,
+            ),
+            Step::DeltaMillerLoop(i, j) => batch_delta_miller_loop_handler(
+                &mut storage,
+                &EmbeddedKey,
+                &session,
+                i as usize,
+                j as usize,
+            ),
+            Step::FinalExponentiation(t, j) => final_exponentiation_handler(
+                &mut storage,
+                &EmbeddedKey,
+                &session,
+                t as usize,
+                j as usize,
+                &[],
+            ),
+        };
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+use ark_bn254::{Fq12, G1Affine, G1Projective};
+use libfuzzer_sys::fuzz_target;
+use verifier::compression::decompress;
+use verifier::state::{decode_entry, read_input};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = decode_entry(data);
+    let _ = decompress(data);
+    let _ = read_input::<G1Projective>(data);
+    let _ = read_input::<G1Affine>(data);
+    let _ = read_input::<Fq12>(data);
+});
@@ -0,0 +1,49 @@
+#![no_main]
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use verifier::final_exponentiation::final_exponentiation_handler;
+use verifier::pvk::EmbeddedKey;
+use verifier::session::Session;
+use verifier::state::Stage;
+use verifier::storage::{MemoryStorage, Storage};
+
+/// Arbitrary session values, written to the slot of `stage` before any step runs.
+#[derive(Arbitrary, Debug)]
+struct Stored {
+    stage: u8,
+    value: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Step {
+    t: u8,
+    j: u8,
+    input: Vec<u8>,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    session: [u8; 32],
+    stored: Vec<Stored>,
+    steps: Vec<Step>,
+}
+
+fuzz_target!(|input: Input| {
+    let mut storage = MemoryStorage::new();
+    let session = Session::new(input.session);
+    for stored in input.stored {
+        if let Some(stage) = Stage::from_u8(stored.stage) {
+            storage.set("data", &session.key(stage.slot()), stored.value);
+        }
+    }
+    for step in input.steps {
+        let _ = final_exponentiation_handler(
+            &mut storage,
+            &EmbeddedKey,
+            &session,
+            step.t as usize,
+            step.j as usize,
+            &step.input,
+        );
+    }
+});
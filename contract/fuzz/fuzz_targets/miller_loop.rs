@@ -0,0 +1,31 @@
+#![no_main]
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use verifier::miller_loop::{
+    delta_miller_loop_handler, gamma_miller_loop_handler, qap_miller_loop_handler,
+};
+use verifier::pvk::EmbeddedKey;
+use verifier::session::Session;
+use verifier::storage::MemoryStorage;
+
+#[derive(Arbitrary, Debug)]
+struct Step {
+    /// The Miller loop, gamma, delta or qap, taken modulo 3.
+    stage: u8,
+    i: u8,
+    j: u8,
+    input: Vec<u8>,
+}
+
+fuzz_target!(|steps: Vec<Step>| {
+    let mut storage = MemoryStorage::new();
+    let session = Session::new([0u8; 32]);
+    for step in steps {
+        let (i, j) = (step.i as usize, step.j as usize);
+        let _ = match step.stage % 3 {
+            0 => gamma_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &step.input),
+            1 => delta_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &step.input),
+            _ => qap_miller_loop_handler(&mut storage, &session, i, j, &step.input),
+        };
+    }
+});
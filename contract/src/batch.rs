@@ -0,0 +1,285 @@
+//! Batch verification of many proofs under one verifying key.
+//!
+//! The proofs of a batch are folded with random scalars `r_i` derived from the hash of the
+//! committed batch, so a single pairing check covers all of them:
+//!
+//!   prod e(A_i, B_i)^r_i = e(alpha, beta)^R * e(sum r_i * PI_i, gamma) * e(sum r_i * C_i, delta)
+//!
+//! with `R = sum r_i`. Every folded proof runs through the Miller loop of `(r_i * A_i, B_i)`
+//! before the next one is folded, the product of these loops is the `qap` of the final
+//! exponentiation. The folded input and `C` run through the regular Miller loop stages and the
+//! last stage compares against `e(alpha, beta)^R`.
+use ark_bn254::{Fq12, Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, FromBytes, One, PrimeField, Zero};
+use arrayref::array_ref;
+use types::crypto::blake2b;
+
+use crate::error::Error;
+use crate::miller_loop::{
+    delta_miller_loop_handler, gamma_miller_loop_handler, qap_miller_loop_handler, PROOF_AB_LEN,
+};
+use crate::pvk::VerifyingKey;
+use crate::session::Session;
+use crate::state::{load, reset, store, Stage};
+use crate::storage::Storage;
+
+const G1_PROJECTIVE_LEN: usize = 96;
+const G1_AFFINE_LEN: usize = 65;
+const G2_AFFINE_LEN: usize = 129;
+
+/// Length of one batch entry: the prepared public input (`G1Projective`) followed by the proof
+/// elements `A` (`G1Affine`), `B` (`G2Affine`) and `C` (`G1Affine`).
+pub const BATCH_ENTRY_LEN: usize =
+    G1_PROJECTIVE_LEN + G1_AFFINE_LEN + G2_AFFINE_LEN + G1_AFFINE_LEN;
+pub const MAX_BATCH_SIZE: usize = 255;
+/// Number of bits of `R` processed by the batch target stage, enough for 255 128-bit scalars.
+pub const BATCH_TARGET_BITS: usize = 136;
+
+const FQ12_LEN: usize = 384;
+const BATCH_STATE_LEN: usize = 32 + 3 + 2 * G1_PROJECTIVE_LEN + 32 + FQ12_LEN + PROOF_AB_LEN;
+
+struct BatchState {
+    digest: [u8; 32],
+    size: u8,
+    folded: u8,
+    /// The number of folded proofs whose Miller loop has finished.
+    looped: u8,
+    input: G1Projective,
+    proof_c: G1Projective,
+    exponent: Fr,
+    /// The product of the finished Miller loops.
+    qap: Fq12,
+    /// `r_k * A_k` and `B_k` of the last folded proof, the input of its Miller loop.
+    proof_ab: Vec<u8>,
+}
+
+impl BatchState {
+    fn read<S: Storage>(storage: &S, session: &Session) -> Result<BatchState, Error> {
+        let src = storage
+            .get("data", &session.key("batch"))
+            .ok_or(Error::MissingState)?;
+        if src.len() != BATCH_STATE_LEN {
+            return Err(Error::InvalidState);
+        }
+        let mut reader = &src[35..];
+        let mut read = || -> Result<(G1Projective, G1Projective, Fr, Fq12), std::io::Error> {
+            Ok((
+                G1Projective::read(&mut reader)?,
+                G1Projective::read(&mut reader)?,
+                Fr::read(&mut reader)?,
+                Fq12::read(&mut reader)?,
+            ))
+        };
+        let (input, proof_c, exponent, qap) = read().map_err(|_| Error::InvalidState)?;
+        Ok(BatchState {
+            digest: *array_ref![src, 0, 32],
+            size: src[32],
+            folded: src[33],
+            looped: src[34],
+            input,
+            proof_c,
+            exponent,
+            qap,
+            proof_ab: reader.to_vec(),
+        })
+    }
+
+    fn write<S: Storage>(&self, storage: &mut S, session: &Session) {
+        let mut dst = self.digest.to_vec();
+        dst.push(self.size);
+        dst.push(self.folded);
+        dst.push(self.looped);
+        dst.extend(to_bytes!(self.input, self.proof_c, self.exponent, self.qap).unwrap());
+        dst.extend_from_slice(&self.proof_ab);
+        storage.set("data", &session.key("batch"), dst);
+    }
+
+    /// Reads a batch whose proofs have all been folded and run through their Miller loops.
+    fn read_folded<S: Storage>(storage: &S, session: &Session) -> Result<BatchState, Error> {
+        let state = BatchState::read(storage, session)?;
+        if state.folded != state.size || state.looped != state.size {
+            return Err(Error::UnexpectedStage);
+        }
+        Ok(state)
+    }
+}
+
+/// The decoded elements of a batch entry.
+struct Entry {
+    prepared_input: G1Projective,
+    proof_a: G1Affine,
+    proof_b: G2Affine,
+    proof_c: G1Affine,
+}
+
+fn read_entry(entry: &[u8]) -> Result<Entry, Error> {
+    if entry.len() != BATCH_ENTRY_LEN {
+        return Err(Error::InvalidBatch);
+    }
+    let mut reader = entry;
+    let mut read = || -> Result<Entry, std::io::Error> {
+        Ok(Entry {
+            prepared_input: G1Projective::read(&mut reader)?,
+            proof_a: G1Affine::read(&mut reader)?,
+            proof_b: G2Affine::read(&mut reader)?,
+            proof_c: G1Affine::read(&mut reader)?,
+        })
+    };
+    read().map_err(|_| Error::InvalidBatch)
+}
+
+/// The scalar `r_k` of the `k`-th proof of the batch with the given digest.
+///
+/// Clients have to derive the same scalars to compute the `qap` of the batch.
+pub fn batch_challenge(digest: &[u8; 32], k: usize) -> Fr {
+    let mut src = digest.to_vec();
+    src.extend_from_slice(&(k as u32).to_le_bytes());
+    Fr::from_le_bytes_mod_order(&blake2b(src)[..16])
+}
+
+/// Stores the entries of a new batch in `session`, discarding any previous batch and any
+/// verification in progress.
+pub fn batch_commit_handler<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    input: &[u8],
+) -> Result<(), Error> {
+    let size = input.len() / BATCH_ENTRY_LEN;
+    if input.len() % BATCH_ENTRY_LEN != 0 || size == 0 || size > MAX_BATCH_SIZE {
+        return Err(Error::InvalidBatch);
+    }
+
+    for entry in input.chunks(BATCH_ENTRY_LEN) {
+        let entry = read_entry(entry)?;
+        if !entry.proof_a.is_on_curve()
+            || !entry.proof_b.is_on_curve()
+            || !entry.proof_c.is_on_curve()
+        {
+            return Err(Error::InvalidBatch);
+        }
+    }
+
+    reset(storage, session);
+    storage.set("data", &session.key("batch_proofs"), input.to_vec());
+    BatchState {
+        digest: blake2b(input),
+        size: size as u8,
+        folded: 0,
+        looped: 0,
+        input: G1Projective::zero(),
+        proof_c: G1Projective::zero(),
+        exponent: Fr::zero(),
+        qap: Fq12::one(),
+        proof_ab: vec![0u8; PROOF_AB_LEN],
+    }
+    .write(storage, session);
+    Ok(())
+}
+
+/// Folds the `k`-th proof of the committed batch into the accumulated input and `C`, and makes
+/// it the input of [`batch_qap_miller_loop_handler`].
+///
+/// The Miller loop of the previous proof has to be finished.
+pub fn batch_fold_handler<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    k: usize,
+) -> Result<(), Error> {
+    let mut state = BatchState::read(storage, session)?;
+    if k != state.folded as usize || k != state.looped as usize || k >= state.size as usize {
+        return Err(Error::UnexpectedStage);
+    }
+
+    let proofs = storage
+        .get("data", &session.key("batch_proofs"))
+        .ok_or(Error::MissingState)?;
+    let entry = proofs
+        .chunks(BATCH_ENTRY_LEN)
+        .nth(k)
+        .ok_or(Error::InvalidState)?;
+    let entry = read_entry(entry).map_err(|_| Error::InvalidState)?;
+
+    let r = batch_challenge(&state.digest, k);
+    state.input += &entry.prepared_input.mul(r.into_repr());
+    state.proof_c += &entry.proof_c.mul(r.into_repr());
+    state.exponent += &r;
+    let proof_a = entry.proof_a.mul(r.into_repr()).into_affine();
+    state.proof_ab = to_bytes!(proof_a, entry.proof_b).unwrap();
+    state.folded += 1;
+    state.write(storage, session);
+    Ok(())
+}
+
+/// Runs step `j` of the Miller loop of the last folded proof, `(r_k * A_k, B_k)`.
+///
+/// The last step multiplies the loop into the product of the earlier ones. The product becomes
+/// the output of [`Stage::QapMillerLoop`] only once every proof of the batch has been looped,
+/// until then that stage has no complete output.
+pub fn batch_qap_miller_loop_handler<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    i: usize,
+    j: usize,
+) -> Result<(), Error> {
+    let mut state = BatchState::read(storage, session)?;
+    if state.looped + 1 != state.folded {
+        return Err(Error::UnexpectedStage);
+    }
+    qap_miller_loop_handler(storage, session, i, j, &state.proof_ab)?;
+    if j != Stage::QapMillerLoop.last_step() as usize {
+        return Ok(());
+    }
+
+    state.qap *= load(storage, session, Stage::QapMillerLoop)?;
+    state.looped += 1;
+    if state.looped == state.size {
+        store(storage, session, Stage::QapMillerLoop, j, &state.qap);
+    } else {
+        storage.set("data", &session.key(Stage::QapMillerLoop.slot()), vec![]);
+    }
+    state.write(storage, session);
+    Ok(())
+}
+
+pub fn batch_gamma_miller_loop_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    i: usize,
+    j: usize,
+) -> Result<(), Error> {
+    let state = BatchState::read_folded(storage, session)?;
+    gamma_miller_loop_handler(
+        storage,
+        vk,
+        session,
+        i,
+        j,
+        to_bytes!(state.input).unwrap().as_slice(),
+    )
+}
+
+pub fn batch_delta_miller_loop_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    i: usize,
+    j: usize,
+) -> Result<(), Error> {
+    let state = BatchState::read_folded(storage, session)?;
+    let proof_c = state.proof_c.into_affine();
+    delta_miller_loop_handler(
+        storage,
+        vk,
+        session,
+        i,
+        j,
+        to_bytes!(proof_c).unwrap().as_slice(),
+    )
+}
+
+/// The exponent `R = sum r_i` of the pairing target of the folded batch.
+pub fn batch_exponent<S: Storage>(storage: &S, session: &Session) -> Result<Fr, Error> {
+    Ok(BatchState::read_folded(storage, session)?.exponent)
+}
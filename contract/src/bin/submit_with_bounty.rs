@@ -0,0 +1,41 @@
+//! Session code submitting a proof with a bounty for its relayers.
+//!
+//! Entry points of a stored contract can't take motes from the caller's main purse, so the
+//! deposit is moved to a new purse here and handed to the verifier's `submit_with_bounty`.
+#![no_main]
+
+use contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use types::{bytesrepr::Bytes, runtime_args, ContractHash, RuntimeArgs, U512};
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let contract_hash: ContractHash = runtime::get_named_arg("contract_hash");
+    let input: Bytes = runtime::get_named_arg("input");
+    let amount: u64 = runtime::get_named_arg("amount");
+    let fee: u64 = runtime::get_named_arg("fee");
+    let ttl: u64 = runtime::get_named_arg("ttl");
+
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(
+        account::get_main_purse(),
+        purse,
+        U512::from(amount),
+        None,
+    )
+    .unwrap_or_revert();
+
+    runtime::call_contract::<()>(
+        contract_hash,
+        "submit_with_bounty",
+        runtime_args! {
+            "input" => input,
+            "amount" => amount,
+            "fee" => fee,
+            "ttl" => ttl,
+            "purse" => purse
+        },
+    );
+}
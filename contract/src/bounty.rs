@@ -0,0 +1,174 @@
+//! Fees for the relayers of a session, paid from an escrowed deposit of the prover.
+//!
+//! A prover submitting a proof with [`submit_with_bounty_handler`] deposits an amount into the
+//! contract's escrow purse and sets the fee of a step. Every step run through
+//! [`relay_step_handler`] pays the fee to the relayer while the deposit lasts. Whatever is left
+//! goes back to the prover once the verification finishes, or through [`refund_handler`] once
+//! the bounty has expired.
+use crate::error::Error;
+use crate::pvk::VerifyingKey;
+use crate::relay::{step_handler, submit_handler, Step};
+use crate::session::Session;
+use crate::state::Stage;
+use crate::storage::Storage;
+
+const BOUNTY_LEN: usize = 32 + 3 * 8;
+
+/// Moves motes between the escrow purse of the contract and accounts.
+pub trait Escrow {
+    /// Moves `amount` from the purse passed by the prover into the escrow purse.
+    fn deposit(&mut self, amount: u64) -> Result<(), Error>;
+    /// Pays `amount` from the escrow purse to `account`.
+    fn pay(&mut self, account: &[u8; 32], amount: u64) -> Result<(), Error>;
+}
+
+/// The escrowed deposit of a relayed session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bounty {
+    pub prover: [u8; 32],
+    /// The fee paid for every step.
+    pub fee: u64,
+    /// What is left of the deposit.
+    pub balance: u64,
+    /// Block time in milliseconds after which the prover can take back the balance.
+    pub expires_at: u64,
+}
+
+impl Bounty {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut dst = self.prover.to_vec();
+        dst.extend_from_slice(&self.fee.to_le_bytes());
+        dst.extend_from_slice(&self.balance.to_le_bytes());
+        dst.extend_from_slice(&self.expires_at.to_le_bytes());
+        dst
+    }
+
+    fn from_bytes(src: &[u8]) -> Result<Bounty, Error> {
+        if src.len() != BOUNTY_LEN {
+            return Err(Error::InvalidState);
+        }
+        let mut prover = [0u8; 32];
+        prover.copy_from_slice(&src[..32]);
+        let mut words = src[32..].chunks(8).map(|word| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(word);
+            u64::from_le_bytes(bytes)
+        });
+        Ok(Bounty {
+            prover,
+            fee: words.next().unwrap(),
+            balance: words.next().unwrap(),
+            expires_at: words.next().unwrap(),
+        })
+    }
+}
+
+/// The bounty of `session`, if it has one that has not been paid out.
+pub fn read_bounty<S: Storage>(storage: &S, session: &Session) -> Result<Option<Bounty>, Error> {
+    match storage.get("data", &session.key("bounty")) {
+        Some(src) if !src.is_empty() => Bounty::from_bytes(&src).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn write_bounty<S: Storage>(storage: &mut S, session: &Session, bounty: Option<&Bounty>) {
+    let value = bounty.map_or(vec![], Bounty::to_bytes);
+    storage.set("data", &session.key("bounty"), value);
+}
+
+/// The terms of a bounty set by the prover.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deposit {
+    /// The amount escrowed for the session.
+    pub amount: u64,
+    /// The fee paid for every step.
+    pub fee: u64,
+    /// Block time in milliseconds after which the prover can take back the balance.
+    pub expires_at: u64,
+}
+
+/// Submits a proof like [`submit_handler`] and escrows a deposit to pay its relayers.
+///
+/// Submitting the same proof again refunds the balance of the earlier bounty first.
+pub fn submit_with_bounty_handler<S: Storage, K: VerifyingKey, E: Escrow>(
+    storage: &mut S,
+    vk: &K,
+    escrow: &mut E,
+    prover: &[u8; 32],
+    input: &[u8],
+    deposit: Deposit,
+) -> Result<Session, Error> {
+    if deposit.amount == 0 || deposit.fee == 0 || deposit.fee > deposit.amount {
+        return Err(Error::InvalidInput);
+    }
+    let session = submit_handler(storage, vk, prover, input)?;
+    if let Some(bounty) = read_bounty(storage, &session)? {
+        if bounty.balance > 0 {
+            escrow.pay(&bounty.prover, bounty.balance)?;
+        }
+    }
+
+    escrow.deposit(deposit.amount)?;
+    let bounty = Bounty {
+        prover: *prover,
+        fee: deposit.fee,
+        balance: deposit.amount,
+        expires_at: deposit.expires_at,
+    };
+    write_bounty(storage, &session, Some(&bounty));
+    Ok(session)
+}
+
+/// Runs the next step of a relayed session and pays its fee to `relayer`.
+///
+/// The last step refunds the rest of the deposit to the prover.
+pub fn relay_step_handler<S: Storage, K: VerifyingKey, E: Escrow>(
+    storage: &mut S,
+    vk: &K,
+    escrow: &mut E,
+    session: &Session,
+    relayer: &[u8; 32],
+) -> Result<Step, Error> {
+    let step = step_handler(storage, vk, session)?;
+    let mut bounty = match read_bounty(storage, session)? {
+        Some(bounty) => bounty,
+        None => return Ok(step),
+    };
+
+    let fee = bounty.fee.min(bounty.balance);
+    if fee > 0 {
+        escrow.pay(relayer, fee)?;
+        bounty.balance -= fee;
+    }
+    let last = Step::FinalExponentiation {
+        t: Stage::HardPartY16 as u8,
+        j: 0,
+    };
+    if step == last {
+        if bounty.balance > 0 {
+            escrow.pay(&bounty.prover, bounty.balance)?;
+        }
+        write_bounty(storage, session, None);
+    } else {
+        write_bounty(storage, session, Some(&bounty));
+    }
+    Ok(step)
+}
+
+/// Refunds the balance of an expired bounty to its prover.
+pub fn refund_handler<S: Storage, E: Escrow>(
+    storage: &mut S,
+    escrow: &mut E,
+    session: &Session,
+    now: u64,
+) -> Result<(), Error> {
+    let bounty = read_bounty(storage, session)?.ok_or(Error::MissingState)?;
+    if now < bounty.expires_at {
+        return Err(Error::BountyLocked);
+    }
+    if bounty.balance > 0 {
+        escrow.pay(&bounty.prover, bounty.balance)?;
+    }
+    write_bounty(storage, session, None);
+    Ok(())
+}
@@ -0,0 +1,125 @@
+//! Calls into another contract once a proof has been accepted.
+//!
+//! A session can carry a callback, a contract hash and the name of one of its entry points.
+//! Once the final pairing check of a single proof has passed, the `run_callback` entry point
+//! calls it with the id of the verifying key and the public inputs of the proof, so the target
+//! contract can act on the verified statement.
+//!
+//! The entry point returns a `u32` status, zero on success, which is recorded in the session.
+//! A target that reverts reverts the whole deploy calling it, which is why the callback runs in
+//! a deploy of its own: the accepted proof stays accepted, and the callback stays registered to
+//! be run again.
+use crate::error::Error;
+use crate::inputs::read_inputs;
+use crate::pvk::VerifyingKey;
+use crate::session::Session;
+use crate::state::{load, reset, Stage};
+use crate::storage::Storage;
+
+/// Entry point names of Casper contracts are short, longer names are rejected.
+pub const MAX_ENTRY_POINT_LEN: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Callback {
+    pub contract_hash: [u8; 32],
+    pub entry_point: String,
+}
+
+impl Callback {
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut dst = self.contract_hash.to_vec();
+        dst.extend_from_slice(self.entry_point.as_bytes());
+        dst
+    }
+
+    fn from_bytes(src: &[u8]) -> Result<Callback, Error> {
+        if src.len() <= 32 {
+            return Err(Error::InvalidState);
+        }
+        let mut contract_hash = [0u8; 32];
+        contract_hash.copy_from_slice(&src[..32]);
+        let entry_point = String::from_utf8(src[32..].to_vec()).map_err(|_| Error::InvalidState)?;
+        Ok(Callback {
+            contract_hash,
+            entry_point,
+        })
+    }
+}
+
+/// Calls the entry point of a callback, on chain through `runtime::call_contract`.
+pub trait Invoker {
+    /// Calls `callback` with the id of the verifying key and the public inputs and returns the
+    /// status returned by the entry point.
+    fn invoke(&mut self, callback: &Callback, vk_id: [u8; 32], inputs: &[u8]) -> u32;
+}
+
+/// Registers the callback of `session`, replacing any earlier one.
+///
+/// The callback needs the public inputs of the proof, so the session starts over and its inputs
+/// have to be prepared on chain by [`crate::inputs::prepare_inputs_handler`].
+pub fn set_callback_handler<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    contract_hash: [u8; 32],
+    entry_point: &str,
+) -> Result<(), Error> {
+    if entry_point.is_empty() || entry_point.len() > MAX_ENTRY_POINT_LEN {
+        return Err(Error::InvalidInput);
+    }
+    let callback = Callback {
+        contract_hash,
+        entry_point: entry_point.to_string(),
+    };
+    reset(storage, session);
+    storage.set("data", &session.key("callback"), callback.to_bytes());
+    Ok(())
+}
+
+/// The callback registered for `session` that has not run yet.
+pub fn read_callback<S: Storage>(
+    storage: &S,
+    session: &Session,
+) -> Result<Option<Callback>, Error> {
+    match storage.get("data", &session.key("callback")) {
+        Some(src) if !src.is_empty() => Callback::from_bytes(&src).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Runs the callback of `session` once its proof has been accepted, i.e. once `hard_part_y16`
+/// has stored the result of the final check.
+///
+/// The callback runs once, its status is written to the `callback_status` slot. If the target
+/// reverts, so does the deploy and none of this is written.
+pub fn run_callback_handler<S: Storage, K: VerifyingKey, I: Invoker>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    invoker: &mut I,
+) -> Result<(), Error> {
+    let callback = read_callback(storage, session)?.ok_or(Error::MissingState)?;
+    if load(storage, session, Stage::HardPartY16)? != vk.alpha_g1_beta_g2() {
+        return Err(Error::InvalidProof);
+    }
+    let (_, inputs) = read_inputs(storage, session)?;
+
+    storage.set("data", &session.key("callback"), vec![]);
+    let status = invoker.invoke(&callback, vk.id(), &inputs);
+    storage.set(
+        "data",
+        &session.key("callback_status"),
+        status.to_le_bytes().to_vec(),
+    );
+    Ok(())
+}
+
+/// The status returned by the last callback of `session`, if one ran.
+pub fn callback_status<S: Storage>(storage: &S, session: &Session) -> Option<u32> {
+    let src = storage.get("data", &session.key("callback_status"))?;
+    let mut status = [0u8; 4];
+    if src.len() != status.len() {
+        return None;
+    }
+    status.copy_from_slice(&src);
+    Some(u32::from_le_bytes(status))
+}
@@ -0,0 +1,91 @@
+use ark_bn254::{Fq12Parameters, Fq6Parameters};
+use ark_ff::{to_bytes, Field, Fp12, Fp12Parameters, Fp6, FromBytes, One, Zero};
+
+/// Length of a compressed element: a one byte tag followed by an `Fp6` element.
+pub const BN254_COMPRESSED_DATA_LEN: usize = 193;
+
+const FP6_DATA_LEN: usize = 192;
+
+const TAG_TORUS: u8 = 0;
+const TAG_ONE: u8 = 1;
+
+// After the easy part of the final exponentiation every value `g = g0 + g1 * w` satisfies
+// `g * conj(g) = 1`, so it lies on the algebraic torus T2(Fp6) and is determined by the single
+// `Fp6` element `c = (1 + g0) / g1`. Decompression recovers it as `g = (c + w) / (c - w)`:
+//
+//   g0 = (c^2 + v) / (c^2 - v)
+//   g1 = 2c / (c^2 - v)
+//
+// where `v` is the non-residue with `w^2 = v`. The only element that has no such `c` is
+// `g = 1`, which is stored under its own tag. `g = -1` maps to `c = 0`.
+
+/// Compresses an element of the cyclotomic subgroup of `Fp12`.
+pub fn compress(g: &Fp12<Fq12Parameters>) -> Vec<u8> {
+    let mut dst = Vec::with_capacity(BN254_COMPRESSED_DATA_LEN);
+    if g.c1.is_zero() {
+        if g.c0.is_one() {
+            dst.push(TAG_ONE);
+            dst.extend_from_slice(&[0u8; FP6_DATA_LEN]);
+            return dst;
+        }
+        dst.push(TAG_TORUS);
+        dst.extend_from_slice(to_bytes!(Fp6::<Fq6Parameters>::zero()).unwrap().as_slice());
+        return dst;
+    }
+
+    let c = (Fp6::<Fq6Parameters>::one() + g.c0) * g.c1.inverse().unwrap();
+    dst.push(TAG_TORUS);
+    dst.extend_from_slice(to_bytes!(c).unwrap().as_slice());
+    dst
+}
+
+/// Decompresses an element written by [`compress`], `None` if `src` is not a compressed element.
+pub fn decompress(src: &[u8]) -> Option<Fp12<Fq12Parameters>> {
+    if src.len() != BN254_COMPRESSED_DATA_LEN {
+        return None;
+    }
+    match src[0] {
+        TAG_ONE => return Some(Fp12::<Fq12Parameters>::one()),
+        TAG_TORUS => {}
+        _ => return None,
+    }
+
+    let c = Fp6::<Fq6Parameters>::read(&mut src[1..].as_ref()).ok()?;
+    let c_square = c.square();
+    let v = Fq12Parameters::mul_fp6_by_nonresidue(&Fp6::<Fq6Parameters>::one());
+    let denominator = (c_square - v).inverse()?;
+
+    Some(Fp12::<Fq12Parameters>::new(
+        (c_square + v) * denominator,
+        c.double() * denominator,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_ff::UniformRand;
+
+    #[test]
+    fn test_compress_round_trip() {
+        let mut rng = ark_std::test_rng();
+        for _ in 0..10 {
+            let f = Fp12::<Fq12Parameters>::rand(&mut rng);
+            // Map f into the cyclotomic subgroup with the easy part of the final exponentiation.
+            let mut g = f;
+            g.conjugate();
+            g *= f.inverse().unwrap();
+            let h = g;
+            g.frobenius_map(2);
+            g *= h;
+
+            let compressed = compress(&g);
+            assert_eq!(compressed.len(), BN254_COMPRESSED_DATA_LEN);
+            assert_eq!(decompress(&compressed), Some(g));
+        }
+
+        let one = Fp12::<Fq12Parameters>::one();
+        assert_eq!(decompress(&compress(&one)), Some(one));
+        assert_eq!(decompress(&compress(&-one)), Some(-one));
+    }
+}
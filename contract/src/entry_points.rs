@@ -34,6 +34,18 @@ pub fn delta_miller_loop() -> EntryPoint {
     )
 }
 
+pub fn qap_miller_loop() -> EntryPoint {
+    endpoint(
+        "qap_miller_loop",
+        vec![
+            Parameter::new("i", CLType::U8),
+            Parameter::new("j", CLType::U8),
+            Parameter::new("input", CLType::List(Box::new(CLType::U8))),
+        ],
+        CLType::Unit,
+    )
+}
+
 pub fn final_exponentiation() -> EntryPoint {
     endpoint(
         "final_exponentiation",
@@ -41,7 +53,127 @@ pub fn final_exponentiation() -> EntryPoint {
             Parameter::new("i", CLType::U8),
             Parameter::new("j", CLType::U8),
             Parameter::new("input", CLType::List(Box::new(CLType::U8))),
-            Parameter::new("keys", CLType::List(Box::new(CLType::String))),
+        ],
+        CLType::Unit,
+    )
+}
+
+pub fn prepare_inputs() -> EntryPoint {
+    endpoint(
+        "prepare_inputs",
+        vec![Parameter::new("input", CLType::List(Box::new(CLType::U8)))],
+        CLType::Unit,
+    )
+}
+
+pub fn set_callback() -> EntryPoint {
+    endpoint(
+        "set_callback",
+        vec![
+            Parameter::new("contract_hash", CLType::ByteArray(32)),
+            Parameter::new("entry_point", CLType::String),
+        ],
+        CLType::Unit,
+    )
+}
+
+pub fn run_callback() -> EntryPoint {
+    endpoint("run_callback", vec![], CLType::Unit)
+}
+
+pub fn submit() -> EntryPoint {
+    endpoint(
+        "submit",
+        vec![Parameter::new("input", CLType::List(Box::new(CLType::U8)))],
+        CLType::Unit,
+    )
+}
+
+pub fn step() -> EntryPoint {
+    endpoint(
+        "step",
+        vec![Parameter::new("session_id", CLType::ByteArray(32))],
+        CLType::Unit,
+    )
+}
+
+pub fn submit_with_bounty() -> EntryPoint {
+    endpoint(
+        "submit_with_bounty",
+        vec![
+            Parameter::new("input", CLType::List(Box::new(CLType::U8))),
+            Parameter::new("amount", CLType::U64),
+            Parameter::new("fee", CLType::U64),
+            Parameter::new("ttl", CLType::U64),
+            Parameter::new("purse", CLType::URef),
+        ],
+        CLType::Unit,
+    )
+}
+
+pub fn refund() -> EntryPoint {
+    endpoint(
+        "refund",
+        vec![Parameter::new("session_id", CLType::ByteArray(32))],
+        CLType::Unit,
+    )
+}
+
+pub fn set_root() -> EntryPoint {
+    endpoint(
+        "set_root",
+        vec![
+            Parameter::new("root", CLType::List(Box::new(CLType::U8))),
+            Parameter::new("accepted", CLType::Bool),
+        ],
+        CLType::Unit,
+    )
+}
+
+pub fn batch_commit() -> EntryPoint {
+    endpoint(
+        "batch_commit",
+        vec![Parameter::new("input", CLType::List(Box::new(CLType::U8)))],
+        CLType::Unit,
+    )
+}
+
+pub fn batch_fold() -> EntryPoint {
+    endpoint(
+        "batch_fold",
+        vec![Parameter::new("i", CLType::U8)],
+        CLType::Unit,
+    )
+}
+
+pub fn batch_qap_miller_loop() -> EntryPoint {
+    endpoint(
+        "batch_qap_miller_loop",
+        vec![
+            Parameter::new("i", CLType::U8),
+            Parameter::new("j", CLType::U8),
+        ],
+        CLType::Unit,
+    )
+}
+
+pub fn batch_gamma_miller_loop() -> EntryPoint {
+    endpoint(
+        "batch_gamma_miller_loop",
+        vec![
+            Parameter::new("i", CLType::U8),
+            Parameter::new("j", CLType::U8),
+        ],
+        CLType::Unit,
+    )
+}
+
+pub fn batch_delta_miller_loop() -> EntryPoint {
+    endpoint(
+        "batch_delta_miller_loop",
+        vec![
+            Parameter::new("i", CLType::U8),
+            Parameter::new("j", CLType::U8),
         ],
         CLType::Unit,
     )
@@ -51,6 +183,20 @@ pub fn default() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
     entry_points.add_entry_point(gamma_miller_loop());
     entry_points.add_entry_point(delta_miller_loop());
+    entry_points.add_entry_point(qap_miller_loop());
     entry_points.add_entry_point(final_exponentiation());
+    entry_points.add_entry_point(prepare_inputs());
+    entry_points.add_entry_point(set_callback());
+    entry_points.add_entry_point(run_callback());
+    entry_points.add_entry_point(submit());
+    entry_points.add_entry_point(step());
+    entry_points.add_entry_point(submit_with_bounty());
+    entry_points.add_entry_point(refund());
+    entry_points.add_entry_point(set_root());
+    entry_points.add_entry_point(batch_commit());
+    entry_points.add_entry_point(batch_fold());
+    entry_points.add_entry_point(batch_qap_miller_loop());
+    entry_points.add_entry_point(batch_gamma_miller_loop());
+    entry_points.add_entry_point(batch_delta_miller_loop());
     entry_points
 }
@@ -9,8 +9,9 @@ use types::ApiError;
 /// return those via the [`Error::User`] variant or equivalently via the [`ApiError::User`]
 /// variant.
 ///
-/// Such a user error should be in the range `[0..(u16::MAX - 30)]` (i.e. [0, 65505]) to avoid
+/// Such a user error should be in the range `[0..(u16::MAX - 41)]` (i.e. [0, 65494]) to avoid
 /// conflicting with the other `Error` variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error {
     /// ERC20 contract called from within an invalid context.
     InvalidContext,
@@ -74,6 +75,26 @@ pub enum Error {
     InvalidToken,
     /// Trying to deploy a payment contract while providing an invalid deposit entry_point name.
     InvalidDepositEntryPointName,
+    /// A verification step read a value that was never written.
+    MissingState,
+    /// A verification step read a value written by another stage, or by a later step.
+    UnexpectedStage,
+    /// The requested verification stage does not exist.
+    InvalidStage,
+    /// The committed batch is empty, too large or not made of whole entries.
+    InvalidBatch,
+    /// The proof does not satisfy the pairing check of the verifying key.
+    InvalidProof,
+    /// An argument has the wrong length or does not encode the expected value.
+    InvalidInput,
+    /// A stored value of the session could not be decoded.
+    InvalidState,
+    /// The nullifier of the proof has already been spent.
+    NullifierSpent,
+    /// The bounty of the session has not expired yet.
+    BountyLocked,
+    /// The Merkle root among the public inputs is not registered.
+    UnknownRoot,
     /// User error.
     User(u16),
 }
@@ -110,6 +131,16 @@ const ERROR_NO_RESERVES: u16 = u16::MAX - 27; // 65508
 const ERROR_PERIOD_NOT_ELAPSED: u16 = u16::MAX - 28; // 65507
 const ERROR_INVALID_TOKEN: u16 = u16::MAX - 29; // 65506
 const ERROR_INVALID_DEPOSIT_ENTRY_POINT_NAME: u16 = u16::MAX - 30; // 65505
+const ERROR_MISSING_STATE: u16 = u16::MAX - 31; // 65504
+const ERROR_UNEXPECTED_STAGE: u16 = u16::MAX - 32; // 65503
+const ERROR_INVALID_STAGE: u16 = u16::MAX - 33; // 65502
+const ERROR_INVALID_BATCH: u16 = u16::MAX - 34; // 65501
+const ERROR_INVALID_PROOF: u16 = u16::MAX - 35; // 65500
+const ERROR_INVALID_INPUT: u16 = u16::MAX - 36; // 65499
+const ERROR_INVALID_STATE: u16 = u16::MAX - 37; // 65498
+const ERROR_NULLIFIER_SPENT: u16 = u16::MAX - 38; // 65497
+const ERROR_BOUNTY_LOCKED: u16 = u16::MAX - 39; // 65496
+const ERROR_UNKNOWN_ROOT: u16 = u16::MAX - 40; // 65495
 
 impl From<Error> for ApiError {
     fn from(error: Error) -> Self {
@@ -145,6 +176,16 @@ impl From<Error> for ApiError {
             Error::PeriodNotElapsed => ERROR_PERIOD_NOT_ELAPSED,
             Error::InvalidToken => ERROR_INVALID_TOKEN,
             Error::InvalidDepositEntryPointName => ERROR_INVALID_DEPOSIT_ENTRY_POINT_NAME,
+            Error::MissingState => ERROR_MISSING_STATE,
+            Error::UnexpectedStage => ERROR_UNEXPECTED_STAGE,
+            Error::InvalidStage => ERROR_INVALID_STAGE,
+            Error::InvalidBatch => ERROR_INVALID_BATCH,
+            Error::InvalidProof => ERROR_INVALID_PROOF,
+            Error::InvalidInput => ERROR_INVALID_INPUT,
+            Error::InvalidState => ERROR_INVALID_STATE,
+            Error::NullifierSpent => ERROR_NULLIFIER_SPENT,
+            Error::BountyLocked => ERROR_BOUNTY_LOCKED,
+            Error::UnknownRoot => ERROR_UNKNOWN_ROOT,
             Error::User(user_error) => user_error,
         };
         ApiError::User(user_error)
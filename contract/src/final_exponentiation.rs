@@ -1,9 +1,16 @@
+use std::convert::TryFrom;
+
 use ark_bn254::Fq12Parameters;
-use ark_ff::{Field, Fp12, Fp12ParamsWrapper, FromBytes, QuadExtField};
-use arrayref::array_ref;
+use ark_ff::{BigInteger, Field, Fp12, Fp12ParamsWrapper, PrimeField, QuadExtField};
 
-use crate::pvk::get_alpha_g1_beta_g2;
-use crate::utils::{get_account_data, put_account_data, BN254_DATA_LEN};
+use crate::batch::{batch_exponent, BATCH_TARGET_BITS};
+use crate::error::Error;
+use crate::nullifier::spend;
+use crate::pvk::VerifyingKey;
+use crate::roots::check_session_root;
+use crate::session::Session;
+use crate::state::{load, load_accumulator, store, Stage};
+use crate::storage::Storage;
 
 const NAF: [i64; 63] = [
     1, 0, 0, 0, 1, 0, 1, 0, 0, -1, 0, 1, 0, 1, 0, -1, 0, 0, 1, 0, 1, 0, -1, 0, -1, 0, -1, 0, 1, 0,
@@ -11,14 +18,29 @@ const NAF: [i64; 63] = [
     0, 1,
 ];
 
-pub fn final_exponentiation_handler(t: usize, j: usize, input: &[u8], keys: &[String]) {
+pub fn final_exponentiation_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    t: usize,
+    j: usize,
+    input: &[u8],
+) -> Result<(), Error> {
+    let stage = u8::try_from(t)
+        .ok()
+        .and_then(Stage::from_u8)
+        .ok_or(Error::InvalidStage)?;
+    if j > stage.last_step() as usize {
+        return Err(Error::InvalidStage);
+    }
+
     match t {
-        2 => prepare_final_data(keys, input),
+        2 => prepare_final_data(storage, session, input),
         // Easy part: result = elt^((q^6-1)*(q^2+1)).
         // Follows, e.g., Beuchat et al page 9, by computing result as follows:
         //   elt^((q^6-1)*(q^2+1)) = (conj(elt) * elt^(-1))^(q^2+1)
-        3 => easy_part1(keys),
-        4 => easy_part2(keys),
+        3 => easy_part1(storage, session),
+        4 => easy_part2(storage, session),
         // Hard part follows Laura Fuentes-Castaneda et al. "Faster hashing to G2"
         // by computing:
         //
@@ -29,50 +51,65 @@ pub fn final_exponentiation_handler(t: usize, j: usize, input: &[u8], keys: &[St
         // which equals
         //
         // result = elt^( 2z * ( 6z^2 + 3z + 1 ) * (q^4 - q^2 + 1)/r ).
-        5 => hard_part_y0(keys, j),
-        6 => hard_part_y1(keys),
-        7 => hard_part_y3(keys),
-        8 => hard_part_y4(keys, j),
-        9 => hard_part_y6(keys, j),
-        10 => hard_part_y8(keys),
-        11 => hard_part_y9(keys),
-        12 => hard_part_y11(keys),
-        13 => hard_part_y13(keys),
-        14 => hard_part_y14(keys),
-        15 => hard_part_y15(keys),
-        16 => hard_part_y16(keys),
-        _ => {}
+        5 => hard_part_y0(storage, session, j),
+        6 => hard_part_y1(storage, session),
+        7 => hard_part_y3(storage, session),
+        8 => hard_part_y4(storage, session, j),
+        9 => hard_part_y6(storage, session, j),
+        10 => hard_part_y8(storage, session),
+        11 => hard_part_y9(storage, session),
+        12 => hard_part_y11(storage, session),
+        13 => hard_part_y13(storage, session),
+        14 => hard_part_y14(storage, session),
+        15 => hard_part_y15(storage, session),
+        16 => hard_part_y16(storage, vk, session),
+        // Batch entries carry prepared inputs only, keys that prepare inputs on chain can't
+        // check them.
+        17 | 18 if vk.prepares_inputs() => Err(Error::InvalidBatch),
+        // A folded batch compares against e(alpha, beta)^R instead, computed bit by bit
+        // from the most significant bit of R.
+        17 => batch_target(storage, vk, session, j),
+        18 => batch_hard_part_y16(storage, session),
+        _ => Err(Error::InvalidStage),
     }
 }
 
-fn prepare_final_data(keys: &[String], input: &[u8]) {
-    let gamma_account = keys[0].clone();
-    let delta_account = keys[1].clone();
-    let final_account = keys[2].clone();
-
-    let qap = array_ref![input, 0, BN254_DATA_LEN];
-    let mut qap = Fp12::<Fq12Parameters>::read(&mut qap.as_ref()).unwrap();
-    qap *= get_account_data(gamma_account, 1);
-    qap *= get_account_data(delta_account, 1);
+/// Multiplies the three Miller loops, all of them computed on chain.
+///
+/// A `qap` passed as `input` is rejected: any value can be made to pass the final check without
+/// a proof.
+fn prepare_final_data<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    input: &[u8],
+) -> Result<(), Error> {
+    if !input.is_empty() {
+        return Err(Error::InvalidInput);
+    }
+    let mut qap = load(storage, session, Stage::QapMillerLoop)?;
+    qap *= load(storage, session, Stage::GammaMillerLoop)?;
+    qap *= load(storage, session, Stage::DeltaMillerLoop)?;
 
-    put_account_data(final_account, &qap);
+    store(storage, session, Stage::PrepareFinalData, 0, &qap);
+    Ok(())
 }
 
-fn easy_part1(keys: &[String]) {
-    let final_account = keys[0].clone();
-    let f = get_account_data(final_account.clone(), 1);
+fn easy_part1<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let f = load(storage, session, Stage::PrepareFinalData)?;
 
     // f1 = r.conjugate() = f^(p^6)
     let mut f1 = f;
     f1.conjugate();
-    let f2 = f.inverse().unwrap();
+    // A zero product of pairings is never the result of a valid proof.
+    let f2 = f.inverse().ok_or(Error::InvalidProof)?;
     let f = f1 * &f2;
-    put_account_data(final_account, &f);
+    // f now has norm one, every value from here on is stored in compressed form.
+    store(storage, session, Stage::EasyPart1, 0, &f);
+    Ok(())
 }
 
-fn easy_part2(keys: &[String]) {
-    let final_account = keys[0].clone();
-    let mut r = get_account_data(final_account.clone(), 1);
+fn easy_part2<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let mut r = load(storage, session, Stage::EasyPart1)?;
 
     // f2 = f^(p^6 - 1)
     // r = f^((p^6 - 1)(p^2))
@@ -81,7 +118,8 @@ fn easy_part2(keys: &[String]) {
     let f2 = r;
     r.frobenius_map(2);
     r *= &f2;
-    put_account_data(final_account, &r);
+    store(storage, session, Stage::EasyPart2, 0, &r);
+    Ok(())
 }
 
 fn cal_y0(
@@ -96,25 +134,22 @@ fn cal_y0(
     y0
 }
 
-fn hard_part_y0(keys: &[String], j: usize) {
-    let final_account = keys[0].clone();
-    let y0_account = keys[1].clone();
-    let r = get_account_data(final_account, 1);
-    let y0 = get_account_data(y0_account.clone(), j);
+fn hard_part_y0<S: Storage>(storage: &mut S, session: &Session, j: usize) -> Result<(), Error> {
+    let r = load(storage, session, Stage::EasyPart2)?;
+    let y0 = load_accumulator(storage, session, Stage::HardPartY0, j)?;
     let mut y0 = cal_y0(&r, y0, j);
     if j == 62 {
         y0.conjugate();
     }
-    put_account_data(y0_account, &y0);
+    store(storage, session, Stage::HardPartY0, j, &y0);
+    Ok(())
 }
 
-fn hard_part_y1(keys: &[String]) {
-    let y0_account = keys[0].clone();
-    let y1_account = keys[1].clone();
-
-    let y0 = get_account_data(y0_account, 1);
+fn hard_part_y1<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let y0 = load(storage, session, Stage::HardPartY0)?;
     let y1 = y0.cyclotomic_square();
-    put_account_data(y1_account, &y1);
+    store(storage, session, Stage::HardPartY1, 0, &y1);
+    Ok(())
 }
 
 fn cal_y3(f: &Fp12<Fq12Parameters>) -> Fp12<Fq12Parameters> {
@@ -125,12 +160,11 @@ fn cal_y3(f: &Fp12<Fq12Parameters>) -> Fp12<Fq12Parameters> {
     y3
 }
 
-fn hard_part_y3(keys: &[String]) {
-    let y0_account = keys[0].clone();
-    let y3_account = keys[1].clone();
-    let y0 = get_account_data(y0_account, 1);
+fn hard_part_y3<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let y0 = load(storage, session, Stage::HardPartY0)?;
     let y3 = cal_y3(&y0);
-    put_account_data(y3_account, &y3);
+    store(storage, session, Stage::HardPartY3, 0, &y3);
+    Ok(())
 }
 
 fn cal_y4(
@@ -144,16 +178,15 @@ fn cal_y4(
     y4
 }
 
-fn hard_part_y4(keys: &[String], j: usize) {
-    let y3_account = keys[0].clone();
-    let y4_account = keys[1].clone();
-    let y3 = get_account_data(y3_account, 1);
-    let y4 = get_account_data(y4_account.clone(), j);
+fn hard_part_y4<S: Storage>(storage: &mut S, session: &Session, j: usize) -> Result<(), Error> {
+    let y3 = load(storage, session, Stage::HardPartY3)?;
+    let y4 = load_accumulator(storage, session, Stage::HardPartY4, j)?;
     let mut y4 = cal_y4(&y3, y4, j);
     if j == 62 {
         y4.conjugate();
     }
-    put_account_data(y4_account, &y4);
+    store(storage, session, Stage::HardPartY4, j, &y4);
+    Ok(())
 }
 
 fn cal_y6(
@@ -168,120 +201,135 @@ fn cal_y6(
     y6
 }
 
-fn hard_part_y6(keys: &[String], j: usize) {
-    let y4_account = keys[0].clone();
-    let y6_account = keys[1].clone();
-
-    let y4 = get_account_data(y4_account, 1);
+fn hard_part_y6<S: Storage>(storage: &mut S, session: &Session, j: usize) -> Result<(), Error> {
+    let y4 = load(storage, session, Stage::HardPartY4)?;
     let y5 = y4.cyclotomic_square();
-    let y6 = get_account_data(y6_account.clone(), j);
+    let y6 = load_accumulator(storage, session, Stage::HardPartY6, j)?;
     let mut y6 = cal_y6(&y5, y6, j);
     if j == 62 {
         y6.conjugate();
     }
-    put_account_data(y6_account, &y6);
+    store(storage, session, Stage::HardPartY6, j, &y6);
+    Ok(())
 }
 
-fn hard_part_y8(keys: &[String]) {
-    let y3_account = keys[0].clone();
-    let y4_account = keys[1].clone();
-    let y6_account = keys[2].clone();
-    let y8_account = keys[3].clone();
-
-    let mut y3 = get_account_data(y3_account, 1);
-    let y4 = get_account_data(y4_account, 1);
-    let mut y6 = get_account_data(y6_account, 1);
+fn hard_part_y8<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let mut y3 = load(storage, session, Stage::HardPartY3)?;
+    let y4 = load(storage, session, Stage::HardPartY4)?;
+    let mut y6 = load(storage, session, Stage::HardPartY6)?;
 
     y3.conjugate();
     y6.conjugate();
     let y7 = y6 * y4;
     let y8 = y7 * y3;
 
-    put_account_data(y8_account, &y8);
+    store(storage, session, Stage::HardPartY8, 0, &y8);
+    Ok(())
 }
 
-fn hard_part_y9(keys: &[String]) {
-    let y1_account = keys[0].clone();
-    let y8_account = keys[1].clone();
-    let y9_account = keys[2].clone();
-
-    let y1 = get_account_data(y1_account, 1);
-    let y8 = get_account_data(y8_account, 1);
+fn hard_part_y9<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let y1 = load(storage, session, Stage::HardPartY1)?;
+    let y8 = load(storage, session, Stage::HardPartY8)?;
 
     let y9 = y8 * y1;
 
-    put_account_data(y9_account, &y9);
+    store(storage, session, Stage::HardPartY9, 0, &y9);
+    Ok(())
 }
 
-fn hard_part_y11(keys: &[String]) {
-    let y4_account = keys[0].clone();
-    let y8_account = keys[1].clone();
-    let final_account = keys[2].clone();
-    let y11_account = keys[3].clone();
-
-    let y4 = get_account_data(y4_account, 1);
-    let y8 = get_account_data(y8_account, 1);
-    let r = get_account_data(final_account, 1);
+fn hard_part_y11<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let y4 = load(storage, session, Stage::HardPartY4)?;
+    let y8 = load(storage, session, Stage::HardPartY8)?;
+    let r = load(storage, session, Stage::EasyPart2)?;
 
     let y11 = y8 * y4 * r;
 
-    put_account_data(y11_account, &y11);
+    store(storage, session, Stage::HardPartY11, 0, &y11);
+    Ok(())
 }
 
-fn hard_part_y13(keys: &[String]) {
-    let y9_account = keys[0].clone();
-    let y11_account = keys[1].clone();
-    let y13_account = keys[2].clone();
-
-    let y9 = get_account_data(y9_account, 1);
-    let y11 = get_account_data(y11_account, 1);
+fn hard_part_y13<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let y9 = load(storage, session, Stage::HardPartY9)?;
+    let y11 = load(storage, session, Stage::HardPartY11)?;
 
     let mut y12 = y9;
     y12.frobenius_map(1);
     let y13 = y12 * y11;
 
-    put_account_data(y13_account, &y13);
+    store(storage, session, Stage::HardPartY13, 0, &y13);
+    Ok(())
 }
 
-fn hard_part_y14(keys: &[String]) {
-    let y8_account = keys[0].clone();
-    let y13_account = keys[1].clone();
-    let y14_account = keys[2].clone();
-
-    let mut y8 = get_account_data(y8_account, 1);
-    let y13 = get_account_data(y13_account, 1);
+fn hard_part_y14<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let mut y8 = load(storage, session, Stage::HardPartY8)?;
+    let y13 = load(storage, session, Stage::HardPartY13)?;
 
     y8.frobenius_map(2);
     let y14 = y8 * y13;
 
-    put_account_data(y14_account, &y14);
+    store(storage, session, Stage::HardPartY14, 0, &y14);
+    Ok(())
 }
 
-fn hard_part_y15(keys: &[String]) {
-    let y9_account = keys[0].clone();
-    let final_account = keys[1].clone();
-    let y15_account = keys[2].clone();
-
-    let mut r = get_account_data(final_account, 1);
-    let y9 = get_account_data(y9_account, 1);
+fn hard_part_y15<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let mut r = load(storage, session, Stage::EasyPart2)?;
+    let y9 = load(storage, session, Stage::HardPartY9)?;
 
     r.conjugate();
     let mut y15 = r * y9;
     y15.frobenius_map(3);
 
-    put_account_data(y15_account, &y15);
+    store(storage, session, Stage::HardPartY15, 0, &y15);
+    Ok(())
+}
+
+fn hard_part_y16<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+) -> Result<(), Error> {
+    let y14 = load(storage, session, Stage::HardPartY14)?;
+    let y15 = load(storage, session, Stage::HardPartY15)?;
+
+    let y16 = y15 * &y14;
+    if y16 != vk.alpha_g1_beta_g2() {
+        return Err(Error::InvalidProof);
+    }
+    check_session_root(storage, vk, session)?;
+    spend(storage, vk, session)?;
+    store(storage, session, Stage::HardPartY16, 0, &y16);
+    Ok(())
 }
 
-fn hard_part_y16(keys: &[String]) {
-    let y14_account = keys[0].clone();
-    let y15_account = keys[1].clone();
+fn batch_target<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    j: usize,
+) -> Result<(), Error> {
+    let exponent = batch_exponent(storage, session)?.into_repr();
+    let mut target = load_accumulator(storage, session, Stage::BatchTarget, j)?;
+    if j > 0 {
+        target = target.cyclotomic_square();
+    }
+    if exponent.get_bit(BATCH_TARGET_BITS - 1 - j) {
+        target *= vk.alpha_g1_beta_g2();
+    }
+    store(storage, session, Stage::BatchTarget, j, &target);
+    Ok(())
+}
 
-    let y14 = get_account_data(y14_account, 1);
-    let y15 = get_account_data(y15_account, 1);
+fn batch_hard_part_y16<S: Storage>(storage: &mut S, session: &Session) -> Result<(), Error> {
+    let y14 = load(storage, session, Stage::HardPartY14)?;
+    let y15 = load(storage, session, Stage::HardPartY15)?;
+    let target = load(storage, session, Stage::BatchTarget)?;
 
     let y16 = y15 * &y14;
-    let alpha_g1_beta_g2 = get_alpha_g1_beta_g2();
-    assert!(y16 == alpha_g1_beta_g2);
+    if y16 != target {
+        return Err(Error::InvalidProof);
+    }
+    store(storage, session, Stage::BatchHardPartY16, 0, &y16);
+    Ok(())
 }
 
 fn exp_by_neg_x(
@@ -0,0 +1,160 @@
+//! Public inputs prepared on chain.
+//!
+//! Keys whose inputs carry a meaning for the contract, a nullifier, a Merkle root or the
+//! submitting account, can't trust a prepared input computed off chain. Their public inputs are
+//! passed to [`prepare_inputs_handler`] instead, and the gamma Miller loop only accepts the
+//! prepared input stored by it.
+use ark_bn254::{Fr, G1Affine, G1Projective};
+use ark_ec::AffineCurve;
+use ark_ff::{to_bytes, FromBytes, PrimeField};
+
+use crate::callback::read_callback;
+use crate::error::Error;
+use crate::nullifier::{is_spent, nullifier};
+use crate::pvk::VerifyingKey;
+use crate::roots::check_root;
+use crate::session::Session;
+use crate::state::{read_input, reset};
+use crate::storage::Storage;
+
+pub(crate) const FR_LEN: usize = 32;
+const G1_PROJECTIVE_LEN: usize = 96;
+
+/// A verifying key whose public input `index` has to be the account submitting the proof.
+///
+/// Binding a proof to its submitter keeps anyone who copies a pending proof from finishing the
+/// verification under their own account: the contract fills in the caller's input and computes
+/// every Miller loop from the proof's `A`, `B` and `C`, so the copied proof fails the final
+/// check for any other account.
+#[derive(Clone, Copy, Debug)]
+pub struct CallerKey<K> {
+    key: K,
+    index: usize,
+}
+
+impl<K: VerifyingKey> CallerKey<K> {
+    pub fn new(key: K, index: usize) -> CallerKey<K> {
+        CallerKey { key, index }
+    }
+}
+
+impl<K: VerifyingKey> VerifyingKey for CallerKey<K> {
+    fn gamma_coeff(&self, j: usize, k: usize) -> ark_bn254::Fq2 {
+        self.key.gamma_coeff(j, k)
+    }
+
+    fn delta_coeff(&self, j: usize, k: usize) -> ark_bn254::Fq2 {
+        self.key.delta_coeff(j, k)
+    }
+
+    fn alpha_g1_beta_g2(&self) -> ark_bn254::Fq12 {
+        self.key.alpha_g1_beta_g2()
+    }
+
+    fn gamma_abc_g1(&self) -> Vec<G1Affine> {
+        self.key.gamma_abc_g1()
+    }
+
+    fn nullifier_index(&self) -> Option<usize> {
+        self.key.nullifier_index()
+    }
+
+    fn caller_index(&self) -> Option<usize> {
+        Some(self.index)
+    }
+
+    fn root_index(&self) -> Option<usize> {
+        self.key.root_index()
+    }
+}
+
+/// The field encoding of an account hash, its little-endian bytes reduced modulo the order of
+/// the scalar field.
+///
+/// Circuits binding a proof to an account have to expose the account in the same encoding.
+pub fn account_input(account: &[u8; 32]) -> Fr {
+    Fr::from_le_bytes_mod_order(account)
+}
+
+/// Prepares the public inputs of the next proof of `session` and clears any earlier progress.
+///
+/// `input` holds the public inputs as 32 byte little-endian field elements, except for the
+/// input of the caller if the key binds proofs to their submitter, which is filled in from
+/// `caller`. The prepared input and the complete inputs are stored in the `inputs` slot of the
+/// session.
+pub fn prepare_inputs_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    caller: &[u8; 32],
+    input: &[u8],
+) -> Result<(), Error> {
+    let gamma_abc_g1 = vk.gamma_abc_g1();
+    let (first, bases) = gamma_abc_g1.split_first().ok_or(Error::InvalidState)?;
+    let caller_index = vk.caller_index().filter(|index| *index < bases.len());
+    let expected_len = (bases.len() - caller_index.map_or(0, |_| 1)) * FR_LEN;
+    if input.len() != expected_len {
+        return Err(Error::InvalidInput);
+    }
+
+    let mut chunks = input.chunks(FR_LEN);
+    let mut inputs = Vec::with_capacity(bases.len() * FR_LEN);
+    let mut prepared_input = first.into_projective();
+    for (k, base) in bases.iter().enumerate() {
+        let x = if caller_index == Some(k) {
+            account_input(caller)
+        } else {
+            read_input::<Fr>(chunks.next().ok_or(Error::InvalidInput)?)?
+        };
+        prepared_input += &base.mul(x.into_repr());
+        inputs.extend(to_bytes!(x).unwrap());
+    }
+    if let Some(nullifier) = nullifier(vk, &inputs) {
+        if is_spent(storage, nullifier) {
+            return Err(Error::NullifierSpent);
+        }
+    }
+    check_root(storage, vk, &inputs)?;
+
+    reset(storage, session);
+    let mut dst = to_bytes!(prepared_input).unwrap();
+    dst.extend(inputs);
+    storage.set("data", &session.key("inputs"), dst);
+    Ok(())
+}
+
+/// Checks that the gamma Miller loop runs over the prepared input stored for `session`.
+///
+/// Keys whose inputs are prepared off chain accept any prepared input, unless the session has a
+/// callback that is passed the inputs.
+pub fn check_prepared_input<S: Storage, K: VerifyingKey>(
+    storage: &S,
+    vk: &K,
+    session: &Session,
+    prepared_input: &G1Projective,
+) -> Result<(), Error> {
+    if !vk.prepares_inputs() && read_callback(storage, session)?.is_none() {
+        return Ok(());
+    }
+    let (stored, _) = read_inputs(storage, session)?;
+    if stored != *prepared_input {
+        return Err(Error::InvalidInput);
+    }
+    Ok(())
+}
+
+/// Reads the prepared input and the public inputs stored for `session`.
+pub fn read_inputs<S: Storage>(
+    storage: &S,
+    session: &Session,
+) -> Result<(G1Projective, Vec<u8>), Error> {
+    let src = storage
+        .get("data", &session.key("inputs"))
+        .ok_or(Error::MissingState)?;
+    if src.len() < G1_PROJECTIVE_LEN {
+        return Err(Error::InvalidState);
+    }
+    let prepared_input =
+        G1Projective::read(&src[..G1_PROJECTIVE_LEN]).map_err(|_| Error::InvalidState)?;
+    Ok((prepared_input, src[G1_PROJECTIVE_LEN..].to_vec()))
+}
@@ -0,0 +1,794 @@
+//! Staged Groth16 verification over BN254.
+//!
+//! The handlers keep their state in a [`storage::Storage`], the contract binary runs them
+//! against its dictionaries, tests and tools against a [`storage::MemoryStorage`].
+#![allow(unused_parens)]
+#![allow(non_snake_case)]
+
+pub mod batch;
+pub mod bounty;
+pub mod callback;
+pub mod compression;
+pub mod error;
+pub mod final_exponentiation;
+pub mod inputs;
+pub mod merkle_key;
+pub mod miller_loop;
+pub mod nullifier;
+pub mod pvk;
+pub mod range_key;
+pub mod relay;
+pub mod roots;
+pub mod session;
+pub mod state;
+pub mod storage;
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use ark_bn254::{Fr, G1Affine};
+    use ark_ec::bn::BnParameters;
+    use ark_ff::{to_bytes, FromBytes, One};
+    use ark_std::rand::{rngs::StdRng, SeedableRng};
+    use circuit::merkle::{merkle_keys, MerkleMembership, MerkleTree, MERKLE_DEPTH};
+    use circuit::poseidon::Poseidon;
+    use circuit::range::{commit, range_keys, CommittedRange};
+
+    use crate::batch::{
+        batch_challenge, batch_commit_handler, batch_delta_miller_loop_handler, batch_fold_handler,
+        batch_gamma_miller_loop_handler, batch_qap_miller_loop_handler, BATCH_TARGET_BITS,
+    };
+    use crate::bounty::{
+        read_bounty, refund_handler, relay_step_handler, submit_with_bounty_handler, Deposit,
+        Escrow,
+    };
+    use crate::callback::{
+        callback_status, run_callback_handler, set_callback_handler, Callback, Invoker,
+    };
+    use crate::error::Error;
+    use crate::final_exponentiation::final_exponentiation_handler;
+    use crate::inputs::{account_input, prepare_inputs_handler, CallerKey};
+    use crate::merkle_key::MerkleKey;
+    use crate::miller_loop::{
+        delta_miller_loop_handler, gamma_miller_loop_handler, qap_miller_loop_handler,
+    };
+    use crate::nullifier::{is_spent, NullifierKey};
+    use crate::pvk::{EmbeddedKey, VerifyingKey};
+    use crate::range_key::RangeKey;
+    use crate::relay::{
+        read_cursor, relayed_session, schedule, step_handler, submit_handler, Step, PROOF_LEN,
+    };
+    use crate::roots::{is_registered, set_root_handler, RootKey, ADMIN_KEY};
+    use crate::session::Session;
+    use crate::state::{decode_entry, Stage};
+    use crate::storage::{MemoryStorage, Storage};
+
+    /// Runs the Miller loop schedule of the client, calling `step(i, j)` for every step.
+    fn miller_loop_steps<F: FnMut(usize, usize)>(mut step: F) {
+        let mut j = 0;
+        for i in (1..ark_bn254::Parameters::ATE_LOOP_COUNT.len()).rev() {
+            step(i, j);
+            j += 1;
+            if ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] != 0 {
+                j += 1;
+            }
+        }
+        step(0, j);
+    }
+
+    /// Runs the Miller loop of the proof's `A` and `B`.
+    fn qap_miller_loop(storage: &mut MemoryStorage, session: &Session, proof_ab: &[u8]) {
+        miller_loop_steps(|i, j| {
+            qap_miller_loop_handler(storage, session, i, j, proof_ab).unwrap();
+        });
+    }
+
+    /// Runs the final exponentiation up to and including `hard_part_y15`.
+    fn final_exponentiation_until_y15(storage: &mut MemoryStorage, session: &Session) {
+        final_exponentiation_handler(storage, &EmbeddedKey, session, 2, 0, &[]).unwrap();
+        for t in 3..16 {
+            let steps = match t {
+                5 | 8 | 9 => 63,
+                _ => 1,
+            };
+            for j in 0..steps {
+                final_exponentiation_handler(storage, &EmbeddedKey, session, t, j, &[]).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let (proof_c, prepared_input, proof_ab) = circuit::initialize().unwrap();
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &prepared_input)
+                .unwrap();
+            delta_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &proof_c)
+                .unwrap();
+        });
+        qap_miller_loop(&mut storage, &session, &proof_ab);
+        final_exponentiation_until_y15(&mut storage, &session);
+        final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 16, 0, &[]).unwrap();
+    }
+
+    #[test]
+    fn test_verify_range_proof() {
+        let rng = &mut StdRng::seed_from_u64(48);
+        let (pk, vk) = range_keys().unwrap();
+        let opening = (Fr::from(1_000_000u64), Fr::from(77u64));
+        let bundle = circuit::prove(CommittedRange::new(32, Some(opening)), &pk, rng).unwrap();
+        assert_eq!(bundle.public_inputs, vec![commit(opening.0, opening.1)]);
+        let payload = circuit::export_for_contract(&bundle, &vk).unwrap();
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        // the contract prepares the commitment itself, a prepared input for another commitment
+        // is rejected
+        let mut other = bundle;
+        other.public_inputs = vec![commit(Fr::from(1_000_001u64), opening.1)];
+        let other = circuit::export_for_contract(&other, &vk).unwrap();
+        prepare_inputs_handler(
+            &mut storage,
+            &RangeKey,
+            &session,
+            &[0; 32],
+            &other.public_inputs,
+        )
+        .unwrap();
+        assert_eq!(
+            gamma_miller_loop_handler(
+                &mut storage,
+                &RangeKey,
+                &session,
+                ark_bn254::Parameters::ATE_LOOP_COUNT.len() - 1,
+                0,
+                &payload.prepared_input
+            ),
+            Err(Error::InvalidInput)
+        );
+
+        prepare_inputs_handler(
+            &mut storage,
+            &RangeKey,
+            &session,
+            &[0; 32],
+            &payload.public_inputs,
+        )
+        .unwrap();
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(
+                &mut storage,
+                &RangeKey,
+                &session,
+                i,
+                j,
+                &payload.prepared_input,
+            )
+            .unwrap();
+            delta_miller_loop_handler(&mut storage, &RangeKey, &session, i, j, &payload.proof_c)
+                .unwrap();
+        });
+        qap_miller_loop(&mut storage, &session, &payload.proof_ab);
+        final_exponentiation_until_y15(&mut storage, &session);
+        final_exponentiation_handler(&mut storage, &RangeKey, &session, 16, 0, &[]).unwrap();
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let size = 3;
+        let (entries, pairs) = circuit::initialize_batch(size).unwrap();
+        let digest = types::crypto::blake2b(&entries);
+        let challenges = (0..size)
+            .map(|k| batch_challenge(&digest, k))
+            .collect::<Vec<_>>();
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        batch_commit_handler(&mut storage, &session, &entries).unwrap();
+        for k in 0..size {
+            batch_fold_handler(&mut storage, &session, k).unwrap();
+            // the next proof is folded once the Miller loop of this one has finished
+            assert_eq!(
+                batch_fold_handler(&mut storage, &session, k + 1),
+                Err(Error::UnexpectedStage)
+            );
+            miller_loop_steps(|i, j| {
+                batch_qap_miller_loop_handler(&mut storage, &session, i, j).unwrap();
+            });
+        }
+        let qap = storage
+            .get("data", &session.key(Stage::QapMillerLoop.slot()))
+            .unwrap();
+        let (stage, step, qap) = decode_entry(&qap).unwrap();
+        assert_eq!((stage, step), (Stage::QapMillerLoop, 89));
+        assert_eq!(
+            to_bytes!(qap).unwrap(),
+            circuit::batch_qap(&pairs, &challenges)
+        );
+
+        miller_loop_steps(|i, j| {
+            batch_gamma_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j).unwrap();
+            batch_delta_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j).unwrap();
+        });
+        final_exponentiation_until_y15(&mut storage, &session);
+        for j in 0..BATCH_TARGET_BITS {
+            final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 17, j, &[]).unwrap();
+        }
+        final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 18, 0, &[]).unwrap();
+        let y16 = storage
+            .get("data", &session.key(Stage::BatchHardPartY16.slot()))
+            .unwrap();
+        assert_eq!(decode_entry(&y16).unwrap().0, Stage::BatchHardPartY16);
+
+        // committing a batch discards the verification in progress
+        batch_commit_handler(&mut storage, &session, &entries).unwrap();
+        assert_eq!(
+            final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 18, 0, &[]),
+            Err(Error::MissingState)
+        );
+    }
+
+    #[test]
+    fn test_sessions_are_isolated() {
+        let (proof_c, prepared_input, proof_ab) = circuit::initialize().unwrap();
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+        let other = Session::new([2u8; 32]);
+
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &prepared_input)
+                .unwrap();
+            delta_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &proof_c)
+                .unwrap();
+        });
+        qap_miller_loop(&mut storage, &session, &proof_ab);
+        assert_eq!(
+            final_exponentiation_handler(&mut storage, &EmbeddedKey, &other, 2, 0, &[]),
+            Err(Error::MissingState)
+        );
+        final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 2, 0, &[]).unwrap();
+        assert_eq!(
+            final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 4, 0, &[]),
+            Err(Error::UnexpectedStage)
+        );
+    }
+
+    #[test]
+    fn test_known_answers() {
+        let answers = circuit::vectors::known_answers().unwrap();
+        let proof_c = hex::decode(&answers.proof_c).unwrap();
+        let prepared_input = hex::decode(&answers.prepared_input).unwrap();
+        let proof_ab = hex::decode(&answers.proof_ab).unwrap();
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        let check = |storage: &MemoryStorage, stage: usize, j: usize| {
+            let expected = answers.find(stage as u8, j as u8).unwrap();
+            assert_eq!(
+                Stage::from_u8(expected.stage).unwrap().slot(),
+                expected.slot
+            );
+            let stored = storage.get("data", &session.key(&expected.slot)).unwrap();
+            let (stored_stage, step, value) = decode_entry(&stored).unwrap();
+            assert_eq!((stored_stage as u8, step), (expected.stage, expected.step));
+            assert!(value == expected.value(), "stage {} step {}", stage, j);
+        };
+
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &prepared_input)
+                .unwrap();
+            check(&storage, 0, j);
+            delta_miller_loop_handler(&mut storage, &EmbeddedKey, &session, i, j, &proof_c)
+                .unwrap();
+            check(&storage, 1, j);
+            qap_miller_loop_handler(&mut storage, &session, i, j, &proof_ab).unwrap();
+            check(&storage, 19, j);
+        });
+        for t in 2..16 {
+            let steps = match t {
+                5 | 8 | 9 => 63,
+                _ => 1,
+            };
+            for j in 0..steps {
+                final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, t, j, &[])
+                    .unwrap();
+                check(&storage, t, j);
+            }
+        }
+        final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 16, 0, &[]).unwrap();
+    }
+
+    #[test]
+    fn test_malformed_steps() {
+        let (proof_c, prepared_input, proof_ab) = circuit::initialize().unwrap();
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        for (i, j) in [(0, 0), (63, 0), (64, 1), (65, 0), (0, 90), (255, 255)] {
+            assert_eq!(
+                gamma_miller_loop_handler(
+                    &mut storage,
+                    &EmbeddedKey,
+                    &session,
+                    i,
+                    j,
+                    &prepared_input
+                ),
+                Err(Error::InvalidStage)
+            );
+        }
+        assert_eq!(
+            delta_miller_loop_handler(&mut storage, &EmbeddedKey, &session, 64, 0, &proof_ab),
+            Err(Error::InvalidInput)
+        );
+        assert_eq!(
+            qap_miller_loop_handler(&mut storage, &session, 64, 0, &proof_ab[1..]),
+            Err(Error::InvalidInput)
+        );
+        // the qap is computed on chain, passing one is an error
+        assert_eq!(
+            final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 2, 0, &[0u8; 384]),
+            Err(Error::InvalidInput)
+        );
+        for (t, j) in [
+            (0, 0),
+            (1, 0),
+            (5, 63),
+            (6, 1),
+            (17, 136),
+            (19, 0),
+            (300, 0),
+        ] {
+            assert_eq!(
+                final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, t, j, &[]),
+                Err(Error::InvalidStage)
+            );
+        }
+
+        storage.set(
+            "data",
+            &session.key(Stage::DeltaMillerLoop.slot()),
+            vec![1, 0, 7],
+        );
+        gamma_miller_loop_handler(&mut storage, &EmbeddedKey, &session, 64, 0, &prepared_input)
+            .unwrap();
+        assert_eq!(
+            delta_miller_loop_handler(&mut storage, &EmbeddedKey, &session, 63, 2, &proof_c),
+            Err(Error::InvalidState)
+        );
+
+        // a loop runs over the input of its first step to the end
+        let other = Session::new([2u8; 32]);
+        let a = G1Affine::read(&proof_ab[..65]).unwrap();
+        let other_ab = [to_bytes!(-a).unwrap(), proof_ab[65..].to_vec()].concat();
+        qap_miller_loop_handler(&mut storage, &other, 64, 0, &proof_ab).unwrap();
+        assert_eq!(
+            qap_miller_loop_handler(&mut storage, &other, 63, 2, &other_ab),
+            Err(Error::InvalidInput)
+        );
+        qap_miller_loop_handler(&mut storage, &other, 63, 2, &proof_ab).unwrap();
+    }
+
+    #[test]
+    fn test_nullifier_is_spent_once() {
+        let (proof_c, prepared_input, proof_ab) = circuit::initialize().unwrap();
+        let public_input = to_bytes!(circuit::demo_public_input()).unwrap();
+        let vk = NullifierKey::new(EmbeddedKey, 0);
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        // the gamma Miller loop only runs over inputs prepared by the contract
+        assert_eq!(
+            gamma_miller_loop_handler(&mut storage, &vk, &session, 64, 0, &prepared_input),
+            Err(Error::MissingState)
+        );
+        let other_input = to_bytes!(Fr::one()).unwrap();
+        prepare_inputs_handler(&mut storage, &vk, &session, &session.id(), &other_input).unwrap();
+        assert_eq!(
+            gamma_miller_loop_handler(&mut storage, &vk, &session, 64, 0, &prepared_input),
+            Err(Error::InvalidInput)
+        );
+        assert_eq!(
+            prepare_inputs_handler(
+                &mut storage,
+                &vk,
+                &session,
+                &session.id(),
+                &public_input[1..]
+            ),
+            Err(Error::InvalidInput)
+        );
+
+        prepare_inputs_handler(&mut storage, &vk, &session, &session.id(), &public_input).unwrap();
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(&mut storage, &vk, &session, i, j, &prepared_input).unwrap();
+            delta_miller_loop_handler(&mut storage, &vk, &session, i, j, &proof_c).unwrap();
+        });
+        qap_miller_loop(&mut storage, &session, &proof_ab);
+        final_exponentiation_until_y15(&mut storage, &session);
+
+        // preparing other inputs discards the verification in progress
+        let mut forked = storage.clone();
+        prepare_inputs_handler(&mut forked, &vk, &session, &session.id(), &other_input).unwrap();
+        assert_eq!(
+            final_exponentiation_handler(&mut forked, &vk, &session, 16, 0, &[]),
+            Err(Error::MissingState)
+        );
+
+        assert!(!is_spent(&storage, &public_input));
+        final_exponentiation_handler(&mut storage, &vk, &session, 16, 0, &[]).unwrap();
+        assert!(is_spent(&storage, &public_input));
+        assert_eq!(
+            final_exponentiation_handler(&mut storage, &vk, &session, 16, 0, &[]),
+            Err(Error::NullifierSpent)
+        );
+        assert_eq!(
+            final_exponentiation_handler(&mut storage, &vk, &session, 17, 0, &[]),
+            Err(Error::InvalidBatch)
+        );
+
+        let other = Session::new([2u8; 32]);
+        assert_eq!(
+            prepare_inputs_handler(&mut storage, &vk, &other, &other.id(), &public_input),
+            Err(Error::NullifierSpent)
+        );
+    }
+
+    #[test]
+    fn test_proof_is_bound_to_caller() {
+        let (proof_c, prepared_input, proof_ab) = circuit::initialize().unwrap();
+        let public_input = circuit::demo_public_input();
+        let vk = CallerKey::new(EmbeddedKey, 0);
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        // the demo proof counts for the account its public input encodes
+        let mut submitter = [0u8; 32];
+        submitter.copy_from_slice(&to_bytes!(public_input).unwrap());
+        assert_eq!(account_input(&submitter), public_input);
+
+        assert_eq!(
+            prepare_inputs_handler(&mut storage, &vk, &session, &submitter, &[0u8; 32]),
+            Err(Error::InvalidInput)
+        );
+        prepare_inputs_handler(&mut storage, &vk, &session, &[3u8; 32], &[]).unwrap();
+        assert_eq!(
+            gamma_miller_loop_handler(&mut storage, &vk, &session, 64, 0, &prepared_input),
+            Err(Error::InvalidInput)
+        );
+
+        prepare_inputs_handler(&mut storage, &vk, &session, &submitter, &[]).unwrap();
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(&mut storage, &vk, &session, i, j, &prepared_input).unwrap();
+            delta_miller_loop_handler(&mut storage, &vk, &session, i, j, &proof_c).unwrap();
+        });
+        qap_miller_loop(&mut storage, &session, &proof_ab);
+        final_exponentiation_until_y15(&mut storage, &session);
+        final_exponentiation_handler(&mut storage, &vk, &session, 16, 0, &[]).unwrap();
+    }
+
+    #[test]
+    fn test_root_has_to_be_registered() {
+        let rng = &mut StdRng::seed_from_u64(47);
+        let (pk, vk) = merkle_keys().unwrap();
+        let leaves = (0..9u64).map(|i| Fr::from(i + 10)).collect::<Vec<_>>();
+        let tree = MerkleTree::new(Poseidon::new(2), MERKLE_DEPTH, &leaves);
+        let member = (leaves[5], tree.path(5));
+        let circuit = MerkleMembership::new(Poseidon::new(2), MERKLE_DEPTH, Some(member));
+        let bundle = circuit::prove(circuit, &pk, rng).unwrap();
+        let payload = circuit::export_for_contract(&bundle, &vk).unwrap();
+        let (proof_c, prepared_input, proof_ab) =
+            (payload.proof_c, payload.prepared_input, payload.proof_ab);
+        let root = to_bytes!(tree.root()).unwrap();
+        assert_eq!(payload.public_inputs, root);
+        let vk = RootKey::new(MerkleKey, 0);
+        let admin = [5u8; 32];
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+
+        // nobody can update the registry before the admin is set
+        assert_eq!(
+            set_root_handler(&mut storage, &admin, &root, true),
+            Err(Error::Forbidden)
+        );
+        storage.set("data", ADMIN_KEY, admin.to_vec());
+        assert_eq!(
+            set_root_handler(&mut storage, &session.id(), &root, true),
+            Err(Error::Forbidden)
+        );
+        assert_eq!(
+            set_root_handler(&mut storage, &admin, &[0xffu8; 32], true),
+            Err(Error::InvalidInput)
+        );
+        assert_eq!(
+            prepare_inputs_handler(&mut storage, &vk, &session, &session.id(), &root),
+            Err(Error::UnknownRoot)
+        );
+
+        set_root_handler(&mut storage, &admin, &root, true).unwrap();
+        assert!(is_registered(&storage, &root));
+        prepare_inputs_handler(&mut storage, &vk, &session, &session.id(), &root).unwrap();
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(&mut storage, &vk, &session, i, j, &prepared_input).unwrap();
+            delta_miller_loop_handler(&mut storage, &vk, &session, i, j, &proof_c).unwrap();
+        });
+        qap_miller_loop(&mut storage, &session, &proof_ab);
+        final_exponentiation_until_y15(&mut storage, &session);
+        assert_eq!(
+            final_exponentiation_handler(&mut storage, &vk, &session, 17, 0, &[]),
+            Err(Error::InvalidBatch)
+        );
+
+        // revoking the root stops the verification in progress
+        let mut revoked = storage.clone();
+        set_root_handler(&mut revoked, &admin, &root, false).unwrap();
+        assert!(!is_registered(&revoked, &root));
+        assert_eq!(
+            final_exponentiation_handler(&mut revoked, &vk, &session, 16, 0, &[]),
+            Err(Error::UnknownRoot)
+        );
+
+        // unlike a nullifier, a registered root is accepted any number of times
+        final_exponentiation_handler(&mut storage, &vk, &session, 16, 0, &[]).unwrap();
+        final_exponentiation_handler(&mut storage, &vk, &session, 16, 0, &[]).unwrap();
+    }
+
+    /// Records every call and answers with a fixed status.
+    struct RecordingInvoker {
+        calls: Vec<(Callback, [u8; 32], Vec<u8>)>,
+        status: u32,
+    }
+
+    impl Invoker for RecordingInvoker {
+        fn invoke(&mut self, callback: &Callback, vk_id: [u8; 32], inputs: &[u8]) -> u32 {
+            self.calls.push((callback.clone(), vk_id, inputs.to_vec()));
+            self.status
+        }
+    }
+
+    /// Stands for a target that reverts, which reverts the deploy calling it.
+    struct RevertingInvoker;
+
+    impl Invoker for RevertingInvoker {
+        fn invoke(&mut self, _: &Callback, _: [u8; 32], _: &[u8]) -> u32 {
+            panic!("the target reverted")
+        }
+    }
+
+    /// Registers a `mint` callback for `session` and verifies the demo proof, returning its
+    /// public input.
+    fn verify_with_callback(storage: &mut MemoryStorage, session: &Session) -> Vec<u8> {
+        let (proof_c, prepared_input, proof_ab) = circuit::initialize().unwrap();
+        let public_input = to_bytes!(circuit::demo_public_input()).unwrap();
+
+        assert_eq!(
+            set_callback_handler(storage, session, [9u8; 32], ""),
+            Err(Error::InvalidInput)
+        );
+        set_callback_handler(storage, session, [9u8; 32], "mint").unwrap();
+        // the inputs passed to the callback have to be the ones the proof is verified with
+        assert_eq!(
+            gamma_miller_loop_handler(storage, &EmbeddedKey, session, 64, 0, &prepared_input),
+            Err(Error::MissingState)
+        );
+        prepare_inputs_handler(storage, &EmbeddedKey, session, &session.id(), &public_input)
+            .unwrap();
+        miller_loop_steps(|i, j| {
+            gamma_miller_loop_handler(storage, &EmbeddedKey, session, i, j, &prepared_input)
+                .unwrap();
+            delta_miller_loop_handler(storage, &EmbeddedKey, session, i, j, &proof_c).unwrap();
+        });
+        qap_miller_loop(storage, session, &proof_ab);
+        final_exponentiation_until_y15(storage, session);
+        // the callback only runs for an accepted proof
+        assert_eq!(
+            run_callback_handler(storage, &EmbeddedKey, session, &mut RevertingInvoker),
+            Err(Error::MissingState)
+        );
+        final_exponentiation_handler(storage, &EmbeddedKey, session, 16, 0, &[]).unwrap();
+        public_input
+    }
+
+    #[test]
+    fn test_callback_runs_once() {
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+        let mut invoker = RecordingInvoker {
+            calls: vec![],
+            status: 7,
+        };
+
+        let public_input = verify_with_callback(&mut storage, &session);
+        run_callback_handler(&mut storage, &EmbeddedKey, &session, &mut invoker).unwrap();
+
+        let callback = Callback {
+            contract_hash: [9u8; 32],
+            entry_point: "mint".to_string(),
+        };
+        assert_eq!(
+            invoker.calls,
+            vec![(callback, EmbeddedKey.id(), public_input)]
+        );
+        assert_eq!(callback_status(&storage, &session), Some(7));
+
+        // a failed callback leaves the accepted proof accepted and does not run again
+        final_exponentiation_handler(&mut storage, &EmbeddedKey, &session, 16, 0, &[]).unwrap();
+        assert_eq!(
+            run_callback_handler(&mut storage, &EmbeddedKey, &session, &mut invoker),
+            Err(Error::MissingState)
+        );
+        assert_eq!(invoker.calls.len(), 1);
+    }
+
+    #[test]
+    fn test_reverting_callback() {
+        let mut storage = MemoryStorage::new();
+        let session = Session::new([1u8; 32]);
+        verify_with_callback(&mut storage, &session);
+
+        // the deploy running the callback reverts and leaves the storage as it was
+        let mut deploy = storage.clone();
+        let reverted = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            run_callback_handler(&mut deploy, &EmbeddedKey, &session, &mut RevertingInvoker)
+        }));
+        assert!(reverted.is_err());
+
+        // the proof was accepted in an earlier deploy and the callback can be run again
+        assert_eq!(callback_status(&storage, &session), None);
+        let mut invoker = RecordingInvoker {
+            calls: vec![],
+            status: 0,
+        };
+        run_callback_handler(&mut storage, &EmbeddedKey, &session, &mut invoker).unwrap();
+        assert_eq!(invoker.calls.len(), 1);
+        assert_eq!(callback_status(&storage, &session), Some(0));
+    }
+
+    #[test]
+    fn test_relayed_session() {
+        let (proof_c, _, proof_ab) = circuit::initialize().unwrap();
+        let public_input = to_bytes!(circuit::demo_public_input()).unwrap();
+        let mut storage = MemoryStorage::new();
+        let prover = [1u8; 32];
+
+        let mut input = [public_input, proof_ab, proof_c].concat();
+        assert_eq!(
+            submit_handler(&mut storage, &EmbeddedKey, &prover, &input[1..]),
+            Err(Error::InvalidInput)
+        );
+        let session = submit_handler(&mut storage, &EmbeddedKey, &prover, &input).unwrap();
+        assert_eq!(session, relayed_session(&prover, &input));
+
+        let schedule = schedule();
+        for expected in &schedule {
+            assert_eq!(
+                step_handler(&mut storage, &EmbeddedKey, &session).unwrap(),
+                *expected
+            );
+        }
+        assert_eq!(
+            schedule.last(),
+            Some(&Step::FinalExponentiation { t: 16, j: 0 })
+        );
+        assert_eq!(read_cursor(&storage, &session), Ok(schedule.len()));
+        assert_eq!(
+            step_handler(&mut storage, &EmbeddedKey, &session),
+            Err(Error::UnexpectedStage)
+        );
+
+        // a tampered proof stops at the final check and does not advance
+        let a_start = input.len() - PROOF_LEN;
+        let a = G1Affine::read(&input[a_start..a_start + 65]).unwrap();
+        input[a_start..a_start + 65].copy_from_slice(&to_bytes!(-a).unwrap());
+        let session = submit_handler(&mut storage, &EmbeddedKey, &prover, &input).unwrap();
+        for _ in 1..schedule.len() {
+            step_handler(&mut storage, &EmbeddedKey, &session).unwrap();
+        }
+        assert_eq!(
+            step_handler(&mut storage, &EmbeddedKey, &session),
+            Err(Error::InvalidProof)
+        );
+        assert_eq!(read_cursor(&storage, &session), Ok(schedule.len() - 1));
+        assert_eq!(
+            step_handler(&mut storage, &EmbeddedKey, &Session::new([2u8; 32])),
+            Err(Error::MissingState)
+        );
+    }
+
+    /// Keeps track of the escrowed amount and of what every account has been paid.
+    #[derive(Default)]
+    struct MockEscrow {
+        escrowed: u64,
+        paid: HashMap<[u8; 32], u64>,
+    }
+
+    impl Escrow for MockEscrow {
+        fn deposit(&mut self, amount: u64) -> Result<(), Error> {
+            self.escrowed += amount;
+            Ok(())
+        }
+
+        fn pay(&mut self, account: &[u8; 32], amount: u64) -> Result<(), Error> {
+            self.escrowed = self
+                .escrowed
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientBalance)?;
+            *self.paid.entry(*account).or_default() += amount;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_relayer_bounty() {
+        let (proof_c, _, proof_ab) = circuit::initialize().unwrap();
+        let public_input = to_bytes!(circuit::demo_public_input()).unwrap();
+        let input = [public_input, proof_ab, proof_c].concat();
+        let mut storage = MemoryStorage::new();
+        let mut escrow = MockEscrow::default();
+        let (prover, ali, bob) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+        let steps = schedule().len() as u64;
+        let deposit = Deposit {
+            amount: 2 * steps + 5,
+            fee: 2,
+            expires_at: 1000,
+        };
+
+        assert_eq!(
+            submit_with_bounty_handler(
+                &mut storage,
+                &EmbeddedKey,
+                &mut escrow,
+                &prover,
+                &input,
+                Deposit { fee: 0, ..deposit }
+            ),
+            Err(Error::InvalidInput)
+        );
+        let session = submit_with_bounty_handler(
+            &mut storage,
+            &EmbeddedKey,
+            &mut escrow,
+            &prover,
+            &input,
+            deposit,
+        )
+        .unwrap();
+        assert_eq!(
+            refund_handler(&mut storage, &mut escrow, &session, 999),
+            Err(Error::BountyLocked)
+        );
+
+        for k in 0..steps {
+            let relayer = if k % 2 == 0 { ali } else { bob };
+            relay_step_handler(&mut storage, &EmbeddedKey, &mut escrow, &session, &relayer)
+                .unwrap();
+        }
+        assert_eq!(escrow.paid[&ali] + escrow.paid[&bob], 2 * steps);
+        assert_eq!(escrow.paid[&prover], 5);
+        assert_eq!(escrow.escrowed, 0);
+        assert_eq!(read_bounty(&storage, &session), Ok(None));
+
+        // an unfinished session is refunded once its bounty expires
+        let session = submit_with_bounty_handler(
+            &mut storage,
+            &EmbeddedKey,
+            &mut escrow,
+            &prover,
+            &input,
+            deposit,
+        )
+        .unwrap();
+        relay_step_handler(&mut storage, &EmbeddedKey, &mut escrow, &session, &ali).unwrap();
+        refund_handler(&mut storage, &mut escrow, &session, 1000).unwrap();
+        assert_eq!(escrow.paid[&prover], 5 + 2 * steps + 3);
+        assert_eq!(escrow.escrowed, 0);
+        assert_eq!(
+            refund_handler(&mut storage, &mut escrow, &session, 1000),
+            Err(Error::MissingState)
+        );
+    }
+}
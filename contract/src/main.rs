@@ -1,5 +1,3 @@
-#![allow(unused_parens)]
-#![allow(non_snake_case)]
 #![no_main]
 
 extern crate alloc;
@@ -8,19 +6,41 @@ use contract::{
     contract_api::{
         runtime,
         storage::{self, create_contract_package_at_hash},
+        system,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
-use types::contracts::NamedKeys;
+use types::{contracts::NamedKeys, URef};
+use verifier::batch::{
+    batch_commit_handler, batch_delta_miller_loop_handler, batch_fold_handler,
+    batch_gamma_miller_loop_handler, batch_qap_miller_loop_handler,
+};
+use verifier::bounty::{refund_handler, relay_step_handler, submit_with_bounty_handler, Deposit};
+use verifier::callback::{run_callback_handler, set_callback_handler};
+use verifier::final_exponentiation::final_exponentiation_handler;
+use verifier::inputs::prepare_inputs_handler;
+#[cfg(feature = "caller")]
+use verifier::inputs::CallerKey;
+#[cfg(feature = "root")]
+use verifier::merkle_key::MerkleKey;
+use verifier::miller_loop::{
+    delta_miller_loop_handler, gamma_miller_loop_handler, qap_miller_loop_handler,
+};
+#[cfg(feature = "nullifier")]
+use verifier::nullifier::NullifierKey;
+#[cfg(not(any(feature = "range", feature = "root")))]
+use verifier::pvk::EmbeddedKey;
+#[cfg(feature = "range")]
+use verifier::range_key::RangeKey;
+use verifier::relay::submit_handler;
+#[cfg(feature = "root")]
+use verifier::roots::RootKey;
+use verifier::roots::{set_root_handler, ADMIN_KEY};
+use verifier::session::Session;
 pub mod entry_points;
-pub mod error;
-pub mod final_exponentiation;
-pub mod miller_loop;
-pub mod pvk;
 pub mod utils;
 
-use crate::final_exponentiation::final_exponentiation_handler;
-use crate::miller_loop::{delta_miller_loop_handler, gamma_miller_loop_handler};
+use crate::utils::{CasperEscrow, CasperInvoker, CasperStorage};
 
 #[no_mangle]
 pub extern "C" fn gamma_miller_loop() {
@@ -28,7 +48,15 @@ pub extern "C" fn gamma_miller_loop() {
     let j: u8 = runtime::get_named_arg("j");
     let input: Vec<u8> = runtime::get_named_arg("input");
 
-    gamma_miller_loop_handler(i as usize, j as usize, input.as_slice());
+    gamma_miller_loop_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &caller_session(),
+        i as usize,
+        j as usize,
+        input.as_slice(),
+    )
+    .unwrap_or_revert();
 }
 
 #[no_mangle]
@@ -37,7 +65,31 @@ pub extern "C" fn delta_miller_loop() {
     let j: u8 = runtime::get_named_arg("j");
     let input: Vec<u8> = runtime::get_named_arg("input");
 
-    delta_miller_loop_handler(i as usize, j as usize, input.as_slice());
+    delta_miller_loop_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &caller_session(),
+        i as usize,
+        j as usize,
+        input.as_slice(),
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn qap_miller_loop() {
+    let i: u8 = runtime::get_named_arg("i");
+    let j: u8 = runtime::get_named_arg("j");
+    let input: Vec<u8> = runtime::get_named_arg("input");
+
+    qap_miller_loop_handler(
+        &mut CasperStorage,
+        &caller_session(),
+        i as usize,
+        j as usize,
+        input.as_slice(),
+    )
+    .unwrap_or_revert();
 }
 
 #[no_mangle]
@@ -45,9 +97,246 @@ pub extern "C" fn final_exponentiation() {
     let i: u8 = runtime::get_named_arg("i");
     let j: u8 = runtime::get_named_arg("j");
     let input: Vec<u8> = runtime::get_named_arg("input");
-    let keys: Vec<String> = runtime::get_named_arg("keys");
 
-    final_exponentiation_handler(i as usize, j as usize, input.as_slice(), keys.as_slice());
+    final_exponentiation_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &caller_session(),
+        i as usize,
+        j as usize,
+        input.as_slice(),
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn prepare_inputs() {
+    let input: Vec<u8> = runtime::get_named_arg("input");
+
+    prepare_inputs_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &caller_session(),
+        &runtime::get_caller().value(),
+        input.as_slice(),
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_callback() {
+    let contract_hash: [u8; 32] = runtime::get_named_arg("contract_hash");
+    let entry_point: String = runtime::get_named_arg("entry_point");
+
+    set_callback_handler(
+        &mut CasperStorage,
+        &caller_session(),
+        contract_hash,
+        &entry_point,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn run_callback() {
+    run_callback_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &caller_session(),
+        &mut CasperInvoker,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn submit() {
+    let input: Vec<u8> = runtime::get_named_arg("input");
+
+    submit_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &runtime::get_caller().value(),
+        input.as_slice(),
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn step() {
+    let session_id: [u8; 32] = runtime::get_named_arg("session_id");
+
+    relay_step_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &mut CasperEscrow::new(),
+        &Session::new(session_id),
+        &runtime::get_caller().value(),
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn submit_with_bounty() {
+    let input: Vec<u8> = runtime::get_named_arg("input");
+    let amount: u64 = runtime::get_named_arg("amount");
+    let fee: u64 = runtime::get_named_arg("fee");
+    let ttl: u64 = runtime::get_named_arg("ttl");
+    let purse: URef = runtime::get_named_arg("purse");
+    let now: u64 = runtime::get_blocktime().into();
+
+    submit_with_bounty_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &mut CasperEscrow::with_source(purse),
+        &runtime::get_caller().value(),
+        input.as_slice(),
+        Deposit {
+            amount,
+            fee,
+            expires_at: now.saturating_add(ttl),
+        },
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn refund() {
+    let session_id: [u8; 32] = runtime::get_named_arg("session_id");
+    let now: u64 = runtime::get_blocktime().into();
+
+    refund_handler(
+        &mut CasperStorage,
+        &mut CasperEscrow::new(),
+        &Session::new(session_id),
+        now,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn set_root() {
+    let root: Vec<u8> = runtime::get_named_arg("root");
+    let accepted: bool = runtime::get_named_arg("accepted");
+
+    set_root_handler(
+        &mut CasperStorage,
+        &runtime::get_caller().value(),
+        root.as_slice(),
+        accepted,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_commit() {
+    let input: Vec<u8> = runtime::get_named_arg("input");
+
+    batch_commit_handler(&mut CasperStorage, &caller_session(), input.as_slice())
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_fold() {
+    let i: u8 = runtime::get_named_arg("i");
+
+    batch_fold_handler(&mut CasperStorage, &caller_session(), i as usize).unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_qap_miller_loop() {
+    let i: u8 = runtime::get_named_arg("i");
+    let j: u8 = runtime::get_named_arg("j");
+
+    batch_qap_miller_loop_handler(
+        &mut CasperStorage,
+        &caller_session(),
+        i as usize,
+        j as usize,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_gamma_miller_loop() {
+    let i: u8 = runtime::get_named_arg("i");
+    let j: u8 = runtime::get_named_arg("j");
+
+    batch_gamma_miller_loop_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &caller_session(),
+        i as usize,
+        j as usize,
+    )
+    .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn batch_delta_miller_loop() {
+    let i: u8 = runtime::get_named_arg("i");
+    let j: u8 = runtime::get_named_arg("j");
+
+    batch_delta_miller_loop_handler(
+        &mut CasperStorage,
+        &verifying_key(),
+        &caller_session(),
+        i as usize,
+        j as usize,
+    )
+    .unwrap_or_revert();
+}
+
+#[cfg(any(
+    all(feature = "nullifier", feature = "caller"),
+    all(feature = "nullifier", feature = "root"),
+    all(feature = "caller", feature = "root")
+))]
+compile_error!(
+    "the embedded key has a single public input, enable one of `nullifier`, `caller`, `root`"
+);
+
+#[cfg(all(
+    feature = "range",
+    any(feature = "nullifier", feature = "caller", feature = "root")
+))]
+compile_error!("the public input of the range key is a commitment, `range` excludes the others");
+
+/// The embedded key, with its only public input as a nullifier if the contract is built with
+/// the `nullifier` feature or as the submitting account with the `caller` feature.
+#[cfg(not(any(
+    feature = "nullifier",
+    feature = "caller",
+    feature = "root",
+    feature = "range"
+)))]
+fn verifying_key() -> EmbeddedKey {
+    EmbeddedKey
+}
+
+#[cfg(feature = "nullifier")]
+fn verifying_key() -> NullifierKey<EmbeddedKey> {
+    NullifierKey::new(EmbeddedKey, 0)
+}
+
+#[cfg(feature = "caller")]
+fn verifying_key() -> CallerKey<EmbeddedKey> {
+    CallerKey::new(EmbeddedKey, 0)
+}
+
+/// The key of the membership circuit with the `root` feature, its root has to be registered.
+#[cfg(feature = "root")]
+fn verifying_key() -> RootKey<MerkleKey> {
+    RootKey::new(MerkleKey, 0)
+}
+
+/// The key of the range circuit with the `range` feature, the contract prepares the commitment.
+#[cfg(feature = "range")]
+fn verifying_key() -> RangeKey {
+    RangeKey
+}
+
+fn caller_session() -> Session {
+    Session::new(runtime::get_caller().value())
 }
 
 #[no_mangle]
@@ -55,11 +344,22 @@ pub extern "C" fn call() {
     let entry_points = entry_points::default();
 
     let data_seed_uref = storage::new_dictionary("data").unwrap_or_revert();
+    let nullifiers_seed_uref = storage::new_dictionary("nullifiers").unwrap_or_revert();
+    let roots_seed_uref = storage::new_dictionary("roots").unwrap_or_revert();
+    // The installing account administers the registry of Merkle roots.
+    storage::dictionary_put(
+        data_seed_uref,
+        ADMIN_KEY,
+        runtime::get_caller().value().to_vec(),
+    );
 
     let mut named_keys = NamedKeys::new();
 
     let (contract_package_hash, access_uref) = create_contract_package_at_hash();
     named_keys.insert("data".to_string(), data_seed_uref.into());
+    named_keys.insert("nullifiers".to_string(), nullifiers_seed_uref.into());
+    named_keys.insert("roots".to_string(), roots_seed_uref.into());
+    named_keys.insert("escrow".to_string(), system::create_purse().into());
 
     named_keys.insert(
         "contract_package_hash".to_string(),
@@ -0,0 +1,4498 @@
+use ark_bn254::{Fq12, Fq2, G1Affine};
+
+use crate::pvk::{ell_coeff_at, fq12_at, g1_points, VerifyingKey};
+
+/// The verifying key of `circuit::merkle::MerkleMembership` for Poseidon trees of depth 16, from
+/// the ceremony whose keys are in `circuit/keys`, compiled into the contract built with the
+/// `root` feature.
+///
+/// The only public input is the root of the tree, the contract wraps the key in a
+/// [`crate::roots::RootKey`] so that only proofs against registered roots are accepted.
+///
+/// The constants are printed by `cargo run -p circuit --bin embed_key -- circuit/keys/merkle.vk
+/// merkle`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MerkleKey;
+
+impl VerifyingKey for MerkleKey {
+    fn gamma_coeff(&self, j: usize, k: usize) -> Fq2 {
+        ell_coeff_at(&GAMMA, j, k)
+    }
+
+    fn delta_coeff(&self, j: usize, k: usize) -> Fq2 {
+        ell_coeff_at(&DELTA, j, k)
+    }
+
+    fn alpha_g1_beta_g2(&self) -> Fq12 {
+        fq12_at(&ALPHA_G1_BETA_G2)
+    }
+
+    fn gamma_abc_g1(&self) -> Vec<G1Affine> {
+        g1_points(&GAMMA_ABC)
+    }
+}
+
+const GAMMA: [u64; 2184] = [
+    15824518647578761371,
+    203067740375347574,
+    6241640849666670560,
+    2461035078438933675,
+    16837035085898259853,
+    2575490871789969881,
+    110065737224207784,
+    57888409046454441,
+    12719724135193450167,
+    3846034792555993258,
+    11876485324144118334,
+    3382772191705589761,
+    11158637510296916359,
+    12733134025420842214,
+    8826343238474647776,
+    458490857369087801,
+    5413822891161144948,
+    14877962490563800770,
+    15828587614475222072,
+    1752108451241087893,
+    198345982764818361,
+    17385559937321579054,
+    11890153513944332031,
+    3466017875652199975,
+    15934454926212423226,
+    2319208625007019831,
+    6948641026820180249,
+    853424422934644779,
+    12692609322511729798,
+    11066621579268474484,
+    14306434715029076654,
+    587081233175838422,
+    4645795977405144867,
+    9101362166677246047,
+    3372061732398673239,
+    2891643580423967742,
+    6555120652850568697,
+    14316423528330044538,
+    12758999237265640559,
+    2199016773088532532,
+    17903942716445423613,
+    11897098224782947435,
+    9278300172300710542,
+    2972803860617059688,
+    6640050814115134878,
+    15950908994781682318,
+    7912623438395298691,
+    1375476486582032833,
+    7211745944548607486,
+    8801714121496027634,
+    1361214854425282229,
+    3357113178197015578,
+    3572584236283685924,
+    10897758529246232335,
+    4911867928997403198,
+    600487942109664096,
+    6905301745361760459,
+    5902342558262418970,
+    108297149807007919,
+    1207186279999046950,
+    9329372425846305820,
+    18389962932545404062,
+    10871487568339636952,
+    1062707073607136996,
+    5227346396731062482,
+    400704329863346950,
+    4315605380442974249,
+    926203522095629983,
+    2657146365294727054,
+    1850377356230367895,
+    15922209086693395425,
+    3235379075258074857,
+    2328140714945391926,
+    16298954330043302672,
+    4065138985504176904,
+    1916258157281377443,
+    17430444372808661605,
+    3111017306480874727,
+    7248296922298144364,
+    439366159335625938,
+    11260707261673350455,
+    9110164680057542378,
+    5039680513538769388,
+    2921351995774646782,
+    1900238988084651935,
+    925548789944540728,
+    18085901514320869502,
+    2246492206536661221,
+    7869834661972618602,
+    2733904266883837143,
+    16441703146545200460,
+    1626390324795743255,
+    13416648344113355591,
+    1485591606410919476,
+    11049040651874066377,
+    1402616088443746557,
+    11779186918431172339,
+    10569252539517944101,
+    11481474473337465627,
+    3184321505672560534,
+    16492652701505659331,
+    1992809080261781964,
+    14496539711532959374,
+    2004417551594955478,
+    401790529848891880,
+    8892732833660901466,
+    10735265473252111181,
+    731896913757636809,
+    1625387798719737077,
+    14941486645747635026,
+    14032720650906318180,
+    890857480423284332,
+    2989962817546872653,
+    5779089262159032968,
+    560749799320146780,
+    586781281372968443,
+    5897152264238716171,
+    1046603329327034264,
+    15315797287782067872,
+    1188619393749164036,
+    16351841530142915670,
+    16620929737119939328,
+    6729622192550810514,
+    1628454304151335491,
+    16085705287984765418,
+    7765157101276454682,
+    2673455620799465297,
+    153161202090465225,
+    937830722072987987,
+    6538556023085232604,
+    11154385333108630884,
+    2107706152013160120,
+    15165574427821744245,
+    4314273384107443382,
+    17315260020615823915,
+    1022772228895954337,
+    13238532977855813681,
+    17996701319662345355,
+    14828936565548612596,
+    1344210413408551664,
+    16154940910219274091,
+    7525961597062994688,
+    4481160186467737123,
+    2616110979358758928,
+    16115935237170128907,
+    9833587811800366529,
+    8052586829326573511,
+    2901362849433045379,
+    7453464237738954010,
+    3008256093050980303,
+    1451408592212251413,
+    959763290719983377,
+    8749836142310892905,
+    11834553183954865392,
+    13684505695005336770,
+    3335949562362779782,
+    12829396670047455862,
+    10592441333236805906,
+    8320812888388721755,
+    1864059325393577438,
+    13104154405728292352,
+    15333485775238375029,
+    162418605332080878,
+    2251486705350849140,
+    18325105314360157410,
+    11172944359971081702,
+    16071780282258362513,
+    3147820182379862168,
+    998667551563831206,
+    4756790993225640669,
+    7404600297865526740,
+    3326098090780981362,
+    16649994495594663209,
+    5365049650968780994,
+    17890803666283947012,
+    2748891136636022118,
+    11761696759739922089,
+    629629406362475461,
+    11691791319817957707,
+    684072471830379854,
+    7602816623779076638,
+    18257948078494263351,
+    835615974797171748,
+    191703453309403446,
+    938473309368262180,
+    12532865971342852508,
+    4022503389162274255,
+    1831129251025536270,
+    4684954071143590629,
+    13482927585304307156,
+    2876184453785839007,
+    3485229363772029134,
+    17163160019580483513,
+    9082214663070279879,
+    11979595955567717479,
+    2266780615618986748,
+    2936500141689907997,
+    9069994633699526466,
+    11219899355464799284,
+    3182549296414962169,
+    15327267125087699617,
+    15188218563241480239,
+    5885872646553795854,
+    1299468172064354255,
+    16045835552204488871,
+    1197701777651724546,
+    8455050119725468878,
+    2656070041979740196,
+    4763330666176257086,
+    1933029275777994,
+    16371884370669685089,
+    1942955873810526164,
+    10769410846971655418,
+    10980547332030686338,
+    2403618237340039353,
+    2141991355264012364,
+    12128440495889835916,
+    2764004572955047863,
+    14958102297136040201,
+    2546050237449725199,
+    8145705641609084105,
+    8186328727045304316,
+    12788857945330822883,
+    439154013135800192,
+    17221123205168077489,
+    14320121586575209440,
+    15632953826567631756,
+    1669753976590160302,
+    15837690585905125548,
+    15930685153914068231,
+    4558200666936637512,
+    1764400200416635216,
+    12291697339332067376,
+    13180075914701041475,
+    15004776664578864622,
+    2222400270971537264,
+    1804316000668638516,
+    12766889940675664606,
+    8124833724182241417,
+    751496834583627783,
+    18364256183327310492,
+    16070988519045522669,
+    7996805022930217449,
+    205022266149699756,
+    18314523447973953707,
+    12393229360746736909,
+    10965978872116551395,
+    2806268857596037738,
+    16827555546924631905,
+    1189588245421081616,
+    8233904479305819549,
+    2459871972609445822,
+    12148372711388984772,
+    17557744178218260672,
+    8917424059737898425,
+    1835574230381317083,
+    2002369037171054219,
+    16029209798582072201,
+    8754975173079397988,
+    417932140832800616,
+    2327402253505799365,
+    12844442437829288258,
+    15475276314924016344,
+    1060603421512893765,
+    2374434589166301122,
+    18304085097604965874,
+    10537206145615510077,
+    1506540816180236767,
+    7154429952908213563,
+    8673287668027271049,
+    3082151662382135610,
+    2962816262208354638,
+    2791173399198985981,
+    2072940716575731576,
+    17166438264678097788,
+    1630717453931611352,
+    8176369659027916627,
+    7621056937009900556,
+    8205969717881614277,
+    2910069719381699422,
+    13565354773939640142,
+    4686357384920428587,
+    15921630869590279770,
+    2279842889095713479,
+    12365181409279721449,
+    1324772755069629207,
+    2221570181337780997,
+    2234608719801264656,
+    8742045623484596695,
+    1203357192128495207,
+    1916258642803092743,
+    781684258691398971,
+    1131433899761834039,
+    1017010312204307478,
+    3122813014687127009,
+    2630628588962502765,
+    15286974084979485365,
+    12685540879928123666,
+    16217279223868411632,
+    97878586087371220,
+    16768823886275680193,
+    2499760429987432907,
+    12298110334963020576,
+    2061255551947680607,
+    15633985113901090583,
+    7425520036735694706,
+    2633077816541645691,
+    1946533900193974986,
+    17534153675172093521,
+    2784213251666681857,
+    12157107364206375245,
+    877729019221971972,
+    14604679386507069514,
+    10271186942853204335,
+    3887484799426008415,
+    2844007205093995286,
+    3874553056298268966,
+    1540213720934739901,
+    1326786044231504956,
+    43345192368233108,
+    18072539062119015933,
+    7408888721491100654,
+    2717449201733594154,
+    1305110586739497807,
+    10555675272061929811,
+    12929199704258124174,
+    8409025820599546592,
+    1495531266640376949,
+    6588465429714665193,
+    15296004809394209463,
+    4361952940396688802,
+    3389724163111277518,
+    16127253463266616545,
+    17955139397362458272,
+    9255438082517592856,
+    2022455800245822240,
+    4536402937419412666,
+    15350057430638713220,
+    2729529540858967839,
+    2780257435499923494,
+    7251282687010168261,
+    8207453936695292555,
+    17440143767730031030,
+    3083031625395831168,
+    7525935046906202846,
+    8291727424430969136,
+    7256646073281075023,
+    1140016328816049476,
+    16798044949517118051,
+    13721557957588961626,
+    17620325860027471540,
+    1741427472114484910,
+    6598209343745071112,
+    11902073037729680528,
+    17279178375702028883,
+    1150063327339938950,
+    2567012558441614781,
+    15880106974891566477,
+    3281072885323012347,
+    464129658268211365,
+    8895022853431312937,
+    17118791808611175110,
+    8188342629775563922,
+    953293467980923248,
+    8611689492232598250,
+    9438222352933529808,
+    13333241183607671182,
+    562735643724193844,
+    521785065059198453,
+    1932109461765504210,
+    16776759455288098812,
+    1814423886496654254,
+    7200286549811210970,
+    3832738418580686263,
+    1976943917651108719,
+    2959435211394812641,
+    6338067942041182271,
+    9970741484670900723,
+    4874129175468985729,
+    3263565724108408056,
+    17401668856370448056,
+    6877416894385210208,
+    18111379143909217626,
+    2548491208826227855,
+    17884857893237018327,
+    9606237213205855619,
+    10632381487729231291,
+    449538125541722113,
+    3847838938999661365,
+    7479995681847687629,
+    4059786921764030945,
+    2768649169240709341,
+    9890255926188160787,
+    8078298768870475388,
+    12882276401275934526,
+    1856839822933254379,
+    14591508305024811508,
+    12466051965935004380,
+    13056089699629653805,
+    307925449041122070,
+    15502956603787926027,
+    16945176100585887828,
+    11880777146977329546,
+    3478675924069784482,
+    11937315163136959319,
+    10336709987534859387,
+    9406126215638151233,
+    1461612503788955997,
+    10655264103108184508,
+    8298837520712546390,
+    7633738335139782459,
+    773162078845168007,
+    11106835726907463078,
+    11045323211098743258,
+    3778023736365105220,
+    370657102722287431,
+    10170636535158939857,
+    1795550853333688862,
+    9944823682990199904,
+    3082254292171759258,
+    18369943902755793736,
+    5836871454957449579,
+    8940021284059620725,
+    2264169996439179452,
+    3584646101861788172,
+    10520120556173328849,
+    6934868103787504080,
+    2108705912952426300,
+    13881847508060852345,
+    3833188239349317111,
+    1454067356215192644,
+    2386005313861238323,
+    17847106737888887436,
+    14825075801162844762,
+    18377239650007088771,
+    543161039922851060,
+    6374499600097819543,
+    2689186870268095238,
+    18151149066529989948,
+    1174925688351161416,
+    12137377539622971935,
+    6040425732391865987,
+    9229372305622705591,
+    640954034367609523,
+    9684783816931200735,
+    5638621131800508071,
+    8407798547401092756,
+    3267043348755903012,
+    14344843248768911335,
+    5581315985777201422,
+    7633008332996815571,
+    2248151181891963911,
+    5157458371524106702,
+    14049357807035703463,
+    3856653412356068555,
+    325048841561250659,
+    12732513054250637365,
+    17642338695570347441,
+    13053123544758295635,
+    368895624450321488,
+    17214945167060997817,
+    8216747660987289712,
+    7782788839285721080,
+    2738985125336230926,
+    8814164483733869533,
+    4989987383060565304,
+    1225372014188297188,
+    573825294703307799,
+    12387063167520258886,
+    10657004421829722123,
+    8413610287432960497,
+    2259095631343386067,
+    9532910400222297394,
+    756842961372101740,
+    13026443451983345437,
+    810704069168038458,
+    9049564933617477424,
+    8444573791454188815,
+    16236567802238039081,
+    1956415057469266404,
+    4678268605306379846,
+    5573851793004628359,
+    9050705462878750254,
+    542917754694820549,
+    17526017207425692965,
+    464413412135016946,
+    7759514043989992377,
+    423628388209507353,
+    7934181039834362756,
+    12040362026888365527,
+    9426889370132976444,
+    753421886518238933,
+    12487837374346965745,
+    6960201690417558333,
+    2703756445929999246,
+    2028323902068190524,
+    13019439263125930864,
+    10314655361559041576,
+    8341984820055454745,
+    300881104502156744,
+    1092841769808044205,
+    10553987072806631046,
+    8559559152296413350,
+    2138702748741792431,
+    17596086852211175003,
+    11432636116765440708,
+    18077160145364314415,
+    130311735727783798,
+    3539050166669561572,
+    7261493029807374195,
+    7410182987592686973,
+    2778724799133911970,
+    15594335046885120065,
+    14416216418328497713,
+    5465326590415088537,
+    2110792011906719931,
+    11012391297573426851,
+    7418055249034135856,
+    18022715848055374966,
+    836903842097974064,
+    5511838693408301842,
+    11227406457230220645,
+    2293350272944464823,
+    606683561534185061,
+    2961126583668573707,
+    14220437715216262131,
+    6135419698862203473,
+    645796701528062819,
+    5009180179875629500,
+    10307865309705852857,
+    4885169965098379701,
+    1877549685416051612,
+    14446892261306309298,
+    16745450497445374783,
+    11050135323082328954,
+    2853042973331968710,
+    7876588652021771454,
+    2574899412364659534,
+    12525838845867550872,
+    35631068296788317,
+    8814814520930745194,
+    7066421192561986892,
+    14836842149825614900,
+    2624625988097093478,
+    14542225798657720482,
+    590298487954274877,
+    12715042831655784973,
+    140099462537613983,
+    5943216510612812536,
+    17934549572715174708,
+    12172999368857076840,
+    2657195155553629829,
+    10264589426963838177,
+    16133986109180313963,
+    540685376893242589,
+    254103677261525456,
+    8860191875704493262,
+    2646629995699380022,
+    14750265157878394036,
+    533975285880801958,
+    8471727533999572938,
+    12370606525445487169,
+    7890866725436272406,
+    2516659328394540615,
+    12742251097330118921,
+    45871730107012938,
+    14033124095793466801,
+    3150197619287460705,
+    8232718201771936023,
+    3685907387081996697,
+    16106843110830859866,
+    640157205873339425,
+    7997753367565013300,
+    16254116917767889799,
+    17883733657611218599,
+    2070747729309433160,
+    5345957702028756160,
+    15612910085822839227,
+    2925627515623052537,
+    2659504644127792589,
+    7055291279211370529,
+    6395080171175810737,
+    10042644465438422614,
+    2902794384459440237,
+    9973550341233062692,
+    11858687552762130823,
+    7258140900345870169,
+    2768841782563747964,
+    16358803406478879982,
+    17796950444534017740,
+    1727173073544535492,
+    3256923381252082910,
+    11776170390111468934,
+    12797242896924434255,
+    13215954807927278709,
+    835698931296029898,
+    15213426523965500643,
+    9888626849012157682,
+    685808681858912080,
+    2812434580624579242,
+    13038673192330229029,
+    14600713242043052264,
+    365195131275382736,
+    20576223375737636,
+    15839406558096134647,
+    6003262244910106463,
+    16470476427925393363,
+    638759430219365605,
+    7787609777255722324,
+    14161431398117663914,
+    311169816148435476,
+    3378172794680823129,
+    9143210844711307039,
+    9195964437702227217,
+    14363217677083676157,
+    847811227971211402,
+    3655563623984072289,
+    17413758027857993612,
+    10844587137481810430,
+    151415985381779470,
+    4798136975906666613,
+    5332858691294277971,
+    2153755283700430019,
+    1580499769232643746,
+    4258718056031130723,
+    2764585741222990999,
+    7119910121261081691,
+    1384847041379799697,
+    12997513353288030875,
+    16285957433972267553,
+    17776219383625263850,
+    2817020674029872357,
+    1435368529504663168,
+    13754819956733900017,
+    10052969206699028848,
+    355708437136068991,
+    1334827725171732854,
+    14414750078424538684,
+    5796461259734498087,
+    1459475361645385126,
+    295217611906406483,
+    14721565581673384696,
+    11176163163330973100,
+    409179490504475167,
+    2402093763100672397,
+    3914972103746947969,
+    17678837530277625477,
+    458496907363032298,
+    11058590692357463855,
+    1033613265407839762,
+    7697038736087775834,
+    1956880237663462999,
+    3862527392349695982,
+    6418870407340198547,
+    10222665441996549058,
+    2317354753163759004,
+    10007015492080472246,
+    7759602801712410769,
+    728991581300537330,
+    2762617370680246991,
+    2454105632912881231,
+    7264538916259085165,
+    15809336929230373506,
+    1148982990330286970,
+    8569480610495956075,
+    15750845378159989387,
+    3725551627047955662,
+    1383544638275293873,
+    11016455247244570135,
+    6497842996793493211,
+    1331657426257218215,
+    1503289897281942852,
+    15988582633532728010,
+    5038807175541191489,
+    5688248450428795837,
+    1076988008641122965,
+    10346484874292762961,
+    1272689005123438619,
+    6990406421184923022,
+    109210536865501840,
+    16664725414508281882,
+    9913467471166088355,
+    17528546995377981498,
+    1776727387021291865,
+    9452831724726637592,
+    2733243929931064452,
+    17026763874938379949,
+    636816250665639790,
+    17074934752306339192,
+    7004208657786267784,
+    12092491801991810935,
+    2070884238900651050,
+    12882072146445740323,
+    1582666778347399363,
+    10453555914783832624,
+    609917776564658003,
+    1972283734555969811,
+    285588939155511743,
+    961530743109550367,
+    2150507245988789467,
+    6902747364452871646,
+    816178770950569097,
+    14789882398039265775,
+    3168132503937601351,
+    3716411417672519238,
+    4820360581060564237,
+    1237118638562910136,
+    1667459969314139365,
+    6357844178073155029,
+    1955930161113613868,
+    13277637994352741903,
+    277851477970007988,
+    2789095423706468694,
+    7876170683622392711,
+    11310986387644165668,
+    3442176573081854761,
+    9273647227849827723,
+    15480301913453446094,
+    4075174277088758967,
+    2568752328124696339,
+    12710232752344212815,
+    8881193194147828121,
+    4371978689902540034,
+    1228624033721542249,
+    17233176649994255723,
+    16261096145731885466,
+    9070195672757342647,
+    1205391260398724709,
+    13270137137001678265,
+    13383540684304568201,
+    2115063841193040712,
+    3288958498789048365,
+    1361942797520925304,
+    8858234023841987413,
+    4351982678063099466,
+    789472160832228107,
+    15192288535933681580,
+    6680234897421441377,
+    1032940044762182236,
+    2233788890160905040,
+    1938929816939446000,
+    13847319297416478958,
+    17110577673645885738,
+    1550543167877440419,
+    2681490655893503502,
+    5261117892394846870,
+    11511000145493885095,
+    201041408299507290,
+    3766528066946332068,
+    6646078321143866542,
+    11145683219929974042,
+    426708410014464374,
+    11574447544237480609,
+    4029251255361588281,
+    17696762410051658124,
+    38188370694703410,
+    14788643057487927787,
+    15093393100290910825,
+    15616225600255233704,
+    216923079201652761,
+    4438569236153546265,
+    5790600276423527377,
+    9866044116922553441,
+    2039521338931483020,
+    9708697736467496171,
+    17605700840885884692,
+    17774788117960544804,
+    2775130014275384866,
+    14833866423752857520,
+    10418362184640014919,
+    17565522815249597873,
+    2464269610228029775,
+    3729941698354872027,
+    4583653837794874047,
+    5205320210135702102,
+    2515031189187311056,
+    12034196071200997631,
+    17038620647438714171,
+    3023868059693643684,
+    1692191857240809189,
+    5153834325970274071,
+    16904855319194861450,
+    16751911818516808739,
+    1535006768565575530,
+    9931244952180106886,
+    1723535024978083375,
+    12392269991759387162,
+    1321586427751997007,
+    14300837566547198251,
+    15003116949492664727,
+    13661077752441196042,
+    1080892013874631006,
+    16201433351219948776,
+    17902623291667140801,
+    7787708487949383058,
+    245560648727043938,
+    11819730450815405146,
+    14745059304648027110,
+    6957753940577197986,
+    754893702174959068,
+    1573125804808120123,
+    7372867660396694001,
+    10318751169791486268,
+    3278106241830145435,
+    11454448231504331242,
+    3782522794620906298,
+    15593369809501410708,
+    3152234218351198907,
+    10902112521229408035,
+    16807440692773219770,
+    3342416629161178442,
+    1471974596219841860,
+    9129534798250336596,
+    15644457084116597381,
+    8901811953446975823,
+    2127427690616255088,
+    8902491168714736213,
+    3019477130299109831,
+    11726984426321466552,
+    2658007430670153233,
+    13944012982427708494,
+    16473703664221676487,
+    4579304200545418692,
+    1266751428904705612,
+    8931910568301041719,
+    17420376261289230461,
+    4706116639952955501,
+    1039577436062648002,
+    3115785705254927452,
+    4240107244840484098,
+    2637881181287782777,
+    2106409490201978296,
+    6832318468918911060,
+    2886789612035133714,
+    9074636377548127451,
+    652435637728526561,
+    16931757311737054304,
+    10786266897078024573,
+    10714278096924876359,
+    3277650198204601746,
+    3836639095224333919,
+    11517216077213516622,
+    2016270049493392243,
+    1080341686168198484,
+    865004369205780011,
+    7711599114256438298,
+    14616829172091043168,
+    867936938338841327,
+    16436722559607322590,
+    13997623686946921532,
+    9379779826206754815,
+    1749026963554065492,
+    16978251817091034105,
+    3845044923255378988,
+    6053874202224235871,
+    1434194642563104530,
+    7922497673005944172,
+    8579032268803118568,
+    8427525440698690864,
+    271159129038940244,
+    13212189078495100779,
+    4041942120863925272,
+    9073371691442587563,
+    1874411372361237505,
+    16477749485328155985,
+    13886653787753141354,
+    18069640000386633441,
+    947354535681702616,
+    4294127308894984895,
+    13005058228178602346,
+    3330005938128554534,
+    1914097668989087835,
+    5246002564975234023,
+    6605719822686337080,
+    15171177615504267231,
+    2647465524663763245,
+    15626187334899283226,
+    11988138413272332068,
+    1550397630524990266,
+    2241477494683933657,
+    18009520358426591419,
+    13089431974083882332,
+    17047808877702458313,
+    986933914457519059,
+    3518320317557738414,
+    7952594523581763422,
+    5315316464526085518,
+    551871355239296240,
+    9434429890195928907,
+    520376882904051779,
+    5100207397235181582,
+    2025841952649785587,
+    8602910985814846643,
+    6653511057944189887,
+    14293220171782276424,
+    1073437551287797604,
+    15969421217263279557,
+    17744011636931783127,
+    3454314543165008546,
+    2243179591161420891,
+    4427521830732046106,
+    11060961874866350941,
+    6555936349400174969,
+    1753258787115187,
+    17974237312592210528,
+    17293273635737526082,
+    5943315882384526012,
+    2754172028340079391,
+    7178910822935183661,
+    11608144587375357139,
+    6096905283486606206,
+    2151310411977881947,
+    2349162275529563692,
+    7565719322807486198,
+    12740446037713949553,
+    1221446192690102588,
+    3439241147446164520,
+    9045104193339104723,
+    15526438261550340872,
+    1464877344992789734,
+    17438982870263253282,
+    7061147606828056401,
+    2934504265168795631,
+    1236123374333205770,
+    10584135791955662062,
+    18436025886032493592,
+    1594721919473263257,
+    2782150947462429528,
+    11466709126608400748,
+    16948977785911748189,
+    14547943003657726892,
+    389735222764848330,
+    489631378121711689,
+    7606647427600155601,
+    18068594157355267815,
+    1341495534740572274,
+    613061655142905114,
+    8620092150635444699,
+    7341170665799892081,
+    88088937325731215,
+    6761878864808574598,
+    15388234037427724072,
+    8811141392570314700,
+    1640264807218709746,
+    18095448826388885581,
+    7805026634509042676,
+    8826196303625951176,
+    2109641038980304844,
+    8947745180260356488,
+    5398727216246552780,
+    2616651434194145773,
+    2912181320037436863,
+    10137660846754146988,
+    10717100565655366112,
+    841629189742789765,
+    1417587312974849463,
+    3724222488628231621,
+    500607724745020745,
+    16891477771953553438,
+    3063719074922800278,
+    13743160689144498846,
+    7256076040743467553,
+    9694529337460457096,
+    509435103694146502,
+    17675935456590195232,
+    2051812916850277359,
+    15656382931455929555,
+    1743640648298039047,
+    1631575270480663788,
+    28078673905403668,
+    2950302955538403023,
+    891370170257524893,
+    1271287197520709495,
+    13862376826695945596,
+    16428427345453211330,
+    3399520437234871242,
+    6201481337283308160,
+    13693028802750804636,
+    9912892550899973013,
+    554870464165440214,
+    6976355878484615241,
+    7140170914574836768,
+    5768797282855623705,
+    419131610533791898,
+    7699046051215439351,
+    14232562835799706259,
+    92057395946741710,
+    1074029638565648955,
+    10612134065257416776,
+    2773307687643075856,
+    2378940293952862114,
+    1631452005738141714,
+    6234849779861010699,
+    14190859274100760775,
+    1704050305797699013,
+    391000653982563762,
+    10972574075345517728,
+    11262812703622237614,
+    15059384464829536563,
+    461412916404469394,
+    9502210474290451413,
+    3247573757231406024,
+    1154661231001689645,
+    2681149473217037297,
+    1324855230740182791,
+    13717145488100189351,
+    13332765128644392569,
+    1471011780075583423,
+    6323287119020617311,
+    13839114235056883561,
+    2810082550973082150,
+    1450150357911302728,
+    12635179919378889952,
+    140348969527748680,
+    14577770213547565113,
+    895970717463839286,
+    12768311085092479215,
+    10432118470449695184,
+    5753928132168857881,
+    2366446951209575953,
+    15707116126334773517,
+    8335366062816335274,
+    11896735163642897308,
+    2236500524750831797,
+    9413522275775906226,
+    145970173363592350,
+    5468679409945258371,
+    2178973276753223029,
+    12736324223192422872,
+    15680701691172574120,
+    11325465026080483189,
+    2446341801905889131,
+    7344273122153641738,
+    15422267913410375502,
+    5039169665124744719,
+    1346094650800048426,
+    16250046074823262158,
+    2698682052910825640,
+    9133209899373720686,
+    59552890721377567,
+    4616751815989999070,
+    5391601412618970285,
+    5073971250891884540,
+    1436458212415930822,
+    8261120875196310989,
+    6047865242254890866,
+    174352714774341469,
+    2683610189207853098,
+    123180191395350450,
+    1200692051365810593,
+    7567375373853216642,
+    1956328837867831925,
+    12976204762494717692,
+    5877204053771829741,
+    200407104625658279,
+    2417966319664764493,
+    3505717034782737378,
+    11269239508284900293,
+    9569975345681106618,
+    1096767321732466615,
+    7001644469567391267,
+    9576769799343711061,
+    17735203840680408984,
+    2289207400265301156,
+    16719274819269438214,
+    5530364579970358158,
+    15440821368331404707,
+    1366459410339453123,
+    11941611651492457342,
+    8925747939251204945,
+    9380698500789613611,
+    3136194123921321705,
+    7275270350149956863,
+    182540602739679000,
+    11859350958477286874,
+    2472460418431052896,
+    417751964573239664,
+    13099863782111161354,
+    16775099808453423786,
+    810230905632705121,
+    1574344290485460479,
+    13577739994579972706,
+    15581640077775307419,
+    841240749759581837,
+    3985401137790329194,
+    1733417041445386480,
+    10739210430907346865,
+    3125952217648486769,
+    938919793603472490,
+    14780347579058667136,
+    14165185604353671435,
+    36447255978122419,
+    8801229269149461670,
+    15208875135724616610,
+    13670198309356298014,
+    1549490146029602836,
+    14530705589039903768,
+    9909159479294529777,
+    17921523166429966682,
+    2583117330472671522,
+    10788932481110429710,
+    8442566859755066351,
+    6844870674021486371,
+    3411543056290708760,
+    17559314942219791136,
+    7128699730787268724,
+    15784221884345676187,
+    395885407327433408,
+    2440642111436142096,
+    11949502757123569126,
+    5090842271327715372,
+    1803126321797698379,
+    3452499049815221437,
+    14149407201009805419,
+    680247771336587603,
+    126895990534662138,
+    2230089236098325246,
+    9294753683391692999,
+    14216201938067435016,
+    1655522014069352893,
+    5983760773752290893,
+    12035073710098792719,
+    11335828695549067222,
+    1544450419900216002,
+    10736983696501332290,
+    8333998330130624210,
+    16753997975769970955,
+    2714447401933788745,
+    14429424051293338438,
+    6302241655109987106,
+    13244919489743753911,
+    2161365151093576174,
+    9077038493190190790,
+    3780233668634754180,
+    3063595498595959224,
+    1016397073421760508,
+    17039068898741501398,
+    9173272579155799185,
+    14529655004018072758,
+    723717631205122550,
+    4682056310471424043,
+    14838726845218432636,
+    8853477909322218227,
+    2291564661312032865,
+    7872667569549764844,
+    8737813522722653400,
+    13931422597148962773,
+    1161783101074544491,
+    15337433272235856417,
+    8306583665515218914,
+    4852055888587632811,
+    322168034576611374,
+    1402350961704510030,
+    7471739687632309816,
+    1429832320707902719,
+    2824213600601040283,
+    2570865036951863453,
+    6088409374694493838,
+    16816697317548907602,
+    1142014826886510138,
+    15456276892589542331,
+    16975159991204043682,
+    7552058732485569682,
+    1225265992017708384,
+    4554958925538685382,
+    5616384110317132177,
+    3804162997702442052,
+    619695004541428173,
+    3497407198934367966,
+    16987876926668325166,
+    18059693458627816096,
+    3197132188962554527,
+    9106308803681997528,
+    8918636652167002190,
+    6378187049008047495,
+    1594552464180714875,
+    13394973023145250472,
+    18330236667411197688,
+    12722499420424574541,
+    2819831035387168735,
+    13176284664344921904,
+    7947913747803161948,
+    3726757349238827930,
+    1577697853504582996,
+    9259290660647923796,
+    15967424075004380020,
+    16974847713551913424,
+    179297610226962436,
+    9090466145129173362,
+    9794212004234667814,
+    6208646302531798673,
+    733461100219557143,
+    10422860555709710581,
+    4530379900671444252,
+    209178458963753002,
+    589044275010746883,
+    7638387388582740462,
+    612922823103586653,
+    115048917872544444,
+    3267200895328979159,
+    7422254572729814607,
+    8447623231193468925,
+    9273559489451359005,
+    3347002586552417460,
+    3198841065485418481,
+    15533271039002831967,
+    7127362959265569703,
+    3428653159444900187,
+    11415886299783471866,
+    7427867819024558083,
+    14685002321581351877,
+    3325919015191711505,
+    17253119861031884776,
+    13501285575817985829,
+    6053214599977950764,
+    1693902570581816950,
+    12243226981873042990,
+    1266638728937766483,
+    14840631089185266868,
+    2596327245508089124,
+    7633719069396909584,
+    13925409311777937770,
+    18170732577792848909,
+    2943569029246941806,
+    7142385701951043437,
+    5213694361680021884,
+    2775597743853442993,
+    932550113237712079,
+    3262551259469445618,
+    1355132543099532684,
+    13252861263697906442,
+    1708309755237409350,
+    3894020711723250577,
+    298740094699035068,
+    11759668848754714698,
+    1411927331405842401,
+    13171024493186638782,
+    12545440111580011025,
+    8045965919080237259,
+    1438775137247689626,
+    5174042981630468476,
+    14484385293830027385,
+    4550337856134949969,
+    1687150833135935453,
+    12304733282125306050,
+    7478704721546399466,
+    7376493488754602037,
+    2502662218930748902,
+    2505245255747123496,
+    16749743006105103969,
+    7795275607524092900,
+    749395793136118234,
+    15626244508801650,
+    16193720969191436673,
+    1599526747838465100,
+    3096957249398467301,
+    5046551460209828928,
+    17213659805805482596,
+    21298166759371445,
+    541178664198281993,
+    10764004379274479431,
+    17769622374989628014,
+    17688405783347796594,
+    855977005265089910,
+    12955910260468900947,
+    4634931987475300261,
+    6242697611570157846,
+    2707567100556499060,
+    3904263580901421673,
+    12404584114543106906,
+    11434127922345743172,
+    119144690265577257,
+    9882985919267483285,
+    6154440704877129512,
+    10557500559639549206,
+    784281711917713489,
+    9457156925697763155,
+    10438248715957903606,
+    3832401180543569430,
+    2627337677804762428,
+    14683358912993134253,
+    12297385784656723959,
+    870534784670690299,
+    117211211506044525,
+    15268854856105846059,
+    5996220663893428596,
+    2057705561227214286,
+    1323841312805077231,
+    8765573792243501043,
+    16617377515337191927,
+    13218700907713149931,
+    558848514065291306,
+    16295305216355758843,
+    9193184938744119026,
+    16902754226017497185,
+    1025430167080675033,
+    3169575996641542673,
+    16721682190421939860,
+    15006709380011561563,
+    1258603179660058220,
+    10180845233234360953,
+    13656316366194025762,
+    13927156527826290126,
+    1188197877922777073,
+    8473619885997550288,
+    3760541911697028239,
+    15621638301284872552,
+    495628288343808949,
+    8345957935424645240,
+    3998042138680139016,
+    16767640565314576638,
+    1716390948036561765,
+    38211847435281131,
+    18013903049196074454,
+    17058953631107486502,
+    136794275735148739,
+    3728557132295775165,
+    18417141143068154019,
+    5983352504284029729,
+    1389950804573403738,
+    14877593072433043217,
+    14978712831699989909,
+    1400551459004322646,
+    627586891664402722,
+    12138458423557803721,
+    15750380526631657289,
+    12017780500647434454,
+    2941442507581217098,
+    5660225129585097899,
+    7807773712684443875,
+    4126081557836814152,
+    402315530288116648,
+    2607283800287377857,
+    14801285077857768174,
+    9126206592588100879,
+    3316293861838001613,
+    3142988844052649038,
+    2737207534844071197,
+    12231164060328564457,
+    1049518744407893611,
+    1296635210195156067,
+    3208331724581881567,
+    5856803709827945964,
+    2759806684986632729,
+    2284383496195392805,
+    10319015520617142037,
+    18439856442820546994,
+    1747676097998709138,
+    17186703927504541113,
+    16390887923723848974,
+    4807421764404349226,
+    906067079912188255,
+    10671192328507330676,
+    14781249160741251424,
+    12600215346582326278,
+    2009685371800152882,
+    7270926860399573246,
+    9718275588804108205,
+    7369444392917320016,
+    987012004231831333,
+    13942044439767544078,
+    4249566951472878154,
+    2722283215524058496,
+    1892961651808199935,
+    7693454116356832844,
+    12476207554253838078,
+    16329956192209831194,
+    550836739592681866,
+    8240720413981886064,
+    16203895943719449482,
+    11349473865176567682,
+    2845696797247954794,
+    8382235131484545254,
+    7990393756974890487,
+    10965211319535361285,
+    2899381804846948112,
+    17894432239321018395,
+    6563871061377120727,
+    9910373966665266923,
+    2480999681510549716,
+    14574392289025760591,
+    4366432391742865023,
+    8368821613233931173,
+    2647588944182033457,
+    16754530108280158622,
+    12348181618897933347,
+    11275691064003564069,
+    2212058097305646652,
+    3282226979437008503,
+    9751439418208151082,
+    12261990783466701660,
+    245942525579957836,
+    10099794295215822344,
+    5387088501403355977,
+    8166781533102983865,
+    2898052917464556636,
+    5577934924074969029,
+    1441039827379459100,
+    16979999718177225086,
+    2466466046132430611,
+    15154090055496368471,
+    16922905026953574107,
+    4650683714249760364,
+    1242943478632941222,
+    2694670448017274052,
+    6923732153893876253,
+    6649373398707786224,
+    2689071003752743500,
+    6184608539541914933,
+    14457476167859934450,
+    6614141078675579039,
+    1910673728994687697,
+    1541684967119129541,
+    1787056888199610609,
+    5284564620916991847,
+    1766900122400287830,
+    9110314970494617629,
+    3459005266472194368,
+    1669615899488004598,
+    2213876820939238066,
+    7253303650281044704,
+    1302216800830660968,
+    4271696211005173338,
+    63517354939456774,
+    12427165669525688935,
+    8731347321532717990,
+    7911307603510964807,
+    2531858647775640474,
+    12798043407388090632,
+    3709628270125023426,
+    16483205482526711558,
+    1597682854971117480,
+    3907765732768537522,
+    890042998512475763,
+    15165177391502757183,
+    2660682236424482450,
+    3201957253045146638,
+    9757184742020973607,
+    6170464203219223245,
+    2540639456510802126,
+    1357286904152233777,
+    4450039110804922549,
+    1623232469468065680,
+    258348990193506239,
+    14725826314494863687,
+    1261188482944336347,
+    7750341591335005750,
+    2292606592398102011,
+    2498069993412867316,
+    15023780935877195967,
+    16415190902105630349,
+    1219618193196871822,
+    7726013410187770601,
+    8265802767488035558,
+    16057120826631051044,
+    1558547668205512710,
+    6409969150992657109,
+    719337716462122181,
+    6124757899610392426,
+    1085846459497214490,
+    12774360169979230075,
+    6303154257614218097,
+    13014369872527123142,
+    2593463269700008695,
+    3389989023931940865,
+    1881597414032024992,
+    16068238324843476616,
+    160718323753838716,
+    2569417428053604734,
+    9255508831677580150,
+    13328562093090771536,
+    22201123752049333,
+    17892660437905201291,
+    8797222664850967173,
+    11095932462249298281,
+    1852724990952201007,
+    5205780439395935972,
+    15133428162943154410,
+    17260500314359471688,
+    2115592061884066345,
+    17013884402634916413,
+    10615818607353413104,
+    17068611225887001685,
+    576317481867423762,
+    4487540124457690253,
+    17588451784537022961,
+    12598558268792109602,
+    961131746690427067,
+    14635606989429013016,
+    4708281745517869599,
+    15382904105552453751,
+    170724168833055334,
+    2408356930533892645,
+    4496010810972412891,
+    11671722460596041767,
+    1370384782693277079,
+    7402586793637078251,
+    984166880969722768,
+    5180287355753601096,
+    2495724816700671819,
+    5157900045834838299,
+    3106505702520487558,
+    1018827930534932954,
+    23804036451053743,
+    15365583165081209947,
+    11795112098032745318,
+    3661255303228751128,
+    1837510618375892572,
+    10895128447598907397,
+    16160148163319740135,
+    2296212890467003277,
+    1264566856320443310,
+    11964964724041381939,
+    12026642928930971551,
+    5562902887678232745,
+    1952819526405706095,
+    7264771159351468537,
+    109709468537624048,
+    12883076231588152604,
+    637959554903357900,
+    9615900722256208407,
+    18213392924421785096,
+    13656317406987306781,
+    3435470807322586077,
+    11028051722500586596,
+    11260018582080417174,
+    15890874373798068044,
+    333398722104301038,
+    12615553651727720452,
+    11428355345519800953,
+    12408171636171854088,
+    1728194807955498063,
+    2246740330100976035,
+    1897218679127506703,
+    11974321901434575936,
+    1968587412482014017,
+    2461674666615009079,
+    14639747680634132380,
+    18153834459996484176,
+    2968342796856323628,
+    12060827873178152708,
+    16078079629376935201,
+    11222337580214545735,
+    2263787571735354320,
+    4084102720169705895,
+    4946638695368372126,
+    17643931094457178958,
+    2499493284430385534,
+    3407900039584884757,
+    13016423707236613712,
+    3638328623199376517,
+    662795001492328634,
+    100878795705231864,
+    13705206390733055181,
+    14888252745844296737,
+    3232279470809974282,
+    9609340430558626439,
+    17722850294767139534,
+    6131314832510191199,
+    314300300154342456,
+    4211048146184087860,
+    4587804138490466219,
+    2811366372073810974,
+    1706497475389458442,
+    13108812611774033185,
+    11633177739380421668,
+    6303313721971862355,
+    1590257112223382824,
+    1225093239893894555,
+    2477017988296137614,
+    15838295933612676614,
+    1215044353214081639,
+    14253918824758551099,
+    16639219434403932836,
+    7974774629716537243,
+    1630986256327596412,
+    6805731157122051406,
+    12521607887409719801,
+    2287271404114215479,
+    758768453799802928,
+    9530740859384978796,
+    17591932219468958821,
+    18121060620103657675,
+    3362757085632962673,
+    6423258783021092647,
+    18087348581204004127,
+    2565633925806069653,
+    1978072451343278805,
+    6731596768476078539,
+    5280585786075729921,
+    10885313726374717216,
+    551187897500567517,
+    1969394683596575968,
+    6793222332253281464,
+    11524342066545743982,
+    173932055440268251,
+    5078464453456671165,
+    14059624065086905106,
+    12775589508497636266,
+    3103333398737937505,
+    11113544376993804878,
+    7722374670855046300,
+    979583919685392591,
+    2942953427722604391,
+    11385939129807798796,
+    12862627436975908968,
+    16864894179075727561,
+    3234701246509994434,
+    191786573029583081,
+    9940210528656188539,
+    5064111876344212301,
+    1552137603199493303,
+    11829487445500862300,
+    8266047032941146441,
+    11653034650833521839,
+    2683946836461599542,
+    10077674761175908054,
+    628145183445744622,
+    17345696629371875942,
+    1338598602163380511,
+    4324936437754498857,
+    12231091809821854109,
+    4177958877755763096,
+    3415023200410981874,
+    17704612688194196613,
+    11265599291092040010,
+    542246281255858563,
+    1324247352755039718,
+    7473468527063656698,
+    6089674763757807458,
+    8471928515619008999,
+    296677923097343017,
+    6636724961078153616,
+    14283943310728994046,
+    10962062053560573311,
+    2922190838685059286,
+    11579222854950805660,
+    7937915515156671189,
+    18159851374032332454,
+    3476747663306387867,
+    15950398365721016067,
+    7889076653747292678,
+    13972171447443913428,
+    2112227997803713896,
+    17948107892209626095,
+    3443376322545993558,
+    6434413720800161246,
+    1759358258468286870,
+    6320698815111548980,
+    757316026062784490,
+    5585410514806032891,
+    2633744906210388987,
+    14868669127279670046,
+    16377056495258189615,
+    10081686042735015105,
+    349095394278790147,
+    6747377660838659172,
+    6050308083057644902,
+    6258600429377410750,
+    2065110002208655781,
+    14774939976876366006,
+    3206197507387233099,
+    3529162961118866225,
+    2891259299802844059,
+    6819040952828192600,
+    14959319404326447811,
+    16616428084864577283,
+    2940897931315949222,
+    8849105449110391161,
+    5211450075824496039,
+    10893875701729969783,
+    341427021725482220,
+    5646496591157194719,
+    12157252169614243489,
+    15451663740189449132,
+    2509104876528168224,
+    10414167829068684682,
+    14277618426471136942,
+    15058299691735180526,
+    3119263508226414252,
+    17676713230170507593,
+    3300293926426281176,
+    11637541326391628943,
+    2270469637984438811,
+    6248785249682675351,
+    11264762192804038018,
+    9789488188714976958,
+    2721300201499365989,
+    5289764717223902005,
+    2682884599756184598,
+    591922449177177646,
+    1307755822708621719,
+    79638017234990925,
+    419029535725286408,
+    17928816888104662878,
+    1007654218175629392,
+    4540316908814435483,
+    10556091853815693867,
+    2965409547017281268,
+    62455824579220231,
+    11545675313739472975,
+    3891234377271537340,
+    540864685180907381,
+    2070278552128564291,
+    14913321590890571818,
+    8541924268693393465,
+    14592355120813213412,
+    314410690966390959,
+    6187703534056071264,
+    9985717696725748813,
+    2785330273676213868,
+    957898183530581986,
+    18346703847894698748,
+    1090726841703361125,
+    13682913126436469993,
+    2458465928604016165,
+    10866618401387977494,
+    11541417705207161881,
+    7592977376833001229,
+    1613760463698522438,
+    241820063645703539,
+    14553474112399975470,
+    9659236811419729946,
+    2022333669962799068,
+    8606659319512130311,
+    6802180309383027044,
+    2213024631640942189,
+    274981384479499679,
+    4395229354572287516,
+    12986176922048555366,
+    1922050424163854967,
+    916658816437065424,
+    11331596344482222484,
+    6540397161635139165,
+    13636209632266051417,
+    1068160963740342195,
+    16299270741264655235,
+    4702345392480955698,
+    4250472393350341035,
+    823147010186056919,
+    11183691959521922102,
+    10877310715302261758,
+    13534317824754461944,
+    3346570943220694604,
+    10256615821694231370,
+    5997647255830540255,
+    1049837628745655783,
+    2596691977692849388,
+    195220011471393674,
+    2718636781382961045,
+    15184758148910314497,
+    3232852186083292712,
+    1676763545606239200,
+    11643397417652139672,
+    977302567223810057,
+    3264889500139914513,
+    17122654915620139233,
+    3674875237222178237,
+    12619140317959502269,
+    2250899276068156606,
+    7655219378752226649,
+    9240830606239404982,
+    13815332292415776737,
+    1597089750944564635,
+    11443304948074512769,
+    8056751313198999081,
+    11963693723161089793,
+    2097600142295562245,
+    16606196603268042187,
+    6696628381309509471,
+    7272383678809283576,
+    405748727340922481,
+    1249180650479586838,
+    3098628201275926732,
+    6226126727232047167,
+    2297582520259120084,
+    10983370619046871272,
+    14721838495949162510,
+    9847463721829321240,
+    839068363555080140,
+    13118041766589851911,
+    13869306362535838948,
+    16968057352185542919,
+    887991062959811144,
+    15551058341488522414,
+    1103206706699300550,
+    5591879411523851846,
+    1235341493377156107,
+    7168221893558724456,
+    14217179667752339449,
+    17851056957085569384,
+    2860345370894382818,
+    14846302095611585735,
+    3855231970666351964,
+    9254121748897403524,
+    1957370147986193348,
+    10719554184622010619,
+    97987270549239407,
+    2888982601676719622,
+    3366096587954431364,
+    10033052138384700637,
+    17307068351305547857,
+    10171973034046352381,
+    2187857077375294266,
+    4405756827656805729,
+    14467778566598868232,
+    11803004592970267066,
+    2312714988342437521,
+    699171653796158511,
+    578477690789372336,
+    12774273632540966107,
+    3236101419092219858,
+    4569251649628590883,
+    15904985807340485809,
+    3331875924380208576,
+    3066213721082783252,
+    16088908117080270442,
+    322466764567347597,
+    1562355359178849205,
+    312755260477381507,
+    7789467732387665788,
+    1712449337416394263,
+    1749886928890783302,
+    2973867938453193485,
+    9960426327930634748,
+    5217485742865216916,
+    2779697259070359982,
+    1212129556052077097,
+    3028370833293130428,
+    4574608181414167096,
+    8732557978923712894,
+    1331175441171879258,
+    9429630671405551987,
+    3050749742311648627,
+    3935862551176619673,
+    2504112245421246813,
+    1051711959075732859,
+    10759758883698190312,
+    15530014044311309263,
+    3411521179910085846,
+    5905247257030982476,
+    14096950810404869059,
+    7062876797159355364,
+    289568315746933206,
+    13069125870564224679,
+    7776856616018943738,
+    14153208009625108650,
+    1003594122000767265,
+    13864292550329254548,
+    10679455095368857441,
+    8668770313308969729,
+    2543905837527929174,
+    16486388885094200280,
+    17471106064658069297,
+    17168005980750069906,
+    2610044210152724450,
+    7511107973609431579,
+    5172571451648793428,
+    6515355507460934508,
+    368104017271598634,
+    14279363939606627794,
+    6290748307459320790,
+    7351838840313936327,
+    1086351711457504395,
+    9623476371529803337,
+    10934361913621517331,
+    6861306264830373925,
+    1735574576224661973,
+    12880119403361407384,
+    15059329777603934828,
+    10213667201386082240,
+    2450243973312829619,
+    6563993113258661329,
+    10438046059169868922,
+    8699970060606391255,
+    3174295804034973056,
+    472970632995034716,
+    503505021601926442,
+    648850518622751566,
+    1480067710956734992,
+    11766900033899935683,
+    8442741409511030247,
+    4113233033548621908,
+    194776508764820120,
+    6021236841882040078,
+    18293957350896303483,
+    4018485837586866433,
+    155091067601279124,
+    12739351640568128167,
+    13107833994116328325,
+    3564068626819637365,
+    2942303544420065301,
+    16138085650688030819,
+    9177205375712485728,
+    11624465457004034642,
+    1353868000497946522,
+    12120815047253856633,
+    1177221598463201124,
+    17352830864102834627,
+    2507797190422596926,
+    14538797367638814886,
+    9514021807708066097,
+    14074681361028618485,
+    752856404593817004,
+    4145661415515714829,
+    5890298442396881777,
+    14750148570009810846,
+    3316988982643735271,
+    7809549772401047246,
+    7241131356640701514,
+    16356199158169378191,
+    102986017892721438,
+    12491524756900755138,
+    18236602831941368615,
+    4332540261187137671,
+    1147715814205941808,
+    15929933525036218975,
+    14252901738144492518,
+    14727700796987420478,
+    2923879609717232050,
+    3902836783023033988,
+    2801057951528202585,
+    6344062814187091846,
+    2000530120012079819,
+    2085943095537634850,
+    1115446464661492577,
+    9767180766616427527,
+    892380004982747700,
+    2626159384377776436,
+    13100646883656469866,
+    14871883880384421179,
+    1190367366986243325,
+    1313563058848386618,
+    2906475289786122469,
+    2161137572449426814,
+    3173893487314423584,
+    4190271619164756229,
+    5589099692953381279,
+    2459316584128698671,
+    720645966401250617,
+    13999957531226216186,
+    5285128409422497699,
+    13623074011291824679,
+    3463157047971202084,
+    16427093732584568795,
+    27423641620784016,
+    12681748608484561771,
+    1284944030783055930,
+    15841604649480364629,
+    8595007942032776121,
+    1863152042584145887,
+    1663669771527524942,
+    4715736840053642350,
+    6984091909272621344,
+    6294952166934626647,
+    343188033432154434,
+    15700789717485858675,
+    15133742464272501121,
+    11462120965981824137,
+    1295480689671398606,
+    12785596238820260286,
+    7937412984783177029,
+    10989440190298086149,
+    2598930548018076037,
+    13941234415154644783,
+    2195772156855179349,
+    7740564433192479279,
+    2049773493687875277,
+    15580310778247904553,
+    12165511303990451061,
+    6683634033280304332,
+    1707135533274171709,
+    9334195216458446261,
+    18133434316240523376,
+    4058061432952035785,
+    1464800247759442838,
+    6890100404868528950,
+    12081024967960833234,
+    9765097179335960236,
+    1096999768691255227,
+    12520875604288331532,
+    688634280155823063,
+    1854050430498579438,
+    2923375733993251826,
+    14598225165125035423,
+    17345635098717361535,
+    11292206990241764329,
+    1040671777240267195,
+    17407786721948691356,
+    12308949567846269611,
+    4889436319677221029,
+    1106152169491859471,
+    11884033056846077840,
+    17636871785434772891,
+    157633572439778,
+    3412210000346636492,
+    9449234362896941299,
+    14516347630728728273,
+    12552263763815023979,
+    3133360369016349372,
+    8730674298865216962,
+    12342494469613431138,
+    3917587528366112789,
+    2642820408096465487,
+    13532610980656454783,
+    11736017825789158460,
+    8895353758533196595,
+    3300933934370954879,
+    18206535738141079039,
+    2039470419155587934,
+    5263382753808032590,
+    2122193227475837543,
+    689512714622535166,
+    3956822792898552152,
+    9097998430127108625,
+    1412208834380817473,
+    8122962486587484995,
+    5736009591080526274,
+    12795143362253383204,
+    2447100004901436761,
+    9583931652525053087,
+    1389176736048909960,
+    4861264103846071363,
+    1905722435797937600,
+    1955769539513112270,
+    94675031286780613,
+    2443113240696121402,
+    3050498826144388905,
+    13471710818309297918,
+    3037653111428353482,
+    15697175795321619457,
+    666755193905182677,
+    7101979190873935722,
+    2001454062373875056,
+    10526855859700420140,
+    457421993705139880,
+    12513242880489535279,
+    9381436229387124042,
+    8289012852007092758,
+    3157345387627831103,
+    12070200253923224915,
+    8996906707688168692,
+    7609638867346646429,
+    2674443995233990026,
+    13475299291749398249,
+    11477192846553474114,
+    6792136297430807293,
+    1486805595411721624,
+    3726097082767134439,
+    10867164831133015052,
+    9559340879510206237,
+    452905788334149034,
+    16314823020283445415,
+    6896264647142739800,
+    11425345407368581562,
+    2643547836743939248,
+    8850618891630999165,
+    15202132581795429330,
+    3102696235912163278,
+    2730542554243274662,
+    5382018303113408038,
+    14233257249178186263,
+    18397871414767360516,
+    2646495637353746063,
+    13178962325975853071,
+    10868066514632616546,
+    14571845127467304370,
+    3141597128559414800,
+    663808298496920513,
+    14099176651914128572,
+    1338503980668609645,
+    431475859382367521,
+    11244735384686897754,
+    6146489304434290691,
+    15038620876877016488,
+    2303980608873789620,
+    9967665973251915893,
+    11658157334281793878,
+    3320834030937397106,
+    1186003137786080398,
+    17271022990615702804,
+    15851398903158792609,
+    13583826709601301426,
+    3122252255416748342,
+    14097963584638257158,
+    17751026216774414899,
+    14588003110001032873,
+    2437108205511571861,
+    11727187990501709719,
+    2692804144207960973,
+    12492767561993493091,
+    1594578913603253739,
+    4548761119206802855,
+    17036554459548797221,
+    13000789704653229086,
+    3016174004092726777,
+    11340780298798950234,
+    2168208318316304149,
+    3670985928642664273,
+    1705354847476616307,
+    16726732989329674725,
+    8959150784992886818,
+    12712183802086604448,
+    2659235236233837604,
+    4361863682833271468,
+    242349686137098672,
+    3336196880494244420,
+    2592747049194343014,
+    15613679122442944392,
+    9674648059077759804,
+    7050820140129352637,
+    2415393525529008673,
+    13784686295820529987,
+    13254175289442643460,
+    411300350979903664,
+    1478629987370994590,
+    11259322121633005988,
+    2214737356726509342,
+    16975108851158849180,
+    1583450225435511520,
+    5330570475120324476,
+    15581280146178188010,
+    8968886354740385435,
+    3374142566011646622,
+    14665728193451573939,
+    13266481369386197259,
+    1161879348345490617,
+    336430079820023262,
+    2015051235339728087,
+    4342448700635572328,
+    7196695375513164636,
+    445612929635829795,
+    80404733125959829,
+    9837283303833895963,
+    867897076275906345,
+    2442921906127329364,
+    7193220875670232170,
+    15123886978953854228,
+    13281129593996823362,
+    2878348146665873222,
+    17432913859242761329,
+    5368364313442531924,
+    17117792825968550793,
+    127524325901195157,
+    18365320814147061129,
+    1476753430900518811,
+    17421291913535480209,
+    3156448506784822431,
+    11715464023514283915,
+    14509500614812294554,
+    1915353303773852409,
+    769591694205412930,
+    8934644869343141602,
+    4856312645644397821,
+    8086088755128162612,
+    2761854404168524184,
+    8109368610785274825,
+    11169208714445985783,
+    7325140727217695363,
+    938659001652250165,
+    16976049342209830345,
+    18024277473949869162,
+    16241255610866829870,
+    1565421103484808073,
+    426425061350033218,
+    17522106491374737881,
+    10005794767518681894,
+    3086378198788967740,
+    11739767497737296318,
+    6717618087090108372,
+    16194330113395098199,
+    1054752717637221431,
+    2060963946622165407,
+    14608305452792104931,
+    4631429079578423377,
+    278411831621415066,
+    3123301021587666066,
+    2818084341568330031,
+    6464811549087037990,
+    713933937020217027,
+    13566175684499926686,
+    12469160030222180941,
+    6471616670178627654,
+    61636868061894621,
+];
+
+const DELTA: [u64; 2184] = [
+    18399278065188310774,
+    16743693871007447146,
+    9825185341575214470,
+    900661444547535207,
+    15494545570702223956,
+    1817664368731027097,
+    15796354002874466626,
+    1167395615095030562,
+    15999720426665891697,
+    12649509834180405352,
+    16556664893989695705,
+    2283452461930261741,
+    3988175088637333696,
+    13686179927966105012,
+    12189398808755230403,
+    179271746625899709,
+    18015526006713674781,
+    8066144986599907967,
+    5830714831611144977,
+    1425791874601806501,
+    14113736024174584830,
+    11521910408122277143,
+    1776942505621459817,
+    843618459741908071,
+    5958321104460582816,
+    11462832043803987571,
+    7406055559521208173,
+    1480051720354974589,
+    821564055520774962,
+    2877994413492562961,
+    9126287973362085795,
+    769554563869419279,
+    16210638200757717143,
+    6091053993475649059,
+    15482975660598144102,
+    301308260429885991,
+    14296885095291919153,
+    3310655763325042477,
+    205229112216058948,
+    906234631536337007,
+    1469988169873806641,
+    3411644389713752011,
+    12738837830017937007,
+    2683654262719000523,
+    2071549106223447238,
+    1611873944026451109,
+    1862809625100406299,
+    679362506790908010,
+    15011767063723325173,
+    3994816369131599293,
+    2373638950741960065,
+    3374249148621913433,
+    9111021123194821039,
+    585790254818049996,
+    661593098291760770,
+    851399417386323055,
+    1483204720940033661,
+    4746182324953206721,
+    9763241691466325670,
+    348787094359326357,
+    2754271043170930064,
+    4253033500755944681,
+    13976545124688108278,
+    3011488177766524276,
+    7149366557323926665,
+    9181002461738290015,
+    7781517566669021900,
+    3021290209000052265,
+    4346033786438327788,
+    251350180399974276,
+    17095590355707823813,
+    250144050145498999,
+    17177072496373366623,
+    14940405976183115281,
+    1497317086622985407,
+    2518305838621720309,
+    16029683488700669802,
+    2377339297564567565,
+    7793218441397152001,
+    221116628733093178,
+    13331081821822728656,
+    3029437079939893708,
+    12249969284053891688,
+    3024390225468085279,
+    17309976783672066117,
+    2014880988580360265,
+    13908680082889252497,
+    664700705538597132,
+    194586316764599216,
+    8848329036325965349,
+    4454588274039782234,
+    464083321083643909,
+    2156928582041398106,
+    18290941264782470594,
+    1609262948767715205,
+    86369904613660322,
+    7413152153175203539,
+    16132375611122229773,
+    15484100420189390493,
+    570537151352998329,
+    8637845980838287469,
+    12289539752401052293,
+    17135429289513055173,
+    1606196244799544966,
+    12136774590812997613,
+    17768118634031865004,
+    12137065006239199494,
+    2246902248818950326,
+    13161452494412321259,
+    14411660483170499308,
+    15013086456110479952,
+    1353911824834392935,
+    4543994460194195043,
+    11137183887265450909,
+    12183210704728695985,
+    806319792911407914,
+    13695374692910676290,
+    3056447327987561202,
+    2619235433315495035,
+    916427511219489595,
+    7733494924960869316,
+    174005161511020436,
+    16503611616199473729,
+    2719356575753950612,
+    10956807978019474157,
+    16265141960883534268,
+    7345318058644942618,
+    1439180755012140458,
+    6256259391756101900,
+    5826288659735381980,
+    8368061123298983862,
+    2289998208164117947,
+    10140840857370246614,
+    14554369862133985774,
+    15999578851426669365,
+    1234713951022877794,
+    14760321736792852861,
+    9687464548345899661,
+    17957152244377695062,
+    1068824535578354368,
+    1297925555643082727,
+    254924189038704626,
+    16228736481933840043,
+    2126821239958297085,
+    14518285319968539408,
+    16153642468637280299,
+    6366304479195596648,
+    2706540588663536969,
+    9634248457168007522,
+    9441370314390568617,
+    15331551385173798100,
+    2944713082390204563,
+    15961798106594312333,
+    16017016992194907409,
+    4668476936145474336,
+    1305449791139517199,
+    10016237144350117124,
+    15357112871392830482,
+    13340907994101336113,
+    2657153918727510085,
+    5919051991260932565,
+    7733022767311175255,
+    4768335327862548727,
+    1217155475687508791,
+    15560452587037316717,
+    5624709172530427655,
+    16298174687658570811,
+    1708658345600236146,
+    13568956132400754419,
+    11480168406667345491,
+    16453512703577633760,
+    3141178111741966733,
+    18012581060860918843,
+    6601751615341161986,
+    3528939832378901138,
+    582262463458292437,
+    17995175673524682519,
+    16708576760964537174,
+    10577985690481184399,
+    1032332707059793640,
+    1439785839398243997,
+    18170861838571579914,
+    16099532788186249369,
+    961684278032748640,
+    5860826027026597650,
+    15143251714645264568,
+    651518876134729851,
+    2648415542506423315,
+    14615074950328984824,
+    9066515447892714024,
+    9448899128136747591,
+    126412174714776905,
+    13957353888198761289,
+    11321562840525140559,
+    3594544958534967397,
+    3031308438994901954,
+    4458488421720336227,
+    13446943088128028187,
+    16032739240623953292,
+    1513011869437491714,
+    18119092377264520471,
+    13151285036419432386,
+    2808510885015938165,
+    2892374943548635905,
+    13938658648331139597,
+    16365765242712824823,
+    13616846730665100215,
+    502948372666402800,
+    2338723894423897093,
+    10428856550685445567,
+    17482591738813467220,
+    720788140176470914,
+    8762918369844510577,
+    7462756565726494003,
+    1179327109037682049,
+    3143094180297296709,
+    11918515876669628913,
+    1910001110149840902,
+    18192696497846425187,
+    2454646603416147123,
+    14232622707480632342,
+    16686155795674517315,
+    281516093603532620,
+    3455395096886552599,
+    17851682690299446385,
+    5086888981282823024,
+    6239407469793721336,
+    1634808918863813337,
+    4082933908547691415,
+    15394462723173503362,
+    4496007838706851342,
+    589726420595216844,
+    12247434840777311,
+    17903149568532694443,
+    18069157827300093766,
+    672984374760423520,
+    4722516337182582976,
+    17556435889550113511,
+    4446859765618402806,
+    1918280668929883075,
+    3380715992198058424,
+    18399528734543296575,
+    7176907935689777354,
+    1040489870224194134,
+    15105494886797380517,
+    4858396436797607826,
+    17458978885990050194,
+    2985874575733763178,
+    63858033263060528,
+    4225793126511571361,
+    4868940656117124887,
+    1445380755261429181,
+    10706642280386447185,
+    1498966090600437055,
+    2217605155028269822,
+    644696626687596805,
+    13597235068831976899,
+    13394956939544782367,
+    6561222941014607878,
+    1057813491204299823,
+    9921378300718304376,
+    15370022997133447795,
+    14843454278654799594,
+    1887512410951712733,
+    14974663885263176326,
+    14841140507851759758,
+    17474026450125405186,
+    591491221237435002,
+    5327315149845603040,
+    1815717815397913955,
+    3131845670868134871,
+    792840833019330937,
+    10889848436093117295,
+    1781340589610661136,
+    4111223111951561350,
+    2631479592730642157,
+    18428996270346636011,
+    6270760831204538114,
+    14727428890190532726,
+    1493394158056186588,
+    17554636832774686146,
+    6916708015882307049,
+    6107497898914506887,
+    2022440017617848238,
+    8431010505732379628,
+    1643085848821498486,
+    1624255437586161097,
+    1725193079461915207,
+    6282531457154007532,
+    14594099639188671886,
+    7753864847845388525,
+    132926273399541793,
+    15890246546070891938,
+    10448589335129313012,
+    1959929374438798072,
+    1457317414268946391,
+    7602044533774298484,
+    13781864057046435240,
+    15759096514666700094,
+    1415961060971370695,
+    14968321763700670670,
+    1231501170224505984,
+    1535546469429163321,
+    2039594255393649154,
+    5209480814977194447,
+    15230473901451656664,
+    16265712372214210286,
+    1398639781502568889,
+    18339455094681365506,
+    3133508433841253118,
+    8459136295422932070,
+    2855803690176643407,
+    3542560697249859889,
+    2276998618202766402,
+    13982373517838362543,
+    2233277914463737276,
+    16422550614698269924,
+    3817696998095396425,
+    10090452526585848316,
+    980701788485209818,
+    13692529273425867475,
+    11290503725304660291,
+    7584570181232924199,
+    1020515409713779746,
+    12403016299430433698,
+    2572659540924623429,
+    15429771712704435280,
+    2602311991681495953,
+    2083597854239521038,
+    11559209716146371750,
+    9567265182127687160,
+    3078052887136958164,
+    8549760453950989261,
+    4509553297477172264,
+    7296565256438358890,
+    2590636301991427957,
+    1035521301948983338,
+    16983026934070881844,
+    5739864400752026041,
+    2534612489490920436,
+    11571512232581264452,
+    6306359282665241632,
+    10095589824366780916,
+    3091318304525246546,
+    17489693376787981392,
+    10363280598420438350,
+    16438174413012078679,
+    1467672095581702873,
+    9295685896782476491,
+    1975183700409565852,
+    1764063388430635649,
+    386601158704121682,
+    2626803464886601562,
+    672762321097861364,
+    14660640008588336689,
+    1132946296450943661,
+    17965601673199570520,
+    15335991247399165629,
+    17936887164840245942,
+    3069909305271018824,
+    5598379492170234844,
+    3237213702756894147,
+    10250591862800760226,
+    1664863264144454105,
+    15818129465519705112,
+    705893291113385338,
+    1174536694801753497,
+    3221662703610742675,
+    139915142529435699,
+    10135811244614880815,
+    831377329336354481,
+    1523636180522521975,
+    7124587533633741511,
+    13332475606805107834,
+    2533028724370269260,
+    3380625013004271544,
+    9930657872436620439,
+    11482233187370867252,
+    13311212593958696701,
+    239110562110218257,
+    13786844422197543636,
+    11187889268078741118,
+    7164058210350337306,
+    1495330018391813054,
+    16093489417419137681,
+    14465547351598539918,
+    991222911750296311,
+    1947538879038836138,
+    13633506151821652425,
+    369669253423919229,
+    1189621357367175368,
+    1614903857122069794,
+    5876383417913507304,
+    5662229476579363887,
+    13848785279942896537,
+    493230658690270254,
+    12447063595868444120,
+    11910339587998813456,
+    11552750699339337491,
+    594652796913013428,
+    787435772864476214,
+    17644660801059960660,
+    9345338270272955418,
+    1329103419878361880,
+    4084098554827140082,
+    14146227351186538417,
+    11900804288096993950,
+    521800995914315332,
+    4458121420660194580,
+    5535205154938813164,
+    4845421639430046466,
+    2230796363320452502,
+    14017186913425216290,
+    10316768065119210455,
+    956516176695456891,
+    282857444208907250,
+    2584099584826687261,
+    1101352607337047828,
+    15938730395198719996,
+    1507418705149657164,
+    3006591105866565019,
+    7116609631812667866,
+    5748977986618880516,
+    3424670274326965137,
+    18362617914711969197,
+    15286251407316585735,
+    1042917363099625005,
+    572431344096089996,
+    5489754340308965210,
+    4839760803684507248,
+    10126461398322389234,
+    2565417904891367079,
+    12943894447831963602,
+    6212648695494960084,
+    6194935410118072154,
+    2640751198211167731,
+    6183224786837176322,
+    14902279496295379092,
+    4481888283933649900,
+    1451922279018808537,
+    4675883425609138242,
+    4860207686817050162,
+    17878580039801491176,
+    2939958106413356003,
+    13868691679954947450,
+    14152278053415793418,
+    5857162109268359342,
+    1902694534008155740,
+    9938778311512457712,
+    8199508467899998691,
+    15464390097597156999,
+    3481616206863757032,
+    13512429191856516156,
+    17296850170041127922,
+    717764179492152400,
+    609157160235831855,
+    5793367604517366389,
+    9893479886085741433,
+    16273749570327019515,
+    3247051457002849306,
+    16423866594461926704,
+    17544444251913379641,
+    9816454598340866836,
+    1312099961906867712,
+    14985604467721073478,
+    138871210951589774,
+    15060012849970168543,
+    2685622035957253492,
+    14761102131496685460,
+    6235155563837019203,
+    13214381630287836072,
+    3223546709588070926,
+    2250922433411492467,
+    8554206331657519619,
+    8855600902504190601,
+    3336035157157140122,
+    5976652303276880500,
+    13917503154140477568,
+    13325355572397529436,
+    1893677090448461351,
+    11447543746332753858,
+    2756746268056589420,
+    6132387484068629107,
+    2138790854393119386,
+    12970123281485786931,
+    15265093422156051408,
+    7302087297737431210,
+    3329431456960050961,
+    4307427966007867238,
+    4449787782985216900,
+    8043974032435433997,
+    2750175823366664880,
+    16579579033238574769,
+    1990540562699099175,
+    16128616414005600963,
+    551041656502664569,
+    8027373808906054831,
+    15382959217570484543,
+    7355030675432015006,
+    756228299244057481,
+    13683388786677501008,
+    9917591550701401453,
+    4149219293029025563,
+    3031565566675267237,
+    10498316339038002120,
+    9695917424470847228,
+    1936060238868622745,
+    267524600885235745,
+    10188937717492377422,
+    609597518516830241,
+    10457219419169315135,
+    1101564198366485135,
+    6456086746494057361,
+    1988694632701375193,
+    11300518646202798886,
+    2982623303370443300,
+    17691288322366415473,
+    13224953321228404266,
+    12033867248739035554,
+    3217261631563536990,
+    13045709695622212986,
+    1996080232650303357,
+    8824019556667730604,
+    2118167448899124066,
+    2043687382069855871,
+    3510019948521316823,
+    6944521503648871190,
+    2505952386806614012,
+    11004929189156806969,
+    3337519299334530831,
+    8452237450166215416,
+    790613446464886637,
+    11536432545612179295,
+    12669940629545421034,
+    4629803230996233874,
+    1773178313054781849,
+    3994233187576005305,
+    6649593191301247187,
+    14282066865057534046,
+    825473106785620366,
+    8567916560460609477,
+    13893512553967674108,
+    5677485411797363256,
+    1498052189599596628,
+    2226676554785151005,
+    9211578388309569775,
+    424993698474253623,
+    2568651117842003631,
+    14413677231780157675,
+    6225159792398053207,
+    707842272197762577,
+    3339417217804649472,
+    18212550177236880035,
+    17155233706915792001,
+    11624722965162151673,
+    2613348501840207386,
+    868274930764971769,
+    14228483955143500793,
+    15894040860941452783,
+    1573507078714493919,
+    7465240118192583403,
+    15229334055266480560,
+    8755124479978596428,
+    1827706069299938400,
+    18333960298533909515,
+    3237990600210275225,
+    14808839612205733489,
+    2128877418887034117,
+    14638401960250182622,
+    3818802792168326534,
+    4317238093432650668,
+    1647847326206973515,
+    3113179109049801740,
+    13227674472012689466,
+    5850394626411679949,
+    2442843976295525332,
+    13223280657908955677,
+    17998871253541472666,
+    17254212727439154141,
+    1271390315847486212,
+    7275590876120382227,
+    11892520241908972623,
+    3384048880771911229,
+    630046393044529428,
+    747800171609974710,
+    8621562998691615003,
+    752879244844294834,
+    1748581044943654258,
+    7478606209654878615,
+    17854820571293753941,
+    1650579826290406338,
+    2619429957282245426,
+    17789857084478070352,
+    323516886104696339,
+    12779964435711621670,
+    2919957004541956084,
+    6120550575667267328,
+    16065032651901983973,
+    7121712953051596764,
+    2173096853329736490,
+    9557002629702344709,
+    5007242286619934987,
+    10050707057626530605,
+    3056839147577961982,
+    14923202028836709774,
+    8203665792772793141,
+    7693779495488790016,
+    1394933323748205526,
+    7120890180693740887,
+    7257354425486260254,
+    5235248825340378685,
+    2410608575805613915,
+    17771799829901760054,
+    8704100823054139436,
+    6318023051282406546,
+    1830336691667787536,
+    13327879367444741427,
+    17440538378217255067,
+    10401474450040693921,
+    2123827996846779406,
+    12279709557614117731,
+    13825709676485133520,
+    9537806893037499312,
+    2747958781188481257,
+    13768684902314379963,
+    119556544208173313,
+    1748233271723240655,
+    3098017333831023350,
+    8617585991838517181,
+    13581764157300337140,
+    3281986587547074365,
+    1712586401489060764,
+    4718793972701690218,
+    13936004952457323404,
+    11259418226816514470,
+    3129362363666505093,
+    1587653556723151785,
+    14116254758622848555,
+    12424755850823136090,
+    1226414837517360541,
+    6143395091505996201,
+    18379311363970421120,
+    4869044750108858080,
+    3201246037609512295,
+    12645061707493011879,
+    12518882755539779704,
+    17300253703601119012,
+    2824896832017686332,
+    18199455639547176473,
+    10470784988696720173,
+    13879779929505726767,
+    266061795814070405,
+    13188530486118050574,
+    16983890278738563532,
+    10320200921565498234,
+    2249311709289845896,
+    12828015779434256210,
+    10248257681717455911,
+    14952278852035498881,
+    2776421908207859992,
+    15229509410367413432,
+    809649866168923078,
+    8928974319120783426,
+    616979612012206973,
+    3099057685659725456,
+    12843529614619202214,
+    1643606403633554924,
+    331426183275649408,
+    18249240582761789744,
+    8243204333290338587,
+    10564844592220583371,
+    58472838278919077,
+    4471050727358371956,
+    12772604731912037077,
+    10352047707544312890,
+    1958501292694654564,
+    17332240119463288383,
+    10086343036033044978,
+    18141937427188303085,
+    2382327151915613959,
+    11471938756426079997,
+    15285290247412117900,
+    3769540093424081360,
+    162677884915057491,
+    4143847918516395533,
+    6834534627893425368,
+    7184613189991311692,
+    310694178603911411,
+    15645485871888498211,
+    1975672845909911125,
+    14814049988330520644,
+    1199977914267644146,
+    18123845957954326454,
+    8434087047453050047,
+    5482637573084889259,
+    2574681392183144340,
+    9311316400839065577,
+    4036095454730255642,
+    15587445976563315044,
+    1069304479620542457,
+    13336929311616982593,
+    16425490216724108900,
+    14161128298244454146,
+    377137141943738654,
+    15756280021997871095,
+    4057980891609430287,
+    1880285547245615199,
+    2913243697270681735,
+    11244031194764815460,
+    71884720664620006,
+    15441996204860238100,
+    2196191962239059521,
+    7709357863542111074,
+    5471726319585134185,
+    13166419203895689196,
+    3190264854097768298,
+    16746144988049429287,
+    13112129900070822296,
+    17173024309372090591,
+    2701863725195468065,
+    13123767217384793140,
+    8291224045713902188,
+    5638716769884808306,
+    1494605357069056295,
+    102056905684905654,
+    17001312170100145496,
+    6898435867195230232,
+    1275776355277614726,
+    9839614760126885000,
+    3067245419818650747,
+    15627273918365921923,
+    1951869456353457408,
+    11961310396516200632,
+    15603927001757246395,
+    7063207303633584432,
+    3344008299995399869,
+    10616491575770858182,
+    3997123750683990833,
+    11462301336389705327,
+    3371035502462744758,
+    16498742338716099307,
+    13264849964219283677,
+    15605991584902280372,
+    2580924497466249316,
+    4989583826024999948,
+    4162845306614461203,
+    14934981653368996347,
+    938563323960372341,
+    10350750723613370574,
+    9081275141130590032,
+    4870796847132259033,
+    609846765030914049,
+    2707026196228467977,
+    9821741493756442901,
+    2734132574990921224,
+    2370976478612489123,
+    13526589728467894482,
+    2615277986039632165,
+    5778193259702897396,
+    1993450616620820909,
+    8539268518708278478,
+    7662707652209552613,
+    13966253564215426028,
+    2691491203025206387,
+    12902174873769722305,
+    9097849131621459622,
+    1875995486387648360,
+    3434911541264009223,
+    4090470188709537907,
+    8585438959289793100,
+    16783773489536474599,
+    1853664708379788122,
+    6702100050777623636,
+    2669087360188321259,
+    9392392727905672632,
+    2109589706860585640,
+    1281337772841142296,
+    17023349047215513159,
+    8536566607541203251,
+    782970204953205824,
+    15661275558485467771,
+    1127072094050496395,
+    2855096013440146181,
+    1208619847462246503,
+    4608501042935938062,
+    8641537595415163642,
+    4921044017274400850,
+    2882861676580541689,
+    7801404202434196268,
+    15376533584542846739,
+    18016154455547358757,
+    1483671050749221417,
+    5460408218505392527,
+    16181825325417508843,
+    278601487919926337,
+    983392053453921474,
+    12577793304668437794,
+    10139657756940365080,
+    5513968773660191960,
+    1665061983226640375,
+    13662822609904854056,
+    8706864274185246628,
+    7023468670986060266,
+    689181166514872848,
+    14449594897906309213,
+    14733649263273098142,
+    11594956435830761822,
+    36940604578264232,
+    5561980561063189472,
+    1422048803200778215,
+    6092218665179456465,
+    2318500536274983410,
+    13072204713372951148,
+    5980722582273798582,
+    8208615857402284342,
+    1165540483888888231,
+    8985350594987980660,
+    12955429585677338546,
+    15553918285332021201,
+    977966150879972651,
+    3094695792740362369,
+    14864114414029382126,
+    13835528388988292341,
+    2545453480800422997,
+    15053175240731205143,
+    13382272775611095486,
+    14503336786765811300,
+    1157797878499862012,
+    17949396564432795515,
+    9146255006615903261,
+    12860408449626606245,
+    1923309250005725090,
+    5498475955092561054,
+    1317477721525499996,
+    10414015519047967883,
+    68422552153296398,
+    6540573735625669214,
+    7217681204777361852,
+    281291398369821906,
+    2619377419348492591,
+    15785544031348489576,
+    5813941366731205859,
+    820096126773080436,
+    376650148123885963,
+    2320685826611931208,
+    15082506636727411512,
+    12291860802685972118,
+    2098977159110686318,
+    17823666240079115080,
+    2377926194710899859,
+    16266511390798558462,
+    1252963486587143045,
+    4687131909626478805,
+    6984927221438928391,
+    14164273441059127954,
+    253683436722330679,
+    11236514153349165005,
+    13519717825047926303,
+    14931156025987950941,
+    1387363803473539651,
+    8717818811462062214,
+    5336328642050615356,
+    10348512214915270578,
+    36187085891647633,
+    1375862539983840506,
+    18147443035796841545,
+    3072885648383541097,
+    1148216515822272866,
+    5136335104980049469,
+    3449772964811388809,
+    17512430257510898579,
+    1140168710060700753,
+    11553487241929602330,
+    8430110453443478777,
+    12527470680063647552,
+    2443162954232741981,
+    10549699173411256500,
+    13130808887150136775,
+    12077668377495280802,
+    108823942897491518,
+    1725782980920371664,
+    15161558742645616133,
+    11181153751647962413,
+    973896646374307649,
+    10105325311668702672,
+    17977450034569455223,
+    13019879000275739933,
+    2376407508223077066,
+    7059596032961580055,
+    3554846612672227616,
+    16008807795219636207,
+    74771233876537426,
+    3108761391291762679,
+    16429738999721647278,
+    17205718984440332048,
+    445936815920434637,
+    6480118660058970543,
+    5228564818290121360,
+    4295858084846979635,
+    483268141563712179,
+    15467146918413196217,
+    11069590684752392344,
+    4009859278515915653,
+    526012763429614336,
+    9488156016962531658,
+    7012728030333759506,
+    15404261416621494645,
+    2627896007285929450,
+    15848640135304058375,
+    15936122253736294761,
+    2066342084590496939,
+    3224266435557206940,
+    16972161417057941916,
+    57882592928279789,
+    3789044009223831423,
+    363163313948135760,
+    2864183337191248497,
+    1297024579211204892,
+    7449680614319030446,
+    2534133286369932896,
+    3187338336692254379,
+    15633612940303216014,
+    12028943460523264124,
+    2765427943764010995,
+    12594208498464120578,
+    4214126721714669221,
+    8502430619117658318,
+    3389824672394843102,
+    7932338131261067882,
+    5177894386050882123,
+    17476080719756651619,
+    937093130137657689,
+    12549651284273102596,
+    5840297028488937430,
+    5496480708120090725,
+    2703545132738954190,
+    9536006543440806142,
+    2720371277874521161,
+    453193546497369398,
+    2511010954701375324,
+    3158513507393598528,
+    5188582215495116702,
+    8004671576328049244,
+    1045070829448852191,
+    1769929252844558081,
+    6799079754389727380,
+    1072104017351680092,
+    3053778529785906530,
+    3131167039567625833,
+    13657732878726834154,
+    678145949026471221,
+    185998869657214244,
+    14979681865598148121,
+    9600994963248716946,
+    5957082515507141769,
+    388553379712381249,
+    507804556688125788,
+    10962597042817488936,
+    1291091781088626968,
+    765725893083839301,
+    11751564637144328191,
+    9595193230534934034,
+    11051063680165376732,
+    1595455300770195813,
+    13085414230034931162,
+    14558972832147710284,
+    8682368691846504318,
+    495589664368562493,
+    10904644686583118140,
+    9878555409930536752,
+    13317198234774144091,
+    2160835648581911848,
+    5317308245350375219,
+    12537381562943251487,
+    14752224424319271190,
+    81793227051433644,
+    6896591183818737740,
+    2336342813105539287,
+    8735937768808735732,
+    1329815361176377443,
+    4139701745008168974,
+    11361129714011686617,
+    11159204351572562966,
+    205466075922198724,
+    17416715265233604859,
+    4094054807303999981,
+    4574000205340985795,
+    1288156621258203983,
+    18359182707568965087,
+    2495532263538937838,
+    14271500154613209021,
+    1605910561783059920,
+    11927770209396224110,
+    336461468589463299,
+    6830452454945907621,
+    1760782835404928859,
+    3866342000923275116,
+    16016859926191232464,
+    10812841832641313999,
+    1175353945172320339,
+    6717258037024307138,
+    8164720611136243321,
+    7137203334630061193,
+    1871681986289690977,
+    7298462284016374882,
+    1999511040721789024,
+    5317501368253524165,
+    1017980378781915533,
+    1427319485011169039,
+    17410181011344888300,
+    7358610533486262690,
+    2220107352361375969,
+    15808581286147446249,
+    13423012737826893268,
+    14921216876725863670,
+    304805148108994311,
+    4769914328560499811,
+    3227570895877942341,
+    13204560154061919854,
+    783163303505701364,
+    11068795079639735290,
+    7045758823428075889,
+    9881366513172693191,
+    1018977134316969925,
+    12916974868878551017,
+    17562151255018820459,
+    4409289863505105709,
+    1550030123381329741,
+    18073958102683328115,
+    13945341707943698651,
+    11228692675886761824,
+    2106749040466686912,
+    13945879410154300359,
+    11758065255177209756,
+    8263145991950987406,
+    1713488032929578788,
+    8156711281056910669,
+    16677390793973506229,
+    10410349527446866966,
+    2393098531120915717,
+    12651507644273289897,
+    15148091989969177018,
+    4253739121504908599,
+    3341724780018118511,
+    15356587823152394290,
+    8946871986642126804,
+    7952321199201256457,
+    2033061578688895215,
+    16427820069284462461,
+    12968369482509148976,
+    12094840718926949384,
+    2925455495592096511,
+    17301932273793998639,
+    4446600083608057512,
+    7645639019918800475,
+    2210312157397116912,
+    10287852751433807081,
+    9292011591337749086,
+    18291304680994181094,
+    2965967411907520903,
+    16825073175078997674,
+    17022466832505839049,
+    1624127422159863527,
+    1092389129686653694,
+    11650522299713861191,
+    1193111350049477775,
+    11111032005100591777,
+    2859288894715046932,
+    7636167541805731150,
+    13647005550650157605,
+    6912846168840643579,
+    1562883120954383281,
+    9664665503184394584,
+    1255887371995496185,
+    3966579883219697928,
+    1514809370769161581,
+    16645457810277056848,
+    17005046479315832,
+    12094393803153986314,
+    3207037659560758582,
+    3968748508759947883,
+    15778872547052636268,
+    12066510854687368122,
+    759204757110699767,
+    10198907415240105842,
+    10641122745322144501,
+    17584198589029714650,
+    3481516169350250266,
+    18313127100092938129,
+    2641682100018459690,
+    13396003923571561878,
+    1343697822437832756,
+    3504072511674332657,
+    18445626332822215783,
+    16393285375713360256,
+    2750012256291008293,
+    6088707051996197389,
+    7280855557787241542,
+    15888526369535907208,
+    2871075346074706761,
+    16463815931816805529,
+    9701661071268833670,
+    11478140438753960351,
+    1686367049880731531,
+    15870932115208203654,
+    4522842273878275069,
+    1618379481069237467,
+    283816019579930510,
+    10886253947666114496,
+    1292145753662068069,
+    11792734915839046106,
+    1009051686167720342,
+    7881375645500313832,
+    14184939265642844456,
+    4208262680154821951,
+    2455400304171035590,
+    13973444074178110034,
+    14808311713688918280,
+    7134654201653090749,
+    2227000320864011603,
+    906726659092181958,
+    369984417453868143,
+    10484610482366256809,
+    6392939159323650,
+    8191083412458735717,
+    13233837750844077026,
+    13425776267793910563,
+    2194017099092147470,
+    11599171973691625989,
+    3270099266729534945,
+    5766376395720806250,
+    1504703798113823206,
+    14927169890371926398,
+    3069029807307753775,
+    9277696382415424350,
+    2272946170669080120,
+    13664244033132423864,
+    11972223656901286270,
+    1499856741347639254,
+    793377979435107571,
+    12343271273529774085,
+    14503727584804307277,
+    17108518845613301198,
+    621172522673376746,
+    9774068801119019987,
+    11282395872771055493,
+    7844018960672535625,
+    3212776789743610094,
+    7068955388170840775,
+    11332500337353898738,
+    7549254185339281171,
+    1571665465011618523,
+    14955257231168044729,
+    12056514766244488554,
+    3516749980454922234,
+    1367136843255174742,
+    2704492526909619123,
+    9709581555139191536,
+    13534389257167569534,
+    697857407488935903,
+    5840483177718232355,
+    13942500324423134048,
+    610807999229394617,
+    1939479793322716125,
+    1017856139970397093,
+    11083453518432902900,
+    3175101188696120246,
+    2614683820127230599,
+    17729124261107641762,
+    15120700295540725237,
+    2287788332078496643,
+    2689488527612499758,
+    17938818618708370553,
+    17109867106747894602,
+    5486185110520205151,
+    381519683147168438,
+    15641117314690651313,
+    2107861502764098256,
+    9444607912660921137,
+    2667988935636925794,
+    4191871272291444436,
+    796041619598246620,
+    7548339818209456341,
+    906240214348104542,
+    16195388347176991533,
+    1293070394879177660,
+    1906797596685119382,
+    695974380667192495,
+    10780052445805699740,
+    7809852920052514251,
+    13583918761679804648,
+    1737677057427337492,
+    2391610895480297571,
+    17372073153526751934,
+    272720134984265028,
+    2014128126752483112,
+    3620870148005865880,
+    12432089091034447989,
+    8704238667323937974,
+    88447755297351557,
+    13333143150709578198,
+    14650742838956210019,
+    11535846711253883585,
+    1230416876909899302,
+    17535263000023012349,
+    8868299363144838019,
+    531016837535790132,
+    1444185564761933451,
+    8840519222521730899,
+    16251870320773012936,
+    14346055560625477354,
+    2604671012245776577,
+    7394053250136656598,
+    12359904257442119135,
+    2398782543343320217,
+    1503552182893478241,
+    18303680590614893214,
+    2643950761402412824,
+    9925285070041381698,
+    2918802813598143058,
+    305059868376098398,
+    17323568342001912840,
+    4782757278944489829,
+    947966729862266502,
+    13183997832814993648,
+    17769205042793666335,
+    2930238499265305328,
+    2995936762574904409,
+    3241544566273262219,
+    5024945272434293301,
+    1621429846257031182,
+    1778231711352860935,
+    661150822955264809,
+    5899318429342124006,
+    13386763924557591041,
+    1776598113806269056,
+    3372926658048723036,
+    17351332604156267541,
+    993561840712302590,
+    3269453534298263669,
+    7666645242605916445,
+    2056105832661011941,
+    2368714014119391450,
+    1331171436380663231,
+    16744886059794740207,
+    4608265216603288493,
+    10892621638562977592,
+    3343921688943031073,
+    16851623964660451624,
+    3414092422987670182,
+    1375001247306744986,
+    1629886733496645686,
+    12108651951928738435,
+    798662367874083937,
+    7417379971133280711,
+    2801354865584968959,
+    14646625052610666314,
+    2842435118779017683,
+    3845920636720758205,
+    3108445754683711021,
+    909326803549337595,
+    17195620935983058873,
+    2745517370909551916,
+    435778551848157846,
+    16415366306983032843,
+    3564536258100175404,
+    6915911893314695827,
+    2849423276771253413,
+    11703658823008243747,
+    17842788392484701519,
+    541271040720040252,
+    1465028263884557400,
+    14734713260969541542,
+    14802555717770714163,
+    13568120529844221453,
+    1671447757699840627,
+    9424602515824797542,
+    1555435097424488300,
+    13831966754834651066,
+    1167726698772652334,
+    4479544052289832767,
+    16226668930331789527,
+    12863822500830865087,
+    1019039959151268031,
+    9805254556071955563,
+    4498610106714611804,
+    6584858732350129014,
+    3183224707994936777,
+    9026103685549114172,
+    15420985489633226015,
+    2246525655135968164,
+    2247621546310945145,
+    3027963856217388889,
+    872124460190840989,
+    3124590168793406526,
+    80612773647632729,
+    3441855016154384901,
+    17504963955361777510,
+    6935792157724227587,
+    1075879866573718019,
+    997582836156514775,
+    1045614777317140,
+    2302006623078772044,
+    1865893418498662627,
+    11662220866089666365,
+    18195584295570098962,
+    7281153350389479536,
+    3427647117431418007,
+    3768776984596684488,
+    3010139857887159370,
+    6805141620354200075,
+    3463603117788211854,
+    15911371177759568126,
+    17400881130523759907,
+    11459268673528042401,
+    3052066547405428382,
+    5380573317088508833,
+    12286972318161733356,
+    13014178049281554764,
+    2045525958396615397,
+    7295985096696291374,
+    10821053948652968327,
+    11972659550806969756,
+    2572792994881453679,
+    16904391592150733154,
+    16209571661524448343,
+    17983319167179123218,
+    2854079708590170406,
+    13095073697108705827,
+    8367744833823371066,
+    12970142509820832819,
+    2632638264361745593,
+    8422499360579162623,
+    8440880265438962278,
+    10545526354338166864,
+    2413120064565866456,
+    8752738973402892856,
+    3434986639003906651,
+    2330975799833920943,
+    2535971855874898136,
+    118893947409447147,
+    11639966217528955973,
+    9034360023418264589,
+    2465246643533585686,
+    16036561903439916184,
+    17281758512220146442,
+    3537025008091415746,
+    1221308235230257818,
+    17748130517164255492,
+    7723295389013026894,
+    8606184255862001343,
+    899594903520299433,
+    11515012804492730480,
+    2560086755900396830,
+    5600590231734837005,
+    2168336280214818496,
+    2904126913746721024,
+    6805228221720601019,
+    10465724424645099164,
+    2041030944311603111,
+    3888092893157302820,
+    9513465036789176350,
+    16003608782180448193,
+    897564899735392440,
+    15651397099284153465,
+    15998165636024915566,
+    11946831918477543082,
+    850363018223623273,
+    737722804121496737,
+    3887594348797816198,
+    6543407254765344495,
+    151398652735939168,
+    2897148361333299762,
+    4670025990594646386,
+    5311297545063431608,
+    410437423668227991,
+    8881971544468927284,
+    6787812492024446305,
+    642533409763318900,
+    1269689421274436796,
+    11763364104665018704,
+    12252397340530728476,
+    12103270561289931697,
+    653014645936380824,
+    8697670634655574152,
+    2928204206317853108,
+    16174598781972422066,
+    2904068638013362422,
+    1265674233870808483,
+    3053633098716003633,
+    12039656566456549234,
+    1709344251954719778,
+    13516210587055213119,
+    4643896047097019002,
+    18246007249161714532,
+    1146213711389547772,
+    13150941208662604050,
+    7725425414265853850,
+    1265324098879625601,
+    1154321688785712143,
+    4842558035316435967,
+    4562493461579545152,
+    10594456518780702471,
+    1063422799870051148,
+    1967052079398644726,
+    12707182309601990024,
+    8111553482164728889,
+    2126073582893328150,
+    16175418369248928541,
+    5554067832116403260,
+    7570376092652629515,
+    936771395066125969,
+    14613121732654804481,
+    8926139332802610210,
+    4245163266650379250,
+    1132704238730767056,
+    15287064791523970568,
+    4162860964466154251,
+    9476874286403817262,
+    1858594571653185446,
+    17467378652000693834,
+    1902967241578585157,
+    5538509977060016801,
+    204680663042887348,
+    585306638284564824,
+    8765465391987974359,
+    12995358976319340655,
+    2223154645516055556,
+    12433537136722791229,
+    14349175453979513214,
+    16720284605184577057,
+    1226190327569673029,
+    2924945921313951144,
+    1414627797513978004,
+    2085835348531446708,
+    3024162643852664416,
+    16971305745319103833,
+    11897251138974365512,
+    1661426840565154750,
+    409444980559847326,
+    11602659474069107426,
+    10835778683882777389,
+    10244442794871949275,
+    2870028145766479616,
+    6565327401938370856,
+    11286255865703344361,
+    12330773413471429713,
+    3368339090357587921,
+    9540228276170753504,
+    14304657762775138041,
+    1371375343588780282,
+    948568799966311609,
+    4488261741654283946,
+    18218977516165304744,
+    5080878870194595311,
+    2001553982350470034,
+    11047496349383436224,
+    7788484576282952839,
+    4700209432056691003,
+    361688685365595899,
+    13274835193832447052,
+    8635965344345988926,
+    10021375539943931478,
+    649025839502204599,
+    2887745129629505020,
+    11759265168168360615,
+    10665950322952666336,
+    2271673800194463873,
+    2942326312872386558,
+    13114923951073050919,
+    12907509053903974346,
+    1695649697897075311,
+    15267179640672653831,
+    14822449460865917024,
+    12604260723713154662,
+    1589191811013454584,
+    4204820402537070660,
+    6117752053563129987,
+    2978802932304489389,
+    2140865595961177263,
+    12191771914837486877,
+    1754075625943187793,
+    11266553776358574350,
+    2892742710109050026,
+    4317650596775598703,
+    7455867961498562761,
+    9319904203517904050,
+    2564581050624096495,
+    15543787095788163556,
+    8210419507646047459,
+    14461471092421583416,
+    2788698926594423442,
+    9864691766231495245,
+    15048860813057156024,
+    10643725318459369987,
+    1286030479450833064,
+    2187265844308515419,
+    11896143140598135801,
+    9125630874767756644,
+    1974922383314253340,
+    4055953387200961741,
+    2545101270067358438,
+    11833356763334413365,
+    1429069372479021466,
+    5423919850827911712,
+    17330201766926008120,
+    1827095068483412574,
+    810873664638699369,
+    18048538755800379127,
+    10857090216746920526,
+    15847115058171186774,
+    312791674934109999,
+    1555538274636920911,
+    6331018750238393561,
+    15702690926911711175,
+    1931405497386646602,
+    16075638978118832922,
+    5657314741050322909,
+    5719292145018011678,
+    1115419990236122630,
+    13293822335752066965,
+    5658979513589976269,
+    2133076541285948244,
+    3352134754844581632,
+    3807387535690905518,
+    16530725135631609911,
+    4163846982173298841,
+    2992546161811941075,
+    6827595169722570186,
+    3944561586265440520,
+    7347817921703605703,
+    3246667340456276907,
+    8921413609072205074,
+    4414489632729126669,
+    16743199731853049174,
+    2873348254776128201,
+    3562415278037179719,
+    3306095794350716650,
+    7468272863324287013,
+    490683783070543158,
+    3664053964675913460,
+    5923060135402384460,
+    6882056147776943459,
+    1784818685074627801,
+    6080167029180222198,
+    13074416943324799132,
+    16458899943898868645,
+    398047270898698064,
+    3702929987529707570,
+    14576071823090880570,
+    12935647502153005994,
+    1492178223292714387,
+    7998654427581594219,
+    18347895566136543875,
+    3491898434303617050,
+    2940925399639417524,
+    14042889297836928428,
+    16587628158802138188,
+    10643055771504349540,
+    515673973025727050,
+    16935992959778431565,
+    13662830468825314474,
+    17667447913817542920,
+    356425139932192356,
+    6568285885228214653,
+    7689081655522399253,
+    924146008118679702,
+    533448248646571105,
+    431841076835220346,
+    2384690397655372488,
+    15748078718431126869,
+    129538316102456202,
+    7293965692755819217,
+    9271354713053552234,
+    6206713288639894197,
+    1726059008689225872,
+    13269331866331274697,
+    637975685006146826,
+    16474423243208479270,
+    3297582994417204203,
+    8772868864118497943,
+    17169348159350306842,
+    13759516252194530927,
+    2279265355853191433,
+    4810095932207882686,
+    14598183933040888196,
+    2690200504497833234,
+    252386739309613548,
+    13210530317872800577,
+    8755926343981007031,
+    10319841494607954973,
+    2038815664315202690,
+    5115871646403093557,
+    15106502967498143189,
+    14040114667633857764,
+    2364704371994351317,
+    17499762415565242782,
+    15032997314957637082,
+    14326140544048922940,
+    1070058005172709273,
+    10121895554806934104,
+    137476118717483662,
+    968057979897969949,
+    1125178686628291112,
+    7746047604419749537,
+    2645598525535455222,
+    15656595787812975296,
+    2140803410018819871,
+    8991166934986518186,
+    399934981647311652,
+    1240741440885416818,
+    83950171114058237,
+    2811481084523773535,
+    16350853119428209250,
+    13534038902534657682,
+    573146285114553186,
+    11522420615396508604,
+    5951916307671737900,
+    3182428490852911087,
+    3405595320552482542,
+    13944346996716415240,
+    3862232590662769865,
+    1524562749998444269,
+    1343938962131794810,
+    13600981891163985982,
+    5827643615909527908,
+    12031002811001418455,
+    1581663502712089173,
+    3113506794342434389,
+    7372193796436141512,
+    1520876902258589037,
+    519234149799940453,
+    12522716450038615745,
+    7082023591303879594,
+    15409149072182084454,
+    2034271346271249845,
+    12110005077426021216,
+    2283707677201172528,
+    414993135794091311,
+    2277721736890600854,
+    13476288713074862572,
+    12578421054914669291,
+    2810715351795882978,
+    2515639655788709508,
+    7799296421573917490,
+    6566059091208606660,
+    17165965345450578913,
+    1444835295597945685,
+    8772845478393521674,
+    14423130729601612465,
+    17029370627858251769,
+    874153176590369181,
+    7577460884038302140,
+    12352115535059124017,
+    9418612989488275166,
+    873727737357040354,
+    5062842825256632105,
+    9296845347963847902,
+    3044425442233024784,
+    828538184963380193,
+    6543018538550471028,
+    6270663671908046015,
+    5285235204983370247,
+    2723415142283206208,
+    1583031452557802809,
+    16630194469208278847,
+    972441392046504020,
+    1523431535544208895,
+    15332875529427942266,
+    11334435499570732239,
+    5883054980804984162,
+    371315264504648612,
+    3100877981014402391,
+    17712733467264488688,
+    6399649140308216613,
+    1164139066441209845,
+    13329419127073273031,
+    15461186918249130124,
+    11224355876435117805,
+    1395637108930413198,
+    17592496472292583308,
+    2367404249205402552,
+    1076534003771627685,
+    3240992666960467616,
+    10395712392052484310,
+    6469618018394733315,
+    13682396332627805826,
+    2074476126547156040,
+    18337360783207709894,
+    2183730248516681126,
+    13003960068911496026,
+    2406839943049863898,
+    11650407240070659226,
+    3551050481514683131,
+    893417587772110718,
+    357817073026713719,
+    17556939028281364158,
+    11317257386053721754,
+    4115407697887457714,
+    726952847058092075,
+    4550993325287983791,
+    10931572471487277972,
+    10423585862948171622,
+    2880531402045579063,
+    17498828318193533942,
+    13384468387986162826,
+    15765918072157707256,
+    2601337142438138192,
+    4350225965506252610,
+    10084612582867103707,
+    11829903451439122961,
+    2785508785327060692,
+    10019552733539859972,
+    13555531463050940355,
+    7459497523278883307,
+    1580351154807346389,
+    13445595841885084435,
+    11682021798722539556,
+    291944028705572699,
+    2477789232340647730,
+    15657301192376672306,
+    5091227546301331090,
+    3543342796292444029,
+    1798767524984391377,
+    4108853589596150057,
+    281952530230729760,
+    1836145692539246043,
+    1755707688655565268,
+    6441625677887214534,
+    8351395043103673378,
+    2175480385037397008,
+    311452734992231245,
+    7782643254801494379,
+    8712751324063848406,
+    2140312548574064100,
+    731890979861449147,
+    17192042847209610999,
+    6416567404560537920,
+    10489073257312217618,
+    384831367010333153,
+    4320244947435583161,
+    16339361039534695581,
+    8585753916296482117,
+    3253879909950277294,
+    4940234571093034116,
+    13676180051444656137,
+    184816442527287036,
+    363696631296497730,
+    2492672875862469076,
+    6327696495519804507,
+    2406616090836552152,
+    1769776850824233584,
+    4273985754344788920,
+    10329824152163549255,
+    12717763507276518952,
+    1811287102887489970,
+    8833140827844414207,
+    4669826085203031309,
+    3312928422394805053,
+    1194885686775817883,
+    17767771339588691896,
+    14473446753899902127,
+    2977830979126193532,
+    3010068982504051575,
+    17156871143161073924,
+    4954226630619043585,
+    18063207984032728740,
+    467195568422179889,
+    15154599478110307697,
+    13263175563311314441,
+    6903234739044861614,
+    267637541121152528,
+    8589760893837107488,
+    16529767107897475807,
+    2291758944543536421,
+    2413424035179115367,
+    8935526917937648128,
+    8109671580492976234,
+    3086045100747999496,
+    3291248544892747881,
+    17057216463003918072,
+    10690723151098918451,
+    3165132940186826807,
+    3247623643570344689,
+    7092117339124922056,
+    11594424852421123855,
+    5011843885356192391,
+    320647643975376617,
+    17867014622809266153,
+    17962457851266716184,
+    13690120378893613839,
+    1445358542897637412,
+    8678070241045452328,
+    10094469944430522379,
+    7288579034463947841,
+    802799283961306344,
+    16663637173482113320,
+    2216082506634922492,
+    11656883219940006953,
+    2187618653965456386,
+    13767494990387540829,
+    8054951503250075733,
+    6147812638672611602,
+    3182606671719890664,
+    3866880112428413998,
+    15605813726021880638,
+    11675487002358276558,
+    3476021001748990384,
+    11911112814945064779,
+    17948423524171320557,
+    4952287516881326958,
+    102442608928362005,
+    16140913492010532072,
+    12576420685443334137,
+    2352212959291188723,
+    3299273179499752903,
+    2360387625241056579,
+    8180421827795069303,
+    11051660223455129019,
+    464145685978358483,
+    18276412000393982602,
+    13327634243471259289,
+    12138384489725855025,
+    2596762437013301910,
+    14619347464421295114,
+    3608869103298027703,
+    9393020924584089766,
+    2498004076795943476,
+    16059120617873261931,
+    11408274291994478911,
+    16563592810206782665,
+    1868893409759019460,
+    8690457477661627108,
+    10871142361016952624,
+    4700927815169862460,
+    1845820692274095450,
+    2032286932540710805,
+    10060556660226541731,
+    1877788401665951768,
+    1025802143634918723,
+    6420463006717940989,
+    7923386286486998349,
+    14993614250125892694,
+    1598726942024945831,
+    7797399933258454606,
+    3859816802035498868,
+    12416245344547901991,
+    3409800455496504485,
+    11655423198426258064,
+    13636213193359397764,
+    1736395319131338944,
+    1017600066885494836,
+    11628273665157235374,
+    2905477823589291305,
+    17625636259870994026,
+    563616774366464431,
+    5425919224751581361,
+    293517355510556406,
+    10362021549832124897,
+    3222062268767207776,
+    16893451224881320040,
+    12687244166385926056,
+    5809468165398268231,
+    786963770620777774,
+    14999998790216488513,
+    10805031801685439619,
+    4727659633906551303,
+    3440028508259909926,
+    3370549517395225827,
+    16652563891763596093,
+    10045978389167929768,
+    1776965104672948380,
+    16571084198256062690,
+    6541007909396464783,
+    8237946987673695709,
+    716489215618019124,
+    12072263701339339720,
+    6211499054218198220,
+    2083016912477554536,
+    1821438059799230194,
+    8557611651196629805,
+    12144716242644127845,
+    5969017042400613059,
+    1383814603687125698,
+    15981322190271838679,
+    16645049653154562505,
+    16368630355776622817,
+    3272188867205599067,
+    17927867366038660171,
+    10407173820343867636,
+    5503411160769910905,
+    1881433629575205663,
+    14107989835216554884,
+    2675638601859331879,
+    15223068788825491261,
+    2087756349443895483,
+    2470582012984699177,
+    1291194440612976330,
+    13119554370881903447,
+    2440131151014963093,
+    15737636312950824561,
+    2427136280822005212,
+    14870602316665226377,
+    1817284650848533227,
+    15116592482915864429,
+    7801517062400111337,
+    7562045491535788781,
+    2900112603798741853,
+    11078782146595727749,
+    2298748500174417546,
+    16895131587196828383,
+    1372879635270851422,
+    1359200850161281532,
+    3739866955420546118,
+    1929739452928637560,
+    2660224904198310431,
+    610131399190561057,
+    15233301082723775381,
+    16401577442006053206,
+    2600068421410885545,
+    6615797199455726526,
+    14647744467130164670,
+    14665063372833998139,
+    2655396075972595291,
+    1861527479829185282,
+    15534067326318259992,
+    15302849817095771840,
+    709778659456974948,
+    18391771436476165296,
+    18150574943031303584,
+    8009832284200771391,
+    2216354403438687584,
+    15505210697534794365,
+    54920640656443933,
+    8452752716114019814,
+    368082663657163773,
+    10832431830432615136,
+    6522990179737764579,
+    12018511164388745176,
+    2357279206194709053,
+    10035076850076214832,
+    11802799804288247933,
+    17123824203755976364,
+    1075991312744810217,
+    11337721199314387809,
+    10722976917609009138,
+    5559873209938592011,
+    1959897242847518252,
+    13847026041124763812,
+    7422019370244816074,
+    10815285829441435762,
+    930017106217127899,
+    12006168628985409801,
+    18216919842459971579,
+    4658804677383389101,
+    1197957772434067980,
+    4088012414516360842,
+    12111502089055459011,
+    11891261325436695589,
+    2966162205335707064,
+    2207083350433490494,
+    7272341109588901498,
+    5306832749987256203,
+    3232513092576921356,
+    117150397966719455,
+    6015296615417648439,
+    8422451639549864888,
+    1298644404612602993,
+    7383199433088376777,
+    17863454842394253158,
+    14364098950105529805,
+    2503927443680615380,
+    6382060422365466046,
+    2233263476675167367,
+    7470384846752866866,
+    1783989453233653745,
+    7434349353779920295,
+    9623958771113894927,
+    3802389955371769963,
+    2184874103731343102,
+    1248303258742625780,
+    13270819595657073449,
+    4563388127159221060,
+    2777368498190412231,
+    15571755733021551286,
+    3965206076198781090,
+    4471377515464112190,
+    2636068982805342439,
+    7286130464729100114,
+    1853028441408412983,
+    15339346316031056449,
+    2938533381421254990,
+    16525877379900124634,
+    13072411259138890181,
+    13195062266666840785,
+    1523654540183637444,
+    9504794498317666974,
+    16200684738755753303,
+    8669266464727625606,
+    1149924541234383230,
+    1713250350920631156,
+    1467601830689431434,
+    11144214799520664455,
+    2508898840305679851,
+    2140136451768673890,
+    1848345291986839035,
+    18011649041297689908,
+    1320742022986084745,
+    14349018758967544995,
+    2658364482034747656,
+    2078662504915096831,
+    398837792941368214,
+    2996637301566070995,
+    3842755863663272326,
+    2208225756681451406,
+    2436595135979340175,
+    2176340468718623614,
+    537586907471182302,
+    7204602217914359564,
+    348111172217388968,
+    6485578306509483875,
+    14179722280567576969,
+    992397296491228149,
+    2455824825433255690,
+    14613410124508960400,
+    10716991514754250624,
+    5093978164612537249,
+    1502270716138557700,
+    13383168637239501959,
+    13584287334567786517,
+    667328442207368026,
+    2799631802642603282,
+    18324182781196793410,
+    6225614679731396866,
+    3259515064815778214,
+    936966470470275031,
+    15838179401139749242,
+    10324972079520305030,
+    10453725648988028012,
+    362838964478665496,
+    18399256808072567478,
+    15977219692253730070,
+    11892109564789488482,
+    3232378995521084285,
+    2757803806550976946,
+    9738676721883737162,
+    7301073814946616326,
+    1757224404540460695,
+    12823372581401665838,
+    1515465573106779469,
+    4587827783162414964,
+    1355165324965877846,
+    14075285043093113326,
+    6301538457716060699,
+    550695699394098348,
+    2653579354718131358,
+    17337510328059419027,
+    3318197445431275062,
+    8136822148661092865,
+    1270375219422249261,
+    5591466145020689126,
+    15849362007210283543,
+    14592237471063610693,
+    638921158031062525,
+    1436909297475391518,
+    11989303189578718430,
+    14660787005486747178,
+    1951062719147121930,
+    14810608345212664594,
+    17896919857481969014,
+    17186990855198583376,
+    2369858619478993121,
+    11125494774375352910,
+    2408886710283744090,
+    15235805551189511294,
+    1691383369901973721,
+    4845416110179016542,
+    12024104191545719156,
+    17223889401504562142,
+    3280501970303320120,
+    11567258282403636826,
+    12307288932007912941,
+    5147201366287234128,
+    1760626599253169293,
+    2540770200916844950,
+    14210570919610714423,
+    14329583086280313512,
+    933716474011060217,
+    7980008875385003510,
+    11837068684331788474,
+    9114895130624603713,
+    70646101607958603,
+    13256441813250842635,
+    7438694414092004977,
+    11059040915450143383,
+    1453773391976276124,
+    10588652543792104079,
+    8775378650199521258,
+    8006535040435283428,
+    2198640942589165596,
+    16906156292768085668,
+    12389478654414381996,
+    13888117431870613656,
+    370729211708978245,
+    290550729459875505,
+    4143727421560347268,
+    17340563744524672471,
+    1815306069276510647,
+    4925654927697042108,
+    5816125095479471340,
+    6892580704790959942,
+    2142769818059441565,
+    11861604989549286080,
+    10273393490816931592,
+    13685572722624840285,
+    138531346626747874,
+    10189145717568724588,
+    15018897011810953027,
+    11787805973901302862,
+    1233054460309507630,
+    5296135435453270708,
+    9671248300651760944,
+    2069573301357216968,
+    45594137753734781,
+    7170101234468273025,
+    15292276633841142691,
+    4366924076354869680,
+    1464184944688352760,
+    7617102067682716012,
+    193545476233341342,
+    16994945521571192982,
+    3297258221974611197,
+    5851406879673277355,
+    3365397902762236521,
+    13780768843552544717,
+    1628576271627881272,
+    9034589110643057173,
+    3146583764031398223,
+    12291110799475550684,
+    2498782627269073122,
+    259626761221602527,
+    7640825443214370555,
+    564083390465401153,
+    2822063082073130792,
+    12840546336459526300,
+    17395693102313832391,
+    15004024580283764654,
+    1648773531072707532,
+    605192870190671195,
+    673054614546519965,
+    16537082584718873214,
+    2845402156830132774,
+    14081834730168809900,
+    13668456855748917848,
+    13409548865869510064,
+    1092418762697937348,
+    8534294875080582343,
+    1508237789941201405,
+    14469998912954625778,
+    2796785847280206515,
+    277120750597972623,
+    12909954103878360933,
+    12302930615942679772,
+    243488887009223409,
+    16603873503222168946,
+    10301382966991044159,
+    10481081453965646116,
+    517402893659101360,
+    633424664311272833,
+    4754256308893003927,
+    10598555802568076645,
+    523015995609177294,
+    8271850491504439267,
+    15280798884925462511,
+    9803411590057352355,
+    26072374900182491,
+    768356652422637560,
+    10790560633662961513,
+    1399935710535449741,
+    3014449490406377794,
+    12683247571915344071,
+    3020973249348446062,
+    16706054219167527434,
+    3003947926650482653,
+];
+
+const ALPHA_G1_BETA_G2: [u64; 48] = [
+    4005146341689767559,
+    10376236909841985020,
+    10228357469384981314,
+    2521105964384565921,
+    14605739922405811558,
+    8532583630488128411,
+    17106026497294539736,
+    2937989719452997088,
+    16035288347328256407,
+    1602964191376104181,
+    11087524935434594182,
+    2370651301131642969,
+    15335896197550860870,
+    6370839159046689831,
+    11953339133793551631,
+    3148323497732076150,
+    12617001678603982911,
+    4438519502073974956,
+    13657114664649340517,
+    1043495224099282200,
+    11226623496337149216,
+    16401940762427697250,
+    14895588894472861168,
+    638907335043333091,
+    5167539968067454444,
+    7239587445516314892,
+    3043445935646176441,
+    227543377402270687,
+    12533139812605754834,
+    686777105408682943,
+    1837060594063531501,
+    2658267562722784078,
+    14574968340745576975,
+    3465261895213942917,
+    7771057689682167840,
+    2933997533346903400,
+    4902447301362786842,
+    5338322643693577206,
+    6049465946559378323,
+    2030909651997394450,
+    2714317791176461084,
+    14737155677528737011,
+    6075488409942306135,
+    211656279536953523,
+    10568663943231836015,
+    9583098301860546388,
+    10315544717566570679,
+    2590419289137760936,
+];
+
+const GAMMA_ABC: [u64; 16] = [
+    1853082223341644633,
+    15003504214402025108,
+    13536119302347392617,
+    2427657551110678660,
+    6610988933368049241,
+    1880641174044608528,
+    6547382518416914961,
+    1556240026436923053,
+    960745377415421658,
+    897922335264399659,
+    7429692064529972628,
+    1760158828392953565,
+    15762589920088611647,
+    9408965261733958269,
+    13844847911611577904,
+    3469250036105703785,
+];
+
+#[cfg(test)]
+mod test {
+    use ark_groth16::prepare_verifying_key;
+    use circuit::merkle::merkle_keys;
+
+    use super::*;
+
+    #[test]
+    fn test_merkle_key_matches_ceremony_keys() {
+        let pvk = prepare_verifying_key(&merkle_keys().unwrap().1);
+        for j in 0..91 {
+            for k in 0..3 {
+                assert_eq!(MerkleKey.gamma_coeff(j, k), pvk.gamma_coeff(j, k));
+                assert_eq!(MerkleKey.delta_coeff(j, k), pvk.delta_coeff(j, k));
+            }
+        }
+        assert_eq!(MerkleKey.alpha_g1_beta_g2(), pvk.alpha_g1_beta_g2());
+        assert_eq!(MerkleKey.gamma_abc_g1(), pvk.gamma_abc_g1());
+    }
+}
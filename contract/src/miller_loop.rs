@@ -1,35 +1,80 @@
-use ark_bn254::{Fq12Parameters, G1Affine, G1Projective, Parameters};
+use ark_bn254::{Fq, Fq12Parameters, Fq2, G1Affine, G1Projective, G2Affine, Parameters};
 use ark_ec::bn::{BnParameters, G1Prepared};
-use ark_ec::ProjectiveCurve;
-use ark_ff::{Field, Fp12, Fp12ParamsWrapper, FromBytes, QuadExtField};
-
-use crate::pvk::{get_delta_qef, get_gamma_qef};
-use crate::utils::{get_account_data, put_account_data};
-
-pub fn gamma_miller_loop_handler(i: usize, j: usize, input: &[u8]) {
-    let prepared_input: G1Prepared<ark_bn254::Parameters> = G1Projective::read(&mut input.as_ref())
-        .unwrap()
-        .into_affine()
-        .into();
-    let account_data = get_account_data("gamma".to_string(), j);
+use ark_ec::{ProjectiveCurve, SWModelParameters};
+use ark_ff::{to_bytes, Field, Fp12, Fp12ParamsWrapper, FromBytes, One, QuadExtField, Zero};
+
+use crate::error::Error;
+use crate::inputs::check_prepared_input;
+use crate::pvk::VerifyingKey;
+use crate::session::Session;
+use crate::state::{load_accumulator, pin_input, read_input, store, Stage};
+use crate::storage::Storage;
+
+pub fn gamma_miller_loop_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    i: usize,
+    j: usize,
+    input: &[u8],
+) -> Result<(), Error> {
+    check_step(i, j)?;
+    let prepared_input = read_input::<G1Projective>(input)?;
+    check_prepared_input(storage, vk, session, &prepared_input)?;
+    let prepared_input: G1Prepared<ark_bn254::Parameters> = prepared_input.into_affine().into();
+    let account_data = load_accumulator(storage, session, Stage::GammaMillerLoop, j)?;
+    pin_input(storage, session, Stage::GammaMillerLoop, j, input)?;
     let account_data = match j {
-        89 => final_gamma_miller_loop(&prepared_input, account_data, j),
-        _ => sub_gamma_miller_loop(&prepared_input, account_data, i, j),
+        89 => final_gamma_miller_loop(vk, &prepared_input, account_data, j),
+        _ => sub_gamma_miller_loop(vk, &prepared_input, account_data, i, j),
     };
-    put_account_data("gamma".to_string(), &account_data);
+    store(storage, session, Stage::GammaMillerLoop, j, &account_data);
+    Ok(())
 }
 
-pub fn gamma_onchain_ell(f: &mut Fp12<Fq12Parameters>, j: usize, p: &G1Affine) {
-    let mut c0 = get_gamma_qef(j, 0);
-    let mut c1 = get_gamma_qef(j, 1);
-    let c2 = get_gamma_qef(j, 2);
+/// Checks that step `j` of a Miller loop runs iteration `i` of the ate loop.
+///
+/// Every iteration takes one line function, or two if the next digit of the loop count is not
+/// zero, and the last step `89` adds the two lines of the Frobenius twists.
+fn check_step(i: usize, j: usize) -> Result<(), Error> {
+    let mut step = 0;
+    for k in (1..Parameters::ATE_LOOP_COUNT.len()).rev() {
+        if step == j {
+            return if k == i {
+                Ok(())
+            } else {
+                Err(Error::InvalidStage)
+            };
+        }
+        step += 1;
+        if Parameters::ATE_LOOP_COUNT[k - 1] != 0 {
+            step += 1;
+        }
+    }
+    if step == j && i == 0 {
+        Ok(())
+    } else {
+        Err(Error::InvalidStage)
+    }
+}
+
+pub fn gamma_onchain_ell<K: VerifyingKey>(
+    vk: &K,
+    f: &mut Fp12<Fq12Parameters>,
+    j: usize,
+    p: &G1Affine,
+) {
+    let mut c0 = vk.gamma_coeff(j, 0);
+    let mut c1 = vk.gamma_coeff(j, 1);
+    let c2 = vk.gamma_coeff(j, 2);
 
     c0.mul_assign_by_fp(&p.y);
     c1.mul_assign_by_fp(&p.x);
     f.mul_by_034(&c0, &c1, &c2);
 }
 
-fn sub_gamma_miller_loop(
+fn sub_gamma_miller_loop<K: VerifyingKey>(
+    vk: &K,
     p: &G1Prepared<ark_bn254::Parameters>,
     mut f: QuadExtField<Fp12ParamsWrapper<Fq12Parameters>>,
     i: usize,
@@ -39,13 +84,13 @@ fn sub_gamma_miller_loop(
         if i != ark_bn254::Parameters::ATE_LOOP_COUNT.len() - 1 {
             f.square_in_place();
         }
-        gamma_onchain_ell(&mut f, j, &p.0);
+        gamma_onchain_ell(vk, &mut f, j, &p.0);
         match ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] {
             1 => {
-                gamma_onchain_ell(&mut f, j + 1, &p.0);
+                gamma_onchain_ell(vk, &mut f, j + 1, &p.0);
             }
             -1 => {
-                gamma_onchain_ell(&mut f, j + 1, &p.0);
+                gamma_onchain_ell(vk, &mut f, j + 1, &p.0);
             }
             _ => {}
         }
@@ -53,41 +98,60 @@ fn sub_gamma_miller_loop(
     f
 }
 
-fn final_gamma_miller_loop(
+fn final_gamma_miller_loop<K: VerifyingKey>(
+    vk: &K,
     p: &G1Prepared<ark_bn254::Parameters>,
     mut f: QuadExtField<Fp12ParamsWrapper<Fq12Parameters>>,
     j: usize,
 ) -> QuadExtField<Fp12ParamsWrapper<Fq12Parameters>> {
     if !p.is_zero() {
-        gamma_onchain_ell(&mut f, j, &p.0);
-        gamma_onchain_ell(&mut f, j + 1, &p.0);
+        gamma_onchain_ell(vk, &mut f, j, &p.0);
+        gamma_onchain_ell(vk, &mut f, j + 1, &p.0);
     }
     f
 }
 
-pub fn delta_miller_loop_handler(i: usize, j: usize, input: &[u8]) {
-    let proof_c = G1Affine::read(&mut input.as_ref())
-        .map(|p| G1Prepared::<Parameters>::from(p))
-        .unwrap();
-    let account_data = get_account_data("delta".to_string(), j);
+pub fn delta_miller_loop_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+    i: usize,
+    j: usize,
+    input: &[u8],
+) -> Result<(), Error> {
+    check_step(i, j)?;
+    let proof_c = read_input::<G1Affine>(input)?;
+    if !proof_c.is_on_curve() {
+        return Err(Error::InvalidInput);
+    }
+    let proof_c = G1Prepared::<Parameters>::from(proof_c);
+    let account_data = load_accumulator(storage, session, Stage::DeltaMillerLoop, j)?;
+    pin_input(storage, session, Stage::DeltaMillerLoop, j, input)?;
     let account_data = match j {
-        89 => final_delta_miller_loop(&proof_c, account_data, j),
-        _ => sub_delta_miller_loop(&proof_c, account_data, i, j),
+        89 => final_delta_miller_loop(vk, &proof_c, account_data, j),
+        _ => sub_delta_miller_loop(vk, &proof_c, account_data, i, j),
     };
-    put_account_data("delta".to_string(), &account_data);
+    store(storage, session, Stage::DeltaMillerLoop, j, &account_data);
+    Ok(())
 }
 
-fn delta_onchain_ell(f: &mut Fp12<Fq12Parameters>, j: usize, p: &G1Affine) {
-    let mut c0 = get_delta_qef(j, 0);
-    let mut c1 = get_delta_qef(j, 1);
-    let c2 = get_delta_qef(j, 2);
+fn delta_onchain_ell<K: VerifyingKey>(
+    vk: &K,
+    f: &mut Fp12<Fq12Parameters>,
+    j: usize,
+    p: &G1Affine,
+) {
+    let mut c0 = vk.delta_coeff(j, 0);
+    let mut c1 = vk.delta_coeff(j, 1);
+    let c2 = vk.delta_coeff(j, 2);
 
     c0.mul_assign_by_fp(&p.y);
     c1.mul_assign_by_fp(&p.x);
     f.mul_by_034(&c0, &c1, &c2);
 }
 
-fn sub_delta_miller_loop(
+fn sub_delta_miller_loop<K: VerifyingKey>(
+    vk: &K,
     p: &G1Prepared<ark_bn254::Parameters>,
     mut f: QuadExtField<Fp12ParamsWrapper<Fq12Parameters>>,
     i: usize,
@@ -97,13 +161,13 @@ fn sub_delta_miller_loop(
         if i != ark_bn254::Parameters::ATE_LOOP_COUNT.len() - 1 {
             f.square_in_place();
         }
-        delta_onchain_ell(&mut f, j, &p.0);
+        delta_onchain_ell(vk, &mut f, j, &p.0);
         match ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] {
             1 => {
-                delta_onchain_ell(&mut f, j + 1, &p.0);
+                delta_onchain_ell(vk, &mut f, j + 1, &p.0);
             }
             -1 => {
-                delta_onchain_ell(&mut f, j + 1, &p.0);
+                delta_onchain_ell(vk, &mut f, j + 1, &p.0);
             }
             _ => {}
         }
@@ -111,14 +175,230 @@ fn sub_delta_miller_loop(
     f
 }
 
-fn final_delta_miller_loop(
+fn final_delta_miller_loop<K: VerifyingKey>(
+    vk: &K,
     p: &G1Prepared<ark_bn254::Parameters>,
     mut f: QuadExtField<Fp12ParamsWrapper<Fq12Parameters>>,
     j: usize,
 ) -> QuadExtField<Fp12ParamsWrapper<Fq12Parameters>> {
     if !p.is_zero() {
-        delta_onchain_ell(&mut f, j, &p.0);
-        delta_onchain_ell(&mut f, j + 1, &p.0);
+        delta_onchain_ell(vk, &mut f, j, &p.0);
+        delta_onchain_ell(vk, &mut f, j + 1, &p.0);
     }
     f
 }
+
+/// Slot of the running point of the Miller loop of `A` and `B`, after the step that reads it.
+pub const QAP_POINT_SLOT: &str = "qap_point";
+
+const G1_AFFINE_LEN: usize = 65;
+const G2_AFFINE_LEN: usize = 129;
+/// Length of the input of the Miller loop of `A` and `B`, `A` (`G1Affine`) followed by `B`
+/// (`G2Affine`).
+pub const PROOF_AB_LEN: usize = G1_AFFINE_LEN + G2_AFFINE_LEN;
+
+type G2Parameters = <Parameters as BnParameters>::G2Parameters;
+type EllCoeff = (Fq2, Fq2, Fq2);
+
+/// Runs step `j` of the Miller loop of the proof's `A` and `B`, the `qap` the final
+/// exponentiation multiplies with the gamma and delta Miller loops.
+///
+/// Unlike gamma and delta, `B` is not known in advance, so its line functions are computed on
+/// chain from a multiple of `B` that every step stores for the next one. The steps have to run
+/// in order, each one over the `A` and `B` of the first step.
+pub fn qap_miller_loop_handler<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    i: usize,
+    j: usize,
+    input: &[u8],
+) -> Result<(), Error> {
+    check_step(i, j)?;
+    let (a, b) = read_proof_ab(input)?;
+    if j == 0 && !is_valid_proof_ab(&a, &b) {
+        return Err(Error::InvalidInput);
+    }
+    let f = load_accumulator(storage, session, Stage::QapMillerLoop, j)?;
+    let r = match j {
+        0 => LinePoint::from(&b),
+        _ => LinePoint::load(storage, session, j)?,
+    };
+    pin_input(storage, session, Stage::QapMillerLoop, j, input)?;
+
+    let (f, r, next) = match j {
+        89 => final_qap_miller_loop(&a, &b, f, r, j),
+        _ => sub_qap_miller_loop(&a, &b, f, r, i, j),
+    };
+    store(storage, session, Stage::QapMillerLoop, j, &f);
+    r.store(storage, session, next);
+    Ok(())
+}
+
+/// Decodes the `A` and `B` of a proof.
+fn read_proof_ab(input: &[u8]) -> Result<(G1Affine, G2Affine), Error> {
+    if input.len() != PROOF_AB_LEN {
+        return Err(Error::InvalidInput);
+    }
+    let (a, b) = input.split_at(G1_AFFINE_LEN);
+    Ok((read_input::<G1Affine>(a)?, read_input::<G2Affine>(b)?))
+}
+
+/// Decoding doesn't check the points, `B` in particular has to be in the prime order subgroup.
+fn is_valid_proof_ab(a: &G1Affine, b: &G2Affine) -> bool {
+    a.is_on_curve() && b.is_on_curve() && b.is_in_correct_subgroup_assuming_on_curve()
+}
+
+/// A multiple of `B` in homogeneous projective coordinates.
+struct LinePoint {
+    x: Fq2,
+    y: Fq2,
+    z: Fq2,
+}
+
+impl From<&G2Affine> for LinePoint {
+    fn from(q: &G2Affine) -> LinePoint {
+        LinePoint {
+            x: q.x,
+            y: q.y,
+            z: Fq2::one(),
+        }
+    }
+}
+
+impl LinePoint {
+    /// Reads the point stored for step `j`.
+    fn load<S: Storage>(storage: &S, session: &Session, j: usize) -> Result<LinePoint, Error> {
+        let src = storage
+            .get("data", &session.key(QAP_POINT_SLOT))
+            .ok_or(Error::MissingState)?;
+        if src.is_empty() {
+            return Err(Error::MissingState);
+        }
+        if src[0] as usize != j {
+            return Err(Error::UnexpectedStage);
+        }
+        let mut reader = &src[1..];
+        let mut read = || -> Result<LinePoint, std::io::Error> {
+            Ok(LinePoint {
+                x: Fq2::read(&mut reader)?,
+                y: Fq2::read(&mut reader)?,
+                z: Fq2::read(&mut reader)?,
+            })
+        };
+        let point = read().map_err(|_| Error::InvalidState)?;
+        if !reader.is_empty() {
+            return Err(Error::InvalidState);
+        }
+        Ok(point)
+    }
+
+    /// Stores the point for step `next`.
+    fn store<S: Storage>(&self, storage: &mut S, session: &Session, next: usize) {
+        let mut dst = vec![next as u8];
+        dst.extend(to_bytes!(self.x, self.y, self.z).unwrap());
+        storage.set("data", &session.key(QAP_POINT_SLOT), dst);
+    }
+
+    /// Doubles the point and returns the line function through it, as `G2Prepared` does.
+    fn doubling_step(&mut self) -> EllCoeff {
+        let two_inv = Fq::one().double().inverse().unwrap();
+        let mut a = self.x * self.y;
+        a.mul_assign_by_fp(&two_inv);
+        let b = self.y.square();
+        let c = self.z.square();
+        let e = G2Parameters::COEFF_B * (c.double() + c);
+        let f = e.double() + e;
+        let mut g = b + f;
+        g.mul_assign_by_fp(&two_inv);
+        let h = (self.y + self.z).square() - (b + c);
+        let i = e - b;
+        let j = self.x.square();
+        let e_square = e.square();
+
+        self.x = a * (b - f);
+        self.y = g.square() - (e_square.double() + e_square);
+        self.z = b * h;
+        (-h, j.double() + j, i)
+    }
+
+    /// Adds `q` to the point and returns the line function through both, as `G2Prepared` does.
+    fn addition_step(&mut self, q: &G2Affine) -> EllCoeff {
+        let theta = self.y - q.y * self.z;
+        let lambda = self.x - q.x * self.z;
+        let c = theta.square();
+        let d = lambda.square();
+        let e = lambda * d;
+        let f = self.z * c;
+        let g = self.x * d;
+        let h = e + f - g.double();
+        self.x = lambda * h;
+        self.y = theta * (g - h) - e * self.y;
+        self.z *= e;
+        let j = theta * q.x - lambda * q.y;
+        (lambda, -theta, j)
+    }
+}
+
+/// The image of `q` under the Frobenius endomorphism of the twist.
+fn mul_by_char(q: &G2Affine) -> G2Affine {
+    let mut s = *q;
+    s.x.frobenius_map(1);
+    s.x *= &Parameters::TWIST_MUL_BY_Q_X;
+    s.y.frobenius_map(1);
+    s.y *= &Parameters::TWIST_MUL_BY_Q_Y;
+    s
+}
+
+fn qap_onchain_ell(f: &mut Fp12<Fq12Parameters>, coeffs: &EllCoeff, p: &G1Affine) {
+    let mut c0 = coeffs.0;
+    let mut c1 = coeffs.1;
+
+    c0.mul_assign_by_fp(&p.y);
+    c1.mul_assign_by_fp(&p.x);
+    f.mul_by_034(&c0, &c1, &coeffs.2);
+}
+
+/// Runs iteration `i` of the ate loop and returns the accumulator, the point and the next step.
+fn sub_qap_miller_loop(
+    a: &G1Affine,
+    b: &G2Affine,
+    mut f: Fp12<Fq12Parameters>,
+    mut r: LinePoint,
+    i: usize,
+    j: usize,
+) -> (Fp12<Fq12Parameters>, LinePoint, usize) {
+    let bit = Parameters::ATE_LOOP_COUNT[i - 1];
+    let next = if bit != 0 { j + 2 } else { j + 1 };
+    // a pairing with the point at infinity is one
+    if a.is_zero() || b.is_zero() {
+        return (f, r, next);
+    }
+    if i != Parameters::ATE_LOOP_COUNT.len() - 1 {
+        f.square_in_place();
+    }
+    qap_onchain_ell(&mut f, &r.doubling_step(), a);
+    match bit {
+        1 => qap_onchain_ell(&mut f, &r.addition_step(b), a),
+        -1 => qap_onchain_ell(&mut f, &r.addition_step(&-*b), a),
+        _ => {}
+    }
+    (f, r, next)
+}
+
+fn final_qap_miller_loop(
+    a: &G1Affine,
+    b: &G2Affine,
+    mut f: Fp12<Fq12Parameters>,
+    mut r: LinePoint,
+    j: usize,
+) -> (Fp12<Fq12Parameters>, LinePoint, usize) {
+    if a.is_zero() || b.is_zero() {
+        return (f, r, j + 2);
+    }
+    let q1 = mul_by_char(b);
+    let mut q2 = mul_by_char(&q1);
+    q2.y = -q2.y;
+    qap_onchain_ell(&mut f, &r.addition_step(&q1), a);
+    qap_onchain_ell(&mut f, &r.addition_step(&q2), a);
+    (f, r, j + 2)
+}
@@ -0,0 +1,96 @@
+//! One-time use of proofs through a registry of spent nullifiers.
+//!
+//! Under a verifying key with a nullifier the public inputs are prepared on chain by
+//! [`crate::inputs::prepare_inputs_handler`] and a successful final check spends the
+//! nullifier, after which no other proof with the same nullifier is accepted. The final check
+//! only passes for a valid proof of those inputs because the contract computes all three Miller
+//! loops itself, from the stored prepared input and the proof's `A`, `B` and `C`.
+use ark_bn254::G1Affine;
+use base64::{encode_config, URL_SAFE_NO_PAD};
+
+use crate::error::Error;
+use crate::inputs::{read_inputs, FR_LEN};
+use crate::pvk::VerifyingKey;
+use crate::session::Session;
+use crate::storage::Storage;
+
+/// A verifying key whose public input `index` is a nullifier.
+#[derive(Clone, Copy, Debug)]
+pub struct NullifierKey<K> {
+    key: K,
+    index: usize,
+}
+
+impl<K: VerifyingKey> NullifierKey<K> {
+    pub fn new(key: K, index: usize) -> NullifierKey<K> {
+        NullifierKey { key, index }
+    }
+}
+
+impl<K: VerifyingKey> VerifyingKey for NullifierKey<K> {
+    fn gamma_coeff(&self, j: usize, k: usize) -> ark_bn254::Fq2 {
+        self.key.gamma_coeff(j, k)
+    }
+
+    fn delta_coeff(&self, j: usize, k: usize) -> ark_bn254::Fq2 {
+        self.key.delta_coeff(j, k)
+    }
+
+    fn alpha_g1_beta_g2(&self) -> ark_bn254::Fq12 {
+        self.key.alpha_g1_beta_g2()
+    }
+
+    fn gamma_abc_g1(&self) -> Vec<G1Affine> {
+        self.key.gamma_abc_g1()
+    }
+
+    fn nullifier_index(&self) -> Option<usize> {
+        Some(self.index)
+    }
+
+    fn caller_index(&self) -> Option<usize> {
+        self.key.caller_index()
+    }
+
+    fn root_index(&self) -> Option<usize> {
+        self.key.root_index()
+    }
+}
+
+/// Records the nullifier of the proof verified in `session` as spent.
+///
+/// Called once the final check passed, that is once the proof is known to be valid for the
+/// inputs prepared on chain. Keys without a nullifier accept a proof any number of times.
+pub fn spend<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+) -> Result<(), Error> {
+    if vk.nullifier_index().is_none() {
+        return Ok(());
+    }
+    let (_, inputs) = read_inputs(storage, session)?;
+    let nullifier = nullifier(vk, &inputs).ok_or(Error::InvalidState)?;
+    if is_spent(storage, nullifier) {
+        return Err(Error::NullifierSpent);
+    }
+    storage.set("nullifiers", &nullifier_key(nullifier), vec![1]);
+    Ok(())
+}
+
+/// Whether a proof with the given nullifier has been accepted already.
+pub fn is_spent<S: Storage>(storage: &S, nullifier: &[u8]) -> bool {
+    storage
+        .get("nullifiers", &nullifier_key(nullifier))
+        .map_or(false, |value| !value.is_empty())
+}
+
+/// The nullifier among the public inputs, if `vk` has one.
+pub(crate) fn nullifier<'a, K: VerifyingKey>(vk: &K, inputs: &'a [u8]) -> Option<&'a [u8]> {
+    let index = vk.nullifier_index()?;
+    inputs.chunks(FR_LEN).nth(index)
+}
+
+fn nullifier_key(nullifier: &[u8]) -> String {
+    encode_config(nullifier, URL_SAFE_NO_PAD)
+}
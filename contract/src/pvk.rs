@@ -1,7 +1,104 @@
-use ark_bn254::{Fq12Parameters, Fq2Parameters};
+use ark_bn254::{Bn254, Fq, Fq12, Fq12Parameters, Fq2, Fq2Parameters, G1Affine};
 use ark_ff::{
-    BigInteger256, CubicExtField, Fp12ParamsWrapper, Fp256, Fp2ParamsWrapper, QuadExtField,
+    to_bytes, BigInteger256, CubicExtField, Fp12ParamsWrapper, Fp256, Fp2ParamsWrapper,
+    QuadExtField,
 };
+use ark_groth16::PreparedVerifyingKey;
+use arrayref::array_ref;
+use types::crypto::blake2b;
+
+/// The parts of a prepared Groth16 verifying key read by the verification stages.
+pub trait VerifyingKey {
+    /// Coefficient `k` of the `j`-th line function of the prepared `-gamma`.
+    fn gamma_coeff(&self, j: usize, k: usize) -> Fq2;
+    /// Coefficient `k` of the `j`-th line function of the prepared `-delta`.
+    fn delta_coeff(&self, j: usize, k: usize) -> Fq2;
+    /// The pairing `e(alpha, beta)` every proof is checked against.
+    fn alpha_g1_beta_g2(&self) -> Fq12;
+    /// The bases `gamma_abc_g1` the public inputs are prepared with, the first one is not
+    /// multiplied by an input.
+    fn gamma_abc_g1(&self) -> Vec<G1Affine>;
+    /// The index of the public input that is a nullifier, if proofs under this key can only be
+    /// accepted once per nullifier.
+    fn nullifier_index(&self) -> Option<usize> {
+        None
+    }
+    /// The index of the public input that has to be the account submitting the proof, if
+    /// proofs under this key are bound to their submitter.
+    fn caller_index(&self) -> Option<usize> {
+        None
+    }
+    /// The index of the public input that is a Merkle root, if proofs under this key are only
+    /// accepted for roots registered by the admin.
+    fn root_index(&self) -> Option<usize> {
+        None
+    }
+    /// Whether the inputs of proofs under this key have to be prepared by the contract, which
+    /// keys reading one of their public inputs need, or keys whose prepared inputs can't be
+    /// trusted off chain.
+    fn prepares_inputs(&self) -> bool {
+        self.nullifier_index().is_some()
+            || self.caller_index().is_some()
+            || self.root_index().is_some()
+    }
+    /// Identifies the key towards other contracts, the hash of `e(alpha, beta)` and
+    /// `gamma_abc_g1`.
+    fn id(&self) -> [u8; 32] {
+        let mut src = to_bytes!(self.alpha_g1_beta_g2()).unwrap();
+        for base in self.gamma_abc_g1() {
+            src.extend(to_bytes!(base).unwrap());
+        }
+        blake2b(src)
+    }
+}
+
+/// The verifying key compiled into the contract.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmbeddedKey;
+
+impl VerifyingKey for EmbeddedKey {
+    fn gamma_coeff(&self, j: usize, k: usize) -> Fq2 {
+        get_gamma_qef(j, k)
+    }
+
+    fn delta_coeff(&self, j: usize, k: usize) -> Fq2 {
+        get_delta_qef(j, k)
+    }
+
+    fn alpha_g1_beta_g2(&self) -> Fq12 {
+        get_alpha_g1_beta_g2()
+    }
+
+    fn gamma_abc_g1(&self) -> Vec<G1Affine> {
+        get_gamma_abc_g1()
+    }
+}
+
+impl VerifyingKey for PreparedVerifyingKey<Bn254> {
+    fn gamma_coeff(&self, j: usize, k: usize) -> Fq2 {
+        ell_coeff(&self.gamma_g2_neg_pc.ell_coeffs[j], k)
+    }
+
+    fn delta_coeff(&self, j: usize, k: usize) -> Fq2 {
+        ell_coeff(&self.delta_g2_neg_pc.ell_coeffs[j], k)
+    }
+
+    fn alpha_g1_beta_g2(&self) -> Fq12 {
+        self.alpha_g1_beta_g2
+    }
+
+    fn gamma_abc_g1(&self) -> Vec<G1Affine> {
+        self.vk.gamma_abc_g1.clone()
+    }
+}
+
+fn ell_coeff(coeffs: &(Fq2, Fq2, Fq2), k: usize) -> Fq2 {
+    match k {
+        0 => coeffs.0,
+        1 => coeffs.1,
+        _ => coeffs.2,
+    }
+}
 
 const GAMMA: [u64; 2184] = [
     16776782478048074777,
@@ -2190,6 +2287,25 @@ const GAMMA: [u64; 2184] = [
     1174119134671340192,
 ];
 
+const GAMMA_ABC: [u64; 16] = [
+    4167760644736523912,
+    3114870143877803802,
+    9842120507202713092,
+    1846841240114748140,
+    15969776001957939534,
+    11292160962820876286,
+    16446421663119224266,
+    1458872708411191081,
+    15010071743712804485,
+    13712813272531845193,
+    3984604048363802188,
+    850673671452308909,
+    7664714725043757339,
+    1171712995496591633,
+    445780378298551578,
+    565848270368208982,
+];
+
 const DELTA: [u64; 2184] = [
     9618203633639557139,
     3730882676121572274,
@@ -4510,6 +4626,38 @@ pub fn get_alpha_g1_beta_g2() -> QuadExtField<Fp12ParamsWrapper<Fq12Parameters>>
     )
 }
 
+/// The points of `gamma_abc_g1`, stored as the coordinates `x` and `y` of each point.
+pub fn get_gamma_abc_g1() -> Vec<G1Affine> {
+    g1_points(&GAMMA_ABC)
+}
+
+/// Reads coefficient `k` of the `j`-th line function from `limbs`, laid out as [`GAMMA`].
+pub(crate) fn ell_coeff_at(limbs: &[u64], j: usize, k: usize) -> Fq2 {
+    let offset = (j * 3 + k) * 8;
+    Fq2::new(fq_at(limbs, offset), fq_at(limbs, offset + 4))
+}
+
+/// Reads the element of `Fq12` laid out as its coefficients in `Fq`, lowest first.
+pub(crate) fn fq12_at(limbs: &[u64; 48]) -> Fq12 {
+    let fq2 = |k: usize| Fq2::new(fq_at(limbs, k * 8), fq_at(limbs, k * 8 + 4));
+    Fq12::new(
+        CubicExtField::new(fq2(0), fq2(1), fq2(2)),
+        CubicExtField::new(fq2(3), fq2(4), fq2(5)),
+    )
+}
+
+/// Reads the points laid out as [`GAMMA_ABC`].
+pub(crate) fn g1_points(limbs: &[u64]) -> Vec<G1Affine> {
+    limbs
+        .chunks(8)
+        .map(|point| G1Affine::new(fq_at(point, 0), fq_at(point, 4), false))
+        .collect()
+}
+
+fn fq_at(limbs: &[u64], offset: usize) -> Fq {
+    Fp256::new(BigInteger256::new(*array_ref![limbs, offset, 4]))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -4523,4 +4671,17 @@ mod test {
         let c = get_gamma_qef(2, 1);
         println!("{:#?}", c);
     }
+
+    #[test]
+    fn test_embedded_key_matches_demo_parameters() {
+        let pvk = circuit::demo_verifying_key();
+        for j in 0..91 {
+            for k in 0..3 {
+                assert_eq!(EmbeddedKey.gamma_coeff(j, k), pvk.gamma_coeff(j, k));
+                assert_eq!(EmbeddedKey.delta_coeff(j, k), pvk.delta_coeff(j, k));
+            }
+        }
+        assert_eq!(EmbeddedKey.alpha_g1_beta_g2(), pvk.alpha_g1_beta_g2());
+        assert_eq!(EmbeddedKey.gamma_abc_g1(), pvk.gamma_abc_g1());
+    }
 }
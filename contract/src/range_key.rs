@@ -0,0 +1,4503 @@
+use ark_bn254::{Fq12, Fq2, G1Affine};
+
+use crate::pvk::{ell_coeff_at, fq12_at, g1_points, VerifyingKey};
+
+/// The verifying key of `circuit::range::CommittedRange` for the range `[0, 2^32)`, from the
+/// ceremony whose keys are in `circuit/keys`, compiled into the contract built with the `range`
+/// feature.
+///
+/// The only public input is the commitment to the value. Its prepared input is computed by the
+/// contract, see [`VerifyingKey::prepares_inputs`]: a prepared input from off chain would let a
+/// prover pass a proof for one commitment as a proof for another one.
+///
+/// The constants are printed by `cargo run -p circuit --bin embed_key -- circuit/keys/range.vk
+/// range`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RangeKey;
+
+impl VerifyingKey for RangeKey {
+    fn gamma_coeff(&self, j: usize, k: usize) -> Fq2 {
+        ell_coeff_at(&GAMMA, j, k)
+    }
+
+    fn delta_coeff(&self, j: usize, k: usize) -> Fq2 {
+        ell_coeff_at(&DELTA, j, k)
+    }
+
+    fn alpha_g1_beta_g2(&self) -> Fq12 {
+        fq12_at(&ALPHA_G1_BETA_G2)
+    }
+
+    fn gamma_abc_g1(&self) -> Vec<G1Affine> {
+        g1_points(&GAMMA_ABC)
+    }
+
+    fn prepares_inputs(&self) -> bool {
+        true
+    }
+}
+
+const GAMMA: [u64; 2184] = [
+    8674663392781680561,
+    11150774770452318155,
+    7809981663646109466,
+    1396765412850258720,
+    14211026145018412007,
+    10422456027353127108,
+    9270173539577186749,
+    1393412133577315262,
+    841204471680720046,
+    9409113336177933900,
+    11533536189327673077,
+    3332941532800090148,
+    6977871920699217621,
+    9480416609398470334,
+    18273143986108456748,
+    1398546971408098623,
+    429862144246880730,
+    11826361384402693459,
+    18409576370641582239,
+    1297595357774245849,
+    11574726958975220076,
+    10513342000974739593,
+    12303333075306418852,
+    2201957314167265116,
+    16913448780861308502,
+    14849423955289795436,
+    399210395488107052,
+    2490966252764467933,
+    4311859192379980493,
+    10349449708663081892,
+    10024770504239497331,
+    2061948552439036191,
+    12651067012008390648,
+    18150704932149206936,
+    15814479871292218490,
+    1137260944631114511,
+    4290283648592569438,
+    12355871579735898818,
+    3686272080381394779,
+    297581873302734669,
+    7728090479764175348,
+    14329227636085115580,
+    3071021637536107510,
+    82830240812000481,
+    6906439909007766160,
+    2561401797460156903,
+    970705604076510452,
+    3419655684297695776,
+    3135796707268240839,
+    12291444794218619339,
+    1510686509267240152,
+    708221306145037001,
+    13561452326639738034,
+    13715549827113353986,
+    11162368705541299785,
+    2394527476045192844,
+    7759383216957980718,
+    13053413204707652981,
+    1133734900849519416,
+    2478240792198046977,
+    226178311663656399,
+    5005543099874230594,
+    9066548258224918087,
+    2114013991547352324,
+    12949657938382544105,
+    17197184619796774887,
+    814521067844658708,
+    856944964948802514,
+    14807321512747723447,
+    12474021966149466195,
+    7988644755845091351,
+    173330133883160837,
+    12019062649576252620,
+    6753570749743198726,
+    6384738077548083981,
+    2470019431727694395,
+    2964917936001161847,
+    14092535275127739099,
+    15696699998332163895,
+    2110948412794278009,
+    13395966322793911800,
+    16190734172225182412,
+    5631976781757322132,
+    2912352350824879873,
+    11634541975248696993,
+    14174765084924758491,
+    6351338818151282130,
+    654234026549654543,
+    17122366453947807953,
+    12653491163026546897,
+    17762725795855589692,
+    1586547736911637631,
+    10485468761367521556,
+    10900986098596580136,
+    8980960926481745842,
+    3700064031837178,
+    10897755916745061097,
+    6788848823037919743,
+    8699729367356431134,
+    1230698982226965880,
+    13610315027997216884,
+    9638664954638796653,
+    12336758497814698042,
+    1549917763656528520,
+    15113319603593239572,
+    12778167660113966031,
+    8132766756314837910,
+    1930148422364045533,
+    10039042243170269372,
+    15103264474119286893,
+    18101711275716457169,
+    551785545474309801,
+    16047532451728665320,
+    9249837174448630110,
+    15210004418371329005,
+    3073387118383812858,
+    6696711198834594625,
+    14242571890906819607,
+    10376749736866473562,
+    465360159038246838,
+    12801596243260109836,
+    14181952298098930719,
+    12637839675433476730,
+    250118460746621603,
+    2566594975796603931,
+    13728666505330613666,
+    2634179081842942752,
+    763268772514101976,
+    18103788037592775257,
+    2946198449405192361,
+    17403695266896164340,
+    1655965033296136192,
+    16660725427192833903,
+    17621677579973441077,
+    6595134448483373835,
+    533951497602922180,
+    15750511338976373902,
+    17405761715459590179,
+    8745770696757352918,
+    2925158704008012353,
+    6657490403639774486,
+    13489087803226435678,
+    14811542153917905484,
+    1530206783803635151,
+    10681591748901673257,
+    11812031751693613852,
+    10927359851087986384,
+    659507076950388866,
+    8176235652202685060,
+    11743805891445164581,
+    2948576583912722103,
+    3058545072504659165,
+    8047594523308926768,
+    6871764291095898329,
+    14008256927139636660,
+    1907675626523702253,
+    2149657217800218016,
+    14667518954882023522,
+    18300025901828297508,
+    2353958562299094418,
+    13813682104806506739,
+    720773505002898557,
+    17001734417340829123,
+    1161852586700004442,
+    10017069392163633459,
+    1696393796739620952,
+    6190801811617361759,
+    1730817587417392627,
+    16721986632638762431,
+    770152502238353806,
+    18126465710118105607,
+    2568051366677734193,
+    17546465014808689042,
+    7708211239777583577,
+    12667396470266885205,
+    2832033498888550591,
+    146997332333709373,
+    891898318690600762,
+    17914351238296680551,
+    77341955269767057,
+    10850582442187281248,
+    14000012093508279239,
+    15020522234186322744,
+    2229945004567675821,
+    7608288165149768239,
+    219045339003518026,
+    17820457025316219594,
+    2765983783040221092,
+    8070102132019118863,
+    16291463145677528487,
+    2157636068934524913,
+    1467046122386901065,
+    14119987602933216239,
+    7055284218783363598,
+    13689087094834335107,
+    2275766286253598487,
+    3811984577894945884,
+    3642080662250333584,
+    7710144347925179023,
+    1560173563914380189,
+    12559192710593247345,
+    17638351129920866873,
+    418981231598460633,
+    1008871266456162962,
+    1498598022960099428,
+    10551762682607067707,
+    14299640244359572543,
+    1870515618246897825,
+    17412142090591899193,
+    13731109882772482006,
+    5692747307754475869,
+    3381908753397110172,
+    8139580881118090738,
+    484738866100753180,
+    6108580319137692742,
+    2940179975668127212,
+    13214703004059208063,
+    9591440765161543947,
+    8652846680946414168,
+    1967980676242577912,
+    12120471427510208251,
+    2315222701962729439,
+    6201932023629839525,
+    3294976267816763112,
+    15274032008277231073,
+    1438512044469712953,
+    18242385742625890753,
+    1341938907209261974,
+    1367504483579060115,
+    12692127802355676738,
+    5479388763250229791,
+    2034590224329461440,
+    17819175726995393091,
+    15789324628518296193,
+    16397323509387177604,
+    34586833598042864,
+    11708193703126602433,
+    2643724069243864999,
+    3098202978777715332,
+    722373426499215710,
+    12574625126314422761,
+    15017425650938438794,
+    4599333690019100832,
+    158103786513169233,
+    8221118775355887984,
+    15305995367600954014,
+    3231760586096358074,
+    1178263511987436636,
+    10444052856456863799,
+    12844577084248454578,
+    16793770321480773822,
+    768642264268647165,
+    7500423859174915047,
+    18261046822335240530,
+    12144659531434065501,
+    184367225767637804,
+    3727776413565250022,
+    6251678293794884489,
+    14713826952554493356,
+    2288071255408111054,
+    13567713742501169220,
+    4293479799793869657,
+    9190540994596619059,
+    3137062920919777409,
+    11813225258955471510,
+    807948146248060153,
+    16593575319651868157,
+    1716615522172204286,
+    3987981357691868690,
+    17640124383746484799,
+    2531688214755302518,
+    549043552724873118,
+    11558225968057025625,
+    3069393656078626774,
+    15031572158981348454,
+    1549916390717683613,
+    9671346770889795658,
+    10024942164968079162,
+    8698725059131879683,
+    1883649663945213512,
+    4403594354663325805,
+    8561653796155809840,
+    3521087491773780368,
+    3356952354765340057,
+    1181915152918021043,
+    15542361456355905814,
+    8294583850676260974,
+    681594497238654107,
+    14666464596956364203,
+    6389950061155161356,
+    12671119898610768782,
+    2104389852112108336,
+    2176171042167326127,
+    16368636477862467501,
+    4130445898065580885,
+    2450730765499217185,
+    13318237805305982344,
+    15964564664570759945,
+    10335964433362412340,
+    2929737464957680809,
+    16944733762813114973,
+    8402312819381713799,
+    8606395760811737693,
+    2927863554054470849,
+    11120181412641763896,
+    13518471286945589020,
+    9705793958617646545,
+    1823629616067656992,
+    1518424810210625859,
+    3733605152026891016,
+    2454695197181853521,
+    1709610949948714670,
+    2052110158846978084,
+    6882608186621961739,
+    25674749507286801,
+    2885417189338583644,
+    9280912321023054898,
+    2474527826570625606,
+    15350404716417303935,
+    1231541331051299217,
+    12722606439030216454,
+    5774138659157336489,
+    2887490328836483965,
+    905149222390991966,
+    7207604675092060714,
+    17715688305979393141,
+    2013108178355014204,
+    229472189869951947,
+    5925447727379444061,
+    2472404161307045281,
+    7550088078436570114,
+    2648816890840102726,
+    16100395049653665522,
+    13728713848869300333,
+    12433651213739156512,
+    632437633814819401,
+    1627825122847105978,
+    17658797294103406729,
+    11884510968438992911,
+    1073703564744382050,
+    6186772885443181804,
+    9377538229173234912,
+    15361756374575485899,
+    811943003691330812,
+    5520702647313530784,
+    5509229977057485981,
+    4337383306690857976,
+    3342685106525269560,
+    3878015949440765670,
+    9816860366846763086,
+    14597898425662966998,
+    3051015742570175694,
+    12147124668409185783,
+    8261948288401737120,
+    8330739199403694445,
+    1230820183293081511,
+    624728906042481882,
+    12559858156210439560,
+    17623493987822369957,
+    1924609268996140848,
+    16429050766934975073,
+    15571946196172855219,
+    18228374260764004055,
+    665302099388764990,
+    936425946368142575,
+    6837278587722726846,
+    16664503159867372212,
+    3064267844669379786,
+    9325422638969327734,
+    2058624602128467781,
+    15974155774994597733,
+    2375762048735342884,
+    4990747936350349414,
+    10493714817571325230,
+    15981158396271421288,
+    1249110884169610274,
+    8265404855792388807,
+    10694151966178699143,
+    580038268225538958,
+    2943108603515460090,
+    11133846472231757095,
+    18270880594112280728,
+    477750131813870342,
+    3295556310491186920,
+    7073183632945290632,
+    14976494157627341352,
+    7502118171919074198,
+    3049952863532357321,
+    8940099810661017786,
+    3138629755288062072,
+    11430358992221140108,
+    2953047402432388001,
+    16374856432254503794,
+    11421018886122655207,
+    4614528529472128698,
+    2245490814553291901,
+    5790789713841317471,
+    18132067971566500721,
+    14379886869449310961,
+    1355096582592652727,
+    17116167052215284710,
+    4455191997136468304,
+    1847508989967984292,
+    294523032552706414,
+    3888270392787870802,
+    15952692155196452292,
+    13328534817545541908,
+    2538738807336071860,
+    17252123156703394522,
+    14794211882930055552,
+    12864932421974104147,
+    2901493607620311357,
+    11836396686111683905,
+    1988117018260717862,
+    16655899964736024907,
+    2404343943152311917,
+    12515763804265929726,
+    3369563591912100288,
+    2630321011054426406,
+    1451967171668040222,
+    13934447145012002116,
+    4192011801965633922,
+    18402593375758474417,
+    535028362842886293,
+    8514051605595910287,
+    15459420232061154666,
+    10432902440568040725,
+    1772969333432269090,
+    11198851574020792648,
+    2098518356915068623,
+    6733047333600791386,
+    2885403416697977144,
+    1995555533209774305,
+    14971505533604331244,
+    7120866983242938843,
+    865931078854208815,
+    12450836658045861834,
+    13766725683182458573,
+    14280996888185146121,
+    763296328066009174,
+    16623720352836478031,
+    8652402367163506627,
+    8353967165121532388,
+    529795979611385312,
+    9874379666200857365,
+    735356904812727111,
+    11914835551726893905,
+    27447737189237579,
+    13781579276506293743,
+    5736319628243954312,
+    14917334846020597031,
+    1942437198898761775,
+    6871601661652334460,
+    4094301654009522707,
+    5782807993390256705,
+    90296356763503373,
+    8294904484138681319,
+    16294784996051271451,
+    14402519967621361065,
+    2610541139666092691,
+    18375147496368069079,
+    14553824061212964970,
+    18104531371631038891,
+    1083110755714829196,
+    7226276478158035340,
+    12940468171163902105,
+    1355200596674638371,
+    1343428889549466274,
+    6373930814993450636,
+    17118206681085488773,
+    3174687111159729044,
+    1870880104524731897,
+    17297087818639126608,
+    9369067956790109426,
+    4451853100946117217,
+    1360796094004736203,
+    7343011746314754548,
+    15257518301743476114,
+    5108067745191662062,
+    598764750052922678,
+    16878861540831861678,
+    15324491686342226202,
+    11277733779354329599,
+    3409400288571208749,
+    11795658050265639679,
+    6003359367380320163,
+    1112030224953668841,
+    2663994506614054303,
+    11266107828715849873,
+    4346094095604431797,
+    15114206701078224448,
+    3163764733030385754,
+    12237274048613736465,
+    11828415067033228014,
+    2376455165392643214,
+    1523088670509579702,
+    11756020517122017357,
+    14992451128430087200,
+    891761124548100852,
+    315590959978889176,
+    17495420620251893806,
+    10194164714825765198,
+    4042104776910908735,
+    1383538996501953635,
+    11190519891353755283,
+    9133869873870124633,
+    18331400610757428508,
+    30155243770950960,
+    5486644444642430050,
+    12167734269083115959,
+    5938392757826079401,
+    672955855221247614,
+    17262534285439369905,
+    12229892365091982279,
+    4835789555145525431,
+    2157826617209993511,
+    15682646237687595983,
+    596655031577150213,
+    6440075717737767498,
+    3229944352323972611,
+    2023537681463316884,
+    14236574506071109925,
+    537350462736071589,
+    2746352662362128190,
+    12046931425863674807,
+    2271205332332354286,
+    18302923664109851187,
+    116576433532917146,
+    10335738936110921833,
+    1331606612882842579,
+    10172981307623520861,
+    3460145428641894564,
+    4691579033288677462,
+    15057615338177608468,
+    3636569474632326788,
+    2685212601317825556,
+    14052284881284194685,
+    9166506176285886418,
+    921961548114817478,
+    2049656301448195740,
+    17088248865021928924,
+    57813480942153102,
+    10359773025157390395,
+    3284967035965108707,
+    4376875821323300866,
+    14642225555653391361,
+    3941529603183518166,
+    1881827204037180215,
+    10312434984190155677,
+    15918296235466630952,
+    15971225303030589344,
+    1205715568298922767,
+    11162749423358419892,
+    7280544666620265611,
+    10081342650597108503,
+    761230959880150929,
+    113282114808188552,
+    4892522192316457462,
+    5831822102573063473,
+    429665262072835891,
+    11315234266408474515,
+    2688297650858808458,
+    6288878327002212757,
+    2650577619947904662,
+    3027355731535834371,
+    9455629139045236738,
+    10368253180193685727,
+    1888678310982192190,
+    483818930277791829,
+    4640821706949380410,
+    16910644184461769340,
+    2781761464756192419,
+    16130194224380328670,
+    9164026952546702619,
+    2590280198094223186,
+    800415922295372560,
+    3763093288594844223,
+    4410867036317533872,
+    11891285607165701302,
+    1315570389737611785,
+    14944022188023301649,
+    12590245435374577586,
+    2714517996608814101,
+    312429919816549497,
+    1976115275812668692,
+    8308889387271073970,
+    3218821469343086722,
+    2099317847724035752,
+    3677375504644312261,
+    10804435679364528690,
+    5030916748815114045,
+    1487877254929417361,
+    7248659462537951358,
+    3181699149523543998,
+    12400202315222611012,
+    2800205685989955245,
+    11396893439315765156,
+    5229586290649173246,
+    5327465484558675049,
+    1158324163387147582,
+    11896795627969034312,
+    2453078407311034544,
+    6737187925748404403,
+    2426070809745402462,
+    15431354049935979781,
+    9170022262219727117,
+    12817414847643619189,
+    2888314792564346024,
+    8404291316630991635,
+    17706326916531160055,
+    15118338185264703196,
+    2098712549569953493,
+    13096786207039593771,
+    11517275311319565685,
+    6661925017683712181,
+    3221840209290958160,
+    4273156877107157497,
+    5760400763909696827,
+    1919741733025057075,
+    2780404453475805808,
+    12992826558392440902,
+    16299285181119818035,
+    6578850464298085017,
+    2719264485587993849,
+    4826591439033967559,
+    14773409427690277299,
+    16506373102172589322,
+    2272889130316412619,
+    8197765697471605179,
+    2662597826684435780,
+    3605582417234547042,
+    3254280737605910182,
+    6010852004293996402,
+    11780506636922730733,
+    7796802656404434274,
+    1220579709228762097,
+    6744475866254298315,
+    9632183869565117824,
+    9263003923815076285,
+    2892126807364236134,
+    6993634104863338320,
+    13449849809875387263,
+    10873601037606535962,
+    2180411150376297566,
+    6655182804268743516,
+    3582751490162103164,
+    1173027547539658046,
+    177332149639736218,
+    4562523700118551247,
+    5989105925811153967,
+    16743789495112284944,
+    1487773267184418705,
+    13586975680214865315,
+    15362404334088871987,
+    18380610764931193874,
+    1374110786440289454,
+    10572273409293750135,
+    51541601146208217,
+    15019344810688023106,
+    1521249292144826111,
+    374507038384772293,
+    1574066566620019007,
+    4976067927445698124,
+    93643118772931230,
+    723969648484576224,
+    5792598339501879754,
+    14928349548869062521,
+    776561159133984701,
+    1668108177022460367,
+    3093778299120587698,
+    12099457328066918573,
+    474355316953219019,
+    9307783558648984418,
+    6732787895181307039,
+    2807769924155799996,
+    2309570046088710957,
+    3503908069565508918,
+    11687140888266031990,
+    3351569246862822261,
+    2323146046419745569,
+    5849616463739821785,
+    3821683653955704250,
+    1108085364956712262,
+    2174928267651466129,
+    13068961935159162251,
+    2094556946281766127,
+    4474492540342541626,
+    1100066934678296276,
+    2405299241816848868,
+    9518051189488447070,
+    6377916445625072515,
+    2841789073139035246,
+    6404640145955495297,
+    10596994340868037071,
+    13579788465311890503,
+    2032568389484757342,
+    17351253961117155598,
+    15111992502016841594,
+    17600898144464438032,
+    3166068871568472404,
+    9497626940815334015,
+    6658513042381917559,
+    9866235289227474657,
+    162436954843529997,
+    10605892975719879052,
+    2002534135913915730,
+    2627371011626766482,
+    41989331977949012,
+    2482343946090723431,
+    16185808305152745373,
+    13690369345806369942,
+    3096935029278781798,
+    6238528507034496717,
+    1052536036675480413,
+    7300230466859063962,
+    1362166999184743226,
+    1891446130701088618,
+    15365663895160294543,
+    2361242390587331059,
+    2964245085679140019,
+    14143566414823267664,
+    8175614194497518136,
+    17909130530596561168,
+    826801935223945917,
+    18252455475318064961,
+    6997110943153557822,
+    14009444608961139873,
+    672864373439962517,
+    10752661191940744685,
+    3941870103242791519,
+    12038977632762061228,
+    372631180962482661,
+    8249386205567554267,
+    793295872053024779,
+    269738377867501284,
+    2503585395867928619,
+    15465693701686971852,
+    13293458530241306914,
+    7803862694156748411,
+    2388886463042540909,
+    13279542729176177174,
+    5242567659352333556,
+    16066633312409866131,
+    1234984741211368092,
+    11941604385146791246,
+    3099856323153581822,
+    7768929370931031007,
+    2593278302179479821,
+    17370557581144580992,
+    14141892909600930030,
+    17403695378258054538,
+    1606355966095187118,
+    7336592422808704061,
+    4798627270486060192,
+    14840075496395956451,
+    1258620366115663404,
+    5529930617811894269,
+    15590164429142997259,
+    4187774063662610307,
+    589134804253757494,
+    16767323532981813647,
+    13109719066361406408,
+    12669605095530723259,
+    1525278739708467996,
+    10622064457955967109,
+    18138911859305090346,
+    14653779944470927064,
+    2210854742715462481,
+    17517868672619481397,
+    9138996728076558945,
+    9436079362369861428,
+    521745563643978190,
+    11717922079082555696,
+    14793015687315664746,
+    8517821807971632316,
+    3274411385069208124,
+    11863174352811164443,
+    18348241887662291411,
+    13299185453234877172,
+    272415932572393233,
+    7012499508231046539,
+    7354607446509364054,
+    16041062351648780900,
+    1724106550754181038,
+    15272480860052162189,
+    2355060637187178812,
+    10332542352311042585,
+    1260243703945431042,
+    15766132990416611133,
+    7574684217112395197,
+    8151875529485218161,
+    1270310398244895958,
+    5607959553438574136,
+    345967862953337625,
+    12773954016278849731,
+    425512376081504237,
+    7018587830071482508,
+    10622421947989920198,
+    7719694389959010555,
+    1226942741477569426,
+    18425246166772880465,
+    13338304180520315074,
+    14400089280850920869,
+    1010830099669565207,
+    11619829572699216665,
+    1983321354909147685,
+    14413648090632360834,
+    2013166347048657024,
+    11252794341993841097,
+    1541467393258243692,
+    6422691879214632263,
+    102414271385481466,
+    7944178775795358194,
+    10785984360186548430,
+    5509400922557683967,
+    467277209069715094,
+    10932041749401117730,
+    7365812402248650775,
+    3778099882651920370,
+    2183984955464898052,
+    14170498371644741176,
+    3025536609161840630,
+    3236885191195081785,
+    2683345835746608036,
+    16653804242456463023,
+    588684848306249618,
+    1086895030140362540,
+    949841295080473083,
+    1525046915817490971,
+    15098266419548317340,
+    3325256085800932086,
+    899927907831528093,
+    17815069115492232836,
+    5146297702420692732,
+    11892803886133825121,
+    755540284937505816,
+    13784538410381387008,
+    2423626425935442276,
+    2857326677247570916,
+    1630293281710187918,
+    8676249548711245880,
+    18010862322986983428,
+    2639506292813062853,
+    491852142538206260,
+    8297517573152518411,
+    8136913630496073638,
+    223181915130829138,
+    1112361796559237078,
+    14632128118515259417,
+    4080339740362115429,
+    13976536013521942522,
+    410720144939093933,
+    7584779106352137871,
+    6295775281779343404,
+    11410038477313473209,
+    2469485975285130064,
+    753116942285048368,
+    9101436379166764876,
+    13722578116689897722,
+    832971032504527442,
+    15266640246200223770,
+    4734177681479512865,
+    14441091408112093315,
+    1898994490270340390,
+    5335643743793132244,
+    5772753839122751374,
+    11959716719743611726,
+    331074816692999014,
+    17999977925323626539,
+    18337120167102177365,
+    6003056045076722872,
+    1424344459184636116,
+    10991806311319869434,
+    8353151645324565361,
+    10019520246613535181,
+    1357916315947387998,
+    13735589388519269287,
+    15103390393385615998,
+    9249331608255496510,
+    739310881538309998,
+    3594195298961010999,
+    3907878909019782140,
+    4160620629930349200,
+    2166102675449166828,
+    4530110101022336968,
+    1962737197598885240,
+    5740069710834558020,
+    1693762045663881818,
+    14462370683297036158,
+    7586204610932220398,
+    13177979056702062990,
+    3126100298272152970,
+    10693250358852481676,
+    4199900059251242640,
+    11586032294793617374,
+    1734300060855025513,
+    10093514281087268480,
+    1885627026262280061,
+    1386380834155022424,
+    673483519553393613,
+    541620779783396089,
+    3303646694834293142,
+    11534207253937023447,
+    1614433911234730509,
+    18076480314820762084,
+    11733293583146269746,
+    11220804969764080561,
+    232225957475939640,
+    8725369342310836673,
+    17401649564440535475,
+    6887750961164353736,
+    2968251806526566117,
+    2073372504913592449,
+    11644929812155881076,
+    3682274963179795849,
+    1955434105220560216,
+    15120116240889526751,
+    14396124483853697313,
+    18063414379752004430,
+    3198687416530330191,
+    9451006861714625613,
+    15979215581995971739,
+    8107778649190154594,
+    1406643032327581176,
+    18293178138041352934,
+    10740497096737210358,
+    4183752050058534013,
+    2268006197602988249,
+    5474686871262045459,
+    17884723406774783557,
+    3021300622631567751,
+    2471305062304722446,
+    16385734420222821889,
+    15802504823563027800,
+    9844221065123385004,
+    1411152907601025237,
+    3192071696221708904,
+    7337110502888543674,
+    16482314538229981945,
+    440089333564913562,
+    13924585503628282561,
+    12292839474395748603,
+    8833938873952686430,
+    660175369185123152,
+    15352751515252848697,
+    3655004816603139126,
+    16033814554139958459,
+    566714680137512607,
+    9681278004202843946,
+    358410542256740118,
+    10420064549065927747,
+    1780722572110615797,
+    10502337923868070673,
+    14945288662134962969,
+    7292215435917912346,
+    842892273020191660,
+    17945727890213537516,
+    4077560186411722002,
+    16413917940650100655,
+    2964133597582726396,
+    6618176497444657522,
+    3501346742996013669,
+    471993658032561550,
+    166647172054324920,
+    16436549929267904813,
+    13949967603643729896,
+    7846034801986440659,
+    736767904835725564,
+    12218346390272190348,
+    14954082841386486775,
+    9087931534942174701,
+    851567897837697209,
+    7523187082887706927,
+    12081175113001475718,
+    376354193376044405,
+    1841189466741357575,
+    146219055939310626,
+    9283771104781047017,
+    9035854866262527146,
+    2608465710905979986,
+    528630504540479440,
+    6223383664196861163,
+    4753583977613867468,
+    1543862193397974283,
+    13416984183808457016,
+    2342010200977322529,
+    14666116847834378242,
+    1052196476150163606,
+    8069809144882071483,
+    9578575499861146708,
+    14471090545828376976,
+    379700039404729111,
+    13685842961452718349,
+    6487304853671450048,
+    5746879938005555745,
+    2159214326454921471,
+    16847052070275860339,
+    4548962701353015061,
+    17606147623189427001,
+    3062824010701924271,
+    10007948722246125067,
+    6963938707357197165,
+    11601750491148662313,
+    3130546652163478922,
+    10796900009379809984,
+    4780247556861590732,
+    10730452248883599757,
+    97822148035934246,
+    14178883356561104331,
+    14512641754524478089,
+    9778188182120216063,
+    55051010785629913,
+    15119599953223050185,
+    2449333691938150178,
+    7926915134727893495,
+    1171147864015610173,
+    9545980240616681431,
+    3472889442385662689,
+    18396635017968549024,
+    2156291401205063169,
+    4290959338045334883,
+    12105797580618151138,
+    3776207335226052856,
+    2457264967230158533,
+    2133938737036180734,
+    16801311250103211223,
+    1098470302404983991,
+    3480693046281120913,
+    16580911187885781870,
+    666249826776735589,
+    12739689442098918769,
+    3178163564507751969,
+    16232669402195977369,
+    9578587211016071021,
+    1481819311634342468,
+    1790687839395433827,
+    7820938451012511290,
+    13952167264943260566,
+    8251782428249611306,
+    1106244621251368026,
+    6596670719769370248,
+    10923195193769051274,
+    5567344768127513060,
+    82741473542901833,
+    13591340510727313072,
+    17542351651459209540,
+    7333266239235213960,
+    1856263566232813552,
+    5382642130373215130,
+    11831608370001398495,
+    15824858301611332196,
+    870278438326449483,
+    5994989514863430960,
+    1702940672217163257,
+    14178044429131174403,
+    990837706542892799,
+    17315644538324586332,
+    4304242772621294741,
+    6121074843838498244,
+    3249022303209407182,
+    16553971285219539214,
+    11940676927048244064,
+    10461909365514076757,
+    1952501825422214588,
+    14498134707329163414,
+    14041898727765956734,
+    10869751541135326475,
+    2624697276980625041,
+    8118465621265205854,
+    14076787505449401426,
+    10332260374622528340,
+    3339814027295483433,
+    15499191748231222074,
+    5218461198622833452,
+    10393413240632594645,
+    1253760003344417137,
+    11897824667302855972,
+    12372810364572834971,
+    8574110160433295513,
+    803508536351569766,
+    6194650752638531531,
+    2432273631905291104,
+    6629856196754054797,
+    1486693770271922323,
+    13225215775306102470,
+    15150169366369568705,
+    14252110420002459558,
+    1913183314363387666,
+    171361661709034440,
+    18062250959085418816,
+    8239785806727736749,
+    1392290953915004043,
+    9620491335394809966,
+    10887057006835890967,
+    13382548933372601020,
+    1785201356172285596,
+    796683908979979638,
+    837320729313050947,
+    7913731273731408004,
+    3231202358082122906,
+    10219710564006283596,
+    6959274692471459947,
+    6727776194000640118,
+    194787098667150108,
+    15944641030391835753,
+    6549532339310883938,
+    11653387551260267832,
+    1018311164496039586,
+    1322966783229747054,
+    1605750969610645170,
+    15852550978369409418,
+    3421298237018287703,
+    28979865691767321,
+    7692753177449761315,
+    15475405223432750850,
+    332388074740571237,
+    4081895198556681990,
+    9659944018836552695,
+    16588316809268477943,
+    1565154360181302557,
+    5461518995160788615,
+    3381346077490879633,
+    3632906421826987248,
+    2891022956806585923,
+    12798687684052671888,
+    17309354337857949216,
+    8237067193182687723,
+    3464119590758530671,
+    5868785373523563698,
+    7238630402909735614,
+    1641867104879573289,
+    1878404186913141661,
+    10764819395266447498,
+    16574643244028462559,
+    8752431183823242432,
+    1438267908188770333,
+    4062230297927065439,
+    9944967202049170754,
+    1253038558081943954,
+    304052282338856371,
+    4621606069881742386,
+    3904579412499736879,
+    11199691549679047824,
+    3402356446781617487,
+    14238264556485977200,
+    7123165032185535364,
+    13560895725716191775,
+    1633285706842036609,
+    13341308961535542792,
+    12920335735336600731,
+    9155662367622349234,
+    2664794391092041947,
+    14599560554011643906,
+    462994222413535302,
+    4132075764846901800,
+    2662447405851609759,
+    12058558658350825407,
+    1968810665363729094,
+    13069499113525958558,
+    306375771772899400,
+    6733627609694213526,
+    12591290588293600867,
+    11736366915294086011,
+    2558754527115767258,
+    9198439929677370847,
+    3220804540013006599,
+    9490455642897563356,
+    651915413925403913,
+    7951251340941172461,
+    11684475858967290679,
+    16911256810045659206,
+    2654921968188486461,
+    7954131594063309840,
+    11682295177465770256,
+    2866983625737925823,
+    2634031369687644506,
+    7416959025301885697,
+    9891831515725281754,
+    6311677380908580326,
+    1349893393465814947,
+    11548660212040366408,
+    2410841509709160138,
+    6583282139334645204,
+    482535534644342932,
+    13194773159729080351,
+    16127338272779747438,
+    3657295363394292149,
+    3120035100431077630,
+    3003803228610397468,
+    6988895247551636423,
+    7017113457988915977,
+    1352982250490148636,
+    6727347405480766509,
+    6738623349492851590,
+    10720436035639893922,
+    844969770093639755,
+    719838128841736717,
+    1824736783288371892,
+    7712384067664090239,
+    1486386423084717622,
+    1484951344437315737,
+    15934724044658596799,
+    17562786472330010213,
+    191959644844384333,
+    2495814350044403981,
+    17845638854133842461,
+    17102056173940078291,
+    438341640915789643,
+    13953670816433794487,
+    15843502166091509453,
+    5373160588504975433,
+    49038167343908407,
+    1381267804481256574,
+    4178528806508090732,
+    7089960094196474911,
+    1572338347898569855,
+    13309586936602587221,
+    984536675655675747,
+    15581203503534060289,
+    1839637837292084177,
+    11841337873779554432,
+    10173835218799611760,
+    2060600371039312914,
+    3081207704059894315,
+    9619247885241603183,
+    18236188852911662778,
+    17401055180196271237,
+    260562441608385094,
+    1342464161075240558,
+    15382546441739312259,
+    10966491317627284353,
+    1938422098904829348,
+    15830513715310508102,
+    7805926212113691131,
+    8897031430328829248,
+    491995181201392198,
+    13493666901314031283,
+    6525496665775287515,
+    11097772205759579449,
+    767090461606971195,
+    6527299785364368266,
+    11419565560789646440,
+    11715034430582833297,
+    2958311961508992902,
+    6502912061946156088,
+    17036871283005777283,
+    2835967683620169121,
+    3138947569757439973,
+    8301471817309244436,
+    18021923970032512863,
+    4111341897432808953,
+    2587485250505637325,
+    433933414205343480,
+    17924644600468380977,
+    18149748962525867129,
+    1275141217356268030,
+    10524001780450808956,
+    6593578565956979123,
+    17707787014681840260,
+    2096368578093141051,
+    16634487647200309209,
+    18411961211434025785,
+    2919310369533075089,
+    19895957122867424,
+    16704658292302559898,
+    4090626527383688664,
+    1470487958493329989,
+    388777911315941717,
+    8970980685942342466,
+    13773560456017381687,
+    6714880292595186030,
+    2140671659180141809,
+    5505151277334667429,
+    18331611334916064855,
+    8004369919620699427,
+    470198511015788822,
+    18037977552528043419,
+    10185477192276462978,
+    6880258034188249254,
+    1529688495100115906,
+    1001088540966898109,
+    13131972005524185386,
+    4466990964617822505,
+    2458599694254216198,
+    1380692374397786980,
+    17362960347898675539,
+    7113775595277209375,
+    696561703534376275,
+    18151782441249884212,
+    331482732837599711,
+    6541512216210083261,
+    87381550704695053,
+    11245852598442799441,
+    1821992395986690462,
+    14785512394581983829,
+    3177044554910919247,
+    13576276690278901040,
+    15668059864126080198,
+    4117521207351214548,
+    627747279675250256,
+    1215284940212410160,
+    18435058026667120813,
+    7839222451916802835,
+    882181212431186297,
+    3814232638811891645,
+    12901449280301264020,
+    5338252506537693575,
+    612061944238846405,
+    4186460170054167326,
+    10763716582937953528,
+    13857678955016487807,
+    1673438701471132220,
+    5152863923714612817,
+    6077178353954812974,
+    323705690656004085,
+    491573034152786607,
+    2483615298647965645,
+    126079801132657906,
+    3484326506348554301,
+    2168959773156151508,
+    1122648409011770896,
+    5273092922733454085,
+    6668902005037128300,
+    7409836748197527,
+    11540376578250722100,
+    17927116209983905818,
+    11192249260089008745,
+    924316660755355142,
+    3495660890476437638,
+    10550187646794827340,
+    17706327919204094389,
+    2026253199575417658,
+    4281478217774646908,
+    13453637142508230736,
+    6875625440896733804,
+    260080193834594576,
+    11658948532419587008,
+    13721058770026899077,
+    1680231416186379010,
+    1676978780205032639,
+    7404433703701983647,
+    16295616211313820948,
+    13930312507438120524,
+    1840225186445655318,
+    1703223353398760670,
+    3476301915146505315,
+    15904811160636382974,
+    636318632791304010,
+    5781009240553621828,
+    11752774444935459681,
+    10978824579518707113,
+    2984381740598643591,
+    10458977773945678705,
+    3770370125908327140,
+    4233394300270716249,
+    56026472995236256,
+    2561377012728343201,
+    2040614370697659966,
+    8168367993251641095,
+    2078250078965803051,
+    1012786275939701753,
+    14799925645601991656,
+    12600775559872053075,
+    3138767536175818036,
+    1199099733475481747,
+    16969332672517391091,
+    4941861177242828558,
+    463371637798741283,
+    2593025208069720788,
+    4284821223187957711,
+    9603248910571732272,
+    1802161314676796062,
+    14741009751896636127,
+    12344883639543517217,
+    10143948370213978804,
+    1828716289241368281,
+    802976092203742405,
+    12923149677018251719,
+    12632150313156339828,
+    75402048186740200,
+    6332966050408067553,
+    17739059593306887461,
+    9827229899514770397,
+    1036713775910771568,
+    5610924358790935378,
+    11880104416179273418,
+    4303748448249103952,
+    1179265949742815668,
+    15851801197861567787,
+    5810097869692510823,
+    11439703910843438852,
+    3295568389312737211,
+    12258207551196001526,
+    13600953044055411001,
+    9005667739979963160,
+    1484894726801385088,
+    15505972252351285567,
+    13641298463367495553,
+    18221954701097143040,
+    1993746252360631401,
+    16260978462002651739,
+    13101333663623427825,
+    4101380472783888948,
+    2432298722647173145,
+    16293991054644991508,
+    7277026840689325690,
+    11897345225433216780,
+    2508511649213649342,
+    4649864735382161705,
+    11228754349785615967,
+    10533777751488480212,
+    406213710403100053,
+    17948396299796298133,
+    7627940497827957427,
+    13715097701986651885,
+    1570567426325652481,
+    5863181841458006846,
+    5615354958407950498,
+    9319384339329900871,
+    3231400500751382375,
+    5110271610559501867,
+    11275060883582789167,
+    14260754799547752689,
+    2292892473220084888,
+    16208260316945319945,
+    16408859171421000334,
+    17665990159890451659,
+    1226320919080078896,
+    11084600565487743434,
+    7325013035026495809,
+    9452628254788176029,
+    3191965918126049394,
+    9095918979548324910,
+    11650001616949511812,
+    8860060508598530870,
+    2239175522749292023,
+    5919620855606806684,
+    14467689768260348463,
+    2316183728200349489,
+    2988729318546200470,
+    6997095259383340544,
+    12166231343154446103,
+    4090604122691329344,
+    890543304787145787,
+    6984434546614375381,
+    5685524558803585702,
+    9960077028202074055,
+    2354306018069604296,
+    14280828646667279496,
+    5211306961233110284,
+    5829288119820939961,
+    445796321423711871,
+    9393660965460564017,
+    10062341794409298663,
+    17723392029826029778,
+    3391640318857052214,
+    15400814562134624153,
+    17833101072753438393,
+    5606128868794799306,
+    408978386695624221,
+    3334848622783274876,
+    4014854166918226281,
+    10958357309307850651,
+    681941506309086112,
+    2814171444201163317,
+    2473748655241255977,
+    15400002499928399702,
+    2392044890266402586,
+    1045917991097656140,
+    11217907421370330251,
+    12844330232515932375,
+    1337494724902576245,
+    15672826467230381832,
+    15557180229954756554,
+    14200589202887310466,
+    3093187511124966376,
+    14697205006583355192,
+    4111772552216522961,
+    5849423502078269436,
+    2752055048584538674,
+    8327390004727946692,
+    7351484754742265395,
+    13892205602153930268,
+    266933601372406620,
+    4941905243867340947,
+    8521510572328871218,
+    8505858726842788953,
+    3312196041941703835,
+    826124063399909587,
+    9439812332571501608,
+    16036155505413321262,
+    1656623515619373366,
+    17484984016399430480,
+    9621667467372775772,
+    4109180461427196818,
+    802365890193853208,
+    11421183181343680672,
+    12151885587008531644,
+    13696634780970051520,
+    3183444695375685037,
+    7869048472423945309,
+    14738838646679761066,
+    1910081837198364485,
+    3351616587541125427,
+    2158664870024219902,
+    13640883236524694451,
+    1433066058439451700,
+    2783083652735233490,
+    399068877569938746,
+    9897254020782026197,
+    4916258679511076599,
+    3061190851971188399,
+    7279046863130141189,
+    3107665035991767856,
+    608178408841008502,
+    1772225130292987887,
+    17860082369038506561,
+    13384742937505916978,
+    6724171535922414140,
+    3413379464031856128,
+    16166373656374702630,
+    790445010439288016,
+    13542652421744868313,
+    2923744458654903271,
+    16182543232441222695,
+    582156465249469915,
+    15797629452179866915,
+    1310161421322412948,
+    4598325850153771464,
+    8149348859657939013,
+    16146496067864457751,
+    834716600822513239,
+    7949372147835623612,
+    17635078714694368151,
+    7929550485076157436,
+    369478067195594186,
+    12273924462900521000,
+    11988657595786401030,
+    14646778969673245500,
+    2090060018792136179,
+    11158835818344895730,
+    14288750819012659820,
+    2111275200467196728,
+    1693044212678483315,
+    6816010267407851162,
+    3692180521733797717,
+    7098700898267488043,
+    3185498616386591217,
+    2553782635134173644,
+    12175276694875759280,
+    6133888291772307567,
+    1576864886189072842,
+    3879013005517374841,
+    8638410338086886388,
+    11816277223812464103,
+    212918523824947320,
+    13926981882193960505,
+    4268049874177606133,
+    10992245103240339408,
+    2487072807461292516,
+    423586491067639212,
+    13135864578090706847,
+    15936908203437647592,
+    1908890526325519652,
+    10616995291969456507,
+    16876427893506553282,
+    17754737241960796720,
+    220548364964849323,
+    11757586693502361666,
+    15839158397800288666,
+    13264092426897034925,
+    748624581743059049,
+    2680649382253947664,
+    15705959420218198413,
+    12658602454116448167,
+    1849056535312021545,
+    16300271338547255620,
+    12923026786718757681,
+    10248998431922050064,
+    1890892149385205726,
+    15596767933938269658,
+    3228398156618259895,
+    7889572751871075209,
+    1079852391696679280,
+    14982875620895968394,
+    213651121433218409,
+    18062709279787419797,
+    3327930268286743506,
+    7985542754580534107,
+    10649251671296988835,
+    15515787959641406866,
+    1270650359713828240,
+    4777345513293488689,
+    12195153425970077589,
+    7140917851392635426,
+    2005731804751087818,
+    7536923473942286742,
+    17938773938749754557,
+    16277276157770648131,
+    2844551948778584368,
+    8424890491725776332,
+    15042405167798120374,
+    16088264765451967022,
+    717374245856450606,
+    3343779606656945494,
+    4973641945117078908,
+    11033322914872501397,
+    114536327972112974,
+    5860445486524016377,
+    4892140427952784639,
+    14919113592206972125,
+    333472811236528553,
+    13242533981951740085,
+    10485059002664792272,
+    15489444539060344055,
+    672643784719267472,
+    2913215259749227613,
+    4226124603211564140,
+    12427346540334071771,
+    3230706494980193380,
+    13934570040866447380,
+    11378792096404184240,
+    17894231884596856583,
+    2613911884624341013,
+    13701561741775379194,
+    17827869315823952059,
+    8691511727726524765,
+    3385730979042837619,
+    14210248632119890278,
+    1659770126811288881,
+    6799729968354907647,
+    1291234938424696278,
+    5678688306811935267,
+    7390733463576914416,
+    948113949572118296,
+    2504458223775290207,
+    15342117606803696245,
+    1947224564662404994,
+    16098962153466365116,
+    445585427895472848,
+    9868359298672158548,
+    3894953087598791958,
+    8415912220295046781,
+    276128867404932142,
+    10343909016180227291,
+    8308231460388222781,
+    2041028750254612587,
+    1829945209562393362,
+    4860204346458319069,
+    10294117095459835887,
+    4030913227926883273,
+    3347234162318786259,
+    9913676189234596817,
+    18048095395745038108,
+    15419153762622054133,
+    164615222969557114,
+    4942580753206266008,
+    16970954006305272390,
+    12387320605406032012,
+    1930808024210891507,
+    11708386404587374251,
+    14748673086099447874,
+    6659860936832243251,
+    2710317848909886544,
+    1514878541364850482,
+    6367237494695109622,
+    8718589584116761429,
+    1833662526977293215,
+    11560897098313629144,
+    8830342721903835150,
+    16924250775069372715,
+    2039132410059302141,
+    17948343636882826395,
+    10202441355010075886,
+    8703360962118380396,
+    918141143746947176,
+    16568623983441916021,
+    17248647503597069585,
+    2256522207033131904,
+    1105999036630076592,
+    2152590126249795551,
+    1381599119723684612,
+    7089529235065048198,
+    621576644889198082,
+    17720134799362270797,
+    4367167484915865608,
+    14051823440201112446,
+    308221887358223660,
+    1203813675387018357,
+    1085820070246345926,
+    227059186588570497,
+    3355991013235461086,
+    9794221896527130657,
+    1774819170606049568,
+    13501693684007884087,
+    2261989562016169559,
+    13527001334464558294,
+    12220529749310093940,
+    18033228182941467204,
+    931266786419776290,
+    13185112551238872984,
+    9118480292293014915,
+    8037649835160204936,
+    2786068414555436377,
+    1748635966579652272,
+    1521043882238504252,
+    55923587288620854,
+    2597065575977956344,
+    6065666103016274148,
+    4994084342591287926,
+    9552705049398761294,
+    2308253067171178683,
+    429453244798160690,
+    14131975226414546590,
+    1012690722689183759,
+    3305360140897356414,
+    16977498557131521202,
+    6614784493792737388,
+    10474461155373686394,
+    3379642597909872002,
+    17866737046816044467,
+    15006698885251644585,
+    9363023821857685188,
+    1047986602511364026,
+    4347092012437538344,
+    10254521888363176832,
+    8190481185033440060,
+    2019843882268377388,
+    13890167637127809404,
+    12057493547189151682,
+    13793358150522586178,
+    3344595129288793309,
+    4819308166669855323,
+    12785595570257987841,
+    10534449587396391083,
+    2816532219125436193,
+    4531391490943835451,
+    437897553703017730,
+    3549841843096605184,
+    1458195892319810779,
+    16797704392952739685,
+    7846389357590436040,
+    4108766618689172464,
+    2021573204595304585,
+    8410974944505583570,
+    2788054796711875553,
+    12918925474991253823,
+    2652404609108818997,
+    6453294999817136916,
+    7357002460213297597,
+    1107467744177408752,
+    1236810430839605574,
+    8266495577244335927,
+    1644308562276157129,
+    9073629247718165109,
+    448932668812256396,
+    7390688384498432990,
+    6741496239206999902,
+    9943541961645800689,
+    126458071014847195,
+    13525371887485798330,
+    13797690542730961944,
+    12179962613742851898,
+    1153391686154193193,
+    2473782315524787720,
+    8392260864088055618,
+    10249990364327237978,
+    1292150626002978246,
+    5955990576058839438,
+    4871018162315929893,
+    5095513731412434443,
+    2779224683721505070,
+    15831659887679299701,
+    9924437104906392299,
+    5450471993900456612,
+    1585685858380235930,
+    9064443143992999063,
+    11551546451328682177,
+    11698477618318186743,
+    1996740528566987447,
+    5098927602906579328,
+    18336823528465842392,
+    112207385678511813,
+    261628430725595964,
+    16731427324209147552,
+    1303852812543824500,
+    1431687116364337300,
+    2829563787760996494,
+    5106573438737351386,
+    15334001478866289358,
+    11140930191111616896,
+    99307311989234441,
+    10903740927861917969,
+    15022770787516546760,
+    6138810514839491038,
+    1041163370463012707,
+    5501302966236211956,
+    1210567481029941985,
+    3233949926144749121,
+    2751848920851254628,
+    3269809687591411806,
+    5287038071436981078,
+    4765561481246147662,
+    3080658283964205178,
+    5529302202010703975,
+    6172970108836459389,
+    16923916305731846932,
+    2261602118487051111,
+    2676710934943073632,
+    16303882002184241814,
+    16874481147549577427,
+    2251160405034251465,
+    13759448794090421319,
+    11069606511254901458,
+    4489138684024806079,
+    2920498256729868638,
+    10625681418681149406,
+    6489472747239936496,
+    2675056201500243693,
+    261019607543446839,
+    13101832691898993578,
+    575752262148830563,
+    14600409528657784861,
+    1836727906265705436,
+    13330516986765259692,
+    17796839124284046410,
+    932966236459849798,
+    1347373193752797694,
+    11321737794717854411,
+    6071689091550453536,
+    6254291069415456306,
+    2601707911263406611,
+    12001257522843275813,
+    15887756135607894356,
+    6205661984067651211,
+    2279587117428613243,
+    12181123299938902610,
+    13690696236472518329,
+    14498868994535355740,
+    49723621290787418,
+    16867708679911100599,
+    8769116828894569784,
+    5916882380121370343,
+    2173946283544017193,
+    11411675594206247997,
+    11307161700813466820,
+    13056812731615830038,
+    2832587499520534595,
+    17576390397401419594,
+    15515375228364414878,
+    5200453991121618670,
+    663806806158259521,
+    12006406909369078967,
+    9444397877605982880,
+    9031433237599815926,
+    3176346705401873314,
+    2770093874821836035,
+    16477128052375156020,
+    11990496012517497913,
+    3119727944457806217,
+    11744204046162540068,
+    10314202774796687305,
+    8152848640163177779,
+    3041413023075191243,
+    15759754992591167856,
+    10327402283336694383,
+    5407539435367013273,
+    1714878849176540360,
+    10833718210786491390,
+    11850412137432796731,
+    17273058285810639758,
+    209533407027894184,
+    2289046259740611611,
+    6388024148647079724,
+    10201423472097345593,
+    1996890162670490866,
+    383930924576668787,
+    14997141744659018050,
+    11336941149390193857,
+    1620537144342804514,
+    10757617283770137533,
+    6267222871114981243,
+    5199642048571696453,
+    127465382540050580,
+    17971203181582101229,
+    15327763749827301252,
+    12545707301578167678,
+    1308214937072789296,
+    5753247597665113252,
+    7049265856276789430,
+    12841995715912514756,
+    1480744500449937164,
+    15216466597257049079,
+    11528424539650363322,
+    9798890284665598398,
+    221907126064606939,
+    17797790301267362015,
+    4512561210143743299,
+    14143439623339648761,
+    1124289691894936546,
+    12414971071890662957,
+    14731193343233517037,
+    12578017531138385963,
+    2412562966378473600,
+    11871765291632810613,
+    7920745240186981702,
+    9116868013177002682,
+    2762501026830715661,
+    16800119140148606188,
+    8566184253887337970,
+    15163037720205080271,
+    2215857437580029260,
+    10207938625560183898,
+    14505252411375845062,
+    742365214468933500,
+    664193963259193049,
+    8853198323755455601,
+    17283533040984464909,
+    16179947578822760538,
+    2954685967405406772,
+    4410094843199292060,
+    3738227035439039261,
+    10702803328407990997,
+    1425014179177436767,
+    16254826719795138616,
+    9621815017331311479,
+    324134143595184985,
+    1973739043287262323,
+    6406056954885483425,
+    16761021726324727548,
+    4133652082810240438,
+    2527476263174402614,
+    6731717123960774119,
+    8685064454796176865,
+    12025063784274412262,
+    450114043268046324,
+    6699604438039263920,
+    2270119134639242209,
+    15808929997262437676,
+    596050858729783787,
+    9945770418737269223,
+    151391085704605898,
+    8356883281108465053,
+    683577792561104239,
+    339316417320671372,
+    6210823512100529274,
+    5499734894938813403,
+    2371765145317259412,
+    11685542554289705789,
+    2046368507354170594,
+    3980812308955296050,
+    359070546739315945,
+    6830534419638130836,
+    11906579232602960054,
+    9806832578447389843,
+    730075331196838548,
+    4686465225623532299,
+    14733380066497569162,
+    8846373051345210422,
+    1717704326116347340,
+    3419990060125224048,
+    2582597808490318915,
+    599852148778761882,
+    2781297668529102354,
+    521482330827492435,
+    13041193985891179213,
+    17602486181059325173,
+    396546655508635333,
+    15454743122009404319,
+    2314076275150223358,
+    10938426235379020057,
+    525149507454344076,
+    2046297572022357405,
+    8491282082304640780,
+    4013334600673081439,
+    2627897114708360923,
+    15445998448313125456,
+    1580245461181857086,
+    778273518592322279,
+    3378598719624576737,
+    3320338602496980132,
+    7704552878513977965,
+    18424655654403679954,
+    1214660266135812744,
+    13422351788741187367,
+    16688413687598783912,
+    2149643237497122323,
+    212076165900321799,
+    177528213684612921,
+    3942777517263647987,
+    6348720641022808128,
+    22760353196116585,
+    13049829834814367833,
+    18034220389906654068,
+    16801539872450273224,
+    1625744620561845681,
+    15839255590463004640,
+    672266679154973213,
+    11639209435605602714,
+    1038247718364024865,
+    15565958624996592133,
+    1997331380735962119,
+    8865513906657548297,
+    2683645402424212122,
+    8627741741433374074,
+    4426725958590614672,
+    13308360799864058083,
+    1232030947526466563,
+    9103680491169989611,
+    13138177466045712435,
+    9731538185051568223,
+    775259228463826557,
+    654097677489878434,
+    8298201926641894777,
+    12015058482556760837,
+    2811213709108682870,
+    17718860632335655457,
+    985963609888045539,
+    1305640918069608433,
+    1531671199523842691,
+    6380377805235819184,
+    8773110652126256684,
+    9465340621264440919,
+    308289750391045535,
+    1672713485096198696,
+    3777004980183545969,
+    7466157795702140996,
+    2692124695197130758,
+    13607431394543866232,
+    6154177661957870257,
+    5522915017110145107,
+    1880080925548680131,
+    11654349564796967986,
+    14245003814081592757,
+    6868086703328089671,
+    1356844003939655467,
+    2234179961094528242,
+    9228100386662147963,
+    9797324510683467992,
+    3133062409789589863,
+    7507431499461128226,
+    11523079351610335850,
+    523342235808789920,
+    355124795193496170,
+    9299086485494931221,
+    2874338141197145116,
+    156199964650785511,
+    702737584140438415,
+    17066958327281526370,
+    6080943978281779114,
+    4856450594295425128,
+    1030222310261516348,
+    6861951872146184110,
+    16058723113952992178,
+    159569880653039238,
+    140922885709279833,
+    10147151082184062035,
+    14014388848485942084,
+    10607606210545166627,
+    1101257519545831008,
+    11611760637130416536,
+    6850328413181057026,
+    17158821702859635686,
+    3217522727380663535,
+    17189943536480165329,
+    1984655701792693097,
+    15470787985822201558,
+    1737920649016889396,
+    10715036106638406482,
+    5570185919869327869,
+    90904752774873976,
+    1300926860735323686,
+    12099119928149539615,
+    16032208957978033962,
+    9475962059645583032,
+    3012131400945840822,
+    15491312834043559483,
+    3686945982894067978,
+    5894928664575795665,
+    2887636970783644272,
+    2392759818022836721,
+    15670115402135339340,
+    14354673268710091751,
+    3097589004370505380,
+    11338606499346432240,
+    4542025499774661586,
+    3468771858789192451,
+    813988902063726370,
+    1517911273135097962,
+    16292830253690128382,
+    3975595831042366237,
+    1030637708120620738,
+    18038629013145466384,
+    14948406443687977236,
+    5577581414586240479,
+    1680101155573707618,
+    8551539178738246860,
+    12685779335979908070,
+    5520631939047116016,
+    295331914903121532,
+    2429032633422077826,
+    16347218449832753174,
+    10675636492327380752,
+    3231033467742919882,
+    2635736582826295999,
+    15534135515522401002,
+    14273535020352570581,
+    879037658174842352,
+    5956407751814519836,
+    11456219055894127364,
+    4652599275085949893,
+    213364118508090492,
+    8708582386983055732,
+    8157041800545034334,
+    5913939538227280658,
+    2872879419271757,
+    14751743982989378110,
+    563401505552390617,
+    14734139841995992151,
+    52915272506339932,
+    8524057184910217412,
+    7068082981572099348,
+    18441142363227169727,
+    2686129097454617590,
+    1755785029420481080,
+    8714904572935507780,
+    12064862314794209063,
+    929967739585960070,
+    8155069241879369475,
+    11265138093710682878,
+    8055658398857329137,
+    2909946182000260242,
+    10140438841978548792,
+    16060929889263167637,
+    4699209754055535550,
+    1420943085044178765,
+    8617585220658413651,
+    14874764561627601908,
+    1650478993722831072,
+    3138898367635094957,
+    15971096119754621145,
+    8140966912225709250,
+    7856257002771446560,
+    498821432717706434,
+    7095703137336676049,
+    16514100379970021965,
+    10760153886839023176,
+    2539762681838600479,
+    1009263993078671330,
+    3298016592919527372,
+    10932999219912631023,
+    983572932045258205,
+    8357917150102120161,
+    694411301943143906,
+    1615161846061578116,
+    2905970022828543853,
+    4782771781650721805,
+    15096926789513052056,
+    6683695489633566260,
+    1208426588273778349,
+    8951780906885198473,
+    9175283422754136811,
+    4642718844583503577,
+    2636396532456674737,
+    9378290758298889286,
+    5459912588124529553,
+    1781925764930901775,
+    939104705339744841,
+    11421092055992715866,
+    14380825536970701616,
+    11581660687604315787,
+    1939668218816503357,
+    10979611849505467457,
+    7065280703897846943,
+    13082022834036591304,
+    1547213390546290296,
+    12580154407968510085,
+    1080595668178219208,
+    7216849299746955623,
+    2525750126297531783,
+    1233353412336234471,
+    12638391196428556490,
+    2722139332354899868,
+    244409814191584798,
+];
+
+const DELTA: [u64; 2184] = [
+    12703539641744288009,
+    9442755572772080440,
+    15769600108646750736,
+    1650174783330672093,
+    8864677513291823333,
+    4341170159726731582,
+    17469710665351322987,
+    1696247850468505465,
+    2426764076776542,
+    13354659976429571425,
+    9772896496906340999,
+    1672048453779312262,
+    11395264054359681793,
+    17162375462977835410,
+    16537912603708801407,
+    1855299088929199249,
+    15260382661321843418,
+    14598771208025775079,
+    2476563105548198277,
+    1911098612654941891,
+    17151508470044856037,
+    2599200940044258368,
+    7718813228177557034,
+    1962725700137282342,
+    7519518261766404790,
+    1936535805593882014,
+    1262824691677513822,
+    1603362904190739620,
+    9054974880482347552,
+    15070302434442807318,
+    10251754996168985398,
+    660110158892864045,
+    6707087739379014947,
+    9575160416927040519,
+    6364307404686744115,
+    1443055533093714604,
+    11073347302995947321,
+    10902354384752376946,
+    15348822913024559267,
+    167019940911757625,
+    12262129487663724856,
+    7006696098370789138,
+    8029119605552199973,
+    133078402065679630,
+    18058650373406450501,
+    6941194415710602289,
+    1146070997241506908,
+    1882503573657256940,
+    5992590519350095824,
+    15916602043469726620,
+    13401918173470730073,
+    2912948485019237932,
+    12577130003432934882,
+    10533206778739804184,
+    4205305396614849667,
+    2677288386119469363,
+    4920017135908111502,
+    7137311948570720955,
+    2197399414155582040,
+    2114963137512457929,
+    4458630649035576489,
+    16091059411578430222,
+    2845803222409442407,
+    788579127831778111,
+    17304527832111988308,
+    15325497743308825113,
+    13854822458083415127,
+    879037088439085469,
+    2413458400806361535,
+    12718114230528027335,
+    129441470158560401,
+    1543810907792882126,
+    14134355362111288905,
+    7776077909297641833,
+    10671898279497549437,
+    998837132239527597,
+    14132264423404498247,
+    4144368034980372824,
+    6817149377981163723,
+    221872448047291078,
+    9871779074903080983,
+    9046265757766998764,
+    7732562863366386358,
+    2503594747877000541,
+    11040697391264945789,
+    12267412755878811251,
+    2455463794563307592,
+    1374967202434542323,
+    14397638461765866645,
+    13673175267319629378,
+    9785586208917326665,
+    1600414095215281983,
+    10120153071327757149,
+    8649073460678342924,
+    13659126950816318443,
+    173298830564310940,
+    12024936386270187209,
+    3325342749781577202,
+    15543453393549377204,
+    2071422131371409657,
+    17697616765082087562,
+    2103910930060397540,
+    999351362621659586,
+    2934194337820469284,
+    4796337254936788983,
+    16286065553070958599,
+    12575616582003490549,
+    2441703460720585423,
+    12423825229486187806,
+    15242553685005788848,
+    18226938864156264291,
+    3097105983667675130,
+    6858416704862056953,
+    13186886299432933942,
+    18127950360666688418,
+    2065945149773343240,
+    9766783828602135013,
+    8191610481197595225,
+    558486025844295898,
+    2029434682788630747,
+    18377836209271519327,
+    11368046968970441388,
+    466192881428497704,
+    2751191111386760890,
+    8230983248355411493,
+    16604602914420006788,
+    14511996285757600784,
+    1558235943863543111,
+    11691579342382576653,
+    15089092739420733109,
+    2448233498345522629,
+    2917324453436218209,
+    5861586965777090926,
+    5145945594290819874,
+    5369490445050096907,
+    1393491804642790873,
+    15105029861892026990,
+    7197573398780152604,
+    8278236707374359040,
+    1193621667180124807,
+    8080858047804005987,
+    13953789564271764500,
+    5408851022230969826,
+    2063368145152053296,
+    15103899338057973758,
+    4409193852158212112,
+    11115442260337675195,
+    2993931778777445611,
+    170179018609035108,
+    12509660061019128927,
+    12563775024006114398,
+    1600334927044331053,
+    7157660346445146879,
+    9826497514575948583,
+    16288677185127505398,
+    2088802953844828333,
+    8732591629017406373,
+    17866534136575876249,
+    12277700272025187515,
+    2698053810680973943,
+    323055743273537163,
+    2711697393543170083,
+    3720336407364948048,
+    2423586279537787760,
+    6939352644593251636,
+    6946624928981789935,
+    13690037595273296918,
+    694094263559516147,
+    17448845279190673970,
+    11627526333064754513,
+    9251103030791290608,
+    2151136724397845146,
+    11482401304877590547,
+    9027271356688165976,
+    10701437137402460514,
+    2832174401369486169,
+    17961462716281770925,
+    14341295697099006890,
+    6343033603205744046,
+    3142803546463529424,
+    10956390691526652064,
+    18356678484610676052,
+    16265337837987006424,
+    965393080218242477,
+    11136411412506708114,
+    13084346504192514027,
+    17824549834202648353,
+    1570833727146757938,
+    12237064122672582710,
+    13525024324229895666,
+    12242860733175629910,
+    1571132012682061804,
+    16731458171248255842,
+    9337306304565283991,
+    10419220122369481405,
+    604454086044520986,
+    10976122882022874922,
+    7985951691114306127,
+    10385870164514402080,
+    226274641341995309,
+    8240684723670545535,
+    8464178626005713607,
+    4991383133849259365,
+    638041061573481556,
+    12366497069974998218,
+    14669662693060201835,
+    10872010437831996319,
+    3412762006789119267,
+    3921493998149265994,
+    10103515607445452773,
+    17776092215527585607,
+    243191354450234462,
+    15403022099844887324,
+    2238818772457980187,
+    124716127547057941,
+    1265054041080134779,
+    18219534144435845408,
+    9703874752268184036,
+    6464379413687144013,
+    588053505110585412,
+    10414739655476528352,
+    9716989328342166193,
+    9214377063890322533,
+    1212409982767187483,
+    13638090210815442265,
+    10135475015160863691,
+    7738451329429589710,
+    980543945505757950,
+    17410569403249758231,
+    10411783325991443726,
+    8459069908731504658,
+    1007705074844043411,
+    12898179052765848310,
+    17372703875595478477,
+    6553546475161592063,
+    193177418312346715,
+    8924416441203897314,
+    11344898743258295883,
+    16427451642691874283,
+    1019093161756776972,
+    5878561312674658163,
+    11341370053722551568,
+    5853752946855877079,
+    1364792426364038748,
+    3952655650233703117,
+    271497005927834620,
+    7062199270014639555,
+    669309871898822383,
+    13420764526686063272,
+    989105965578641852,
+    123423385152123523,
+    2660875993019576569,
+    16351048838936748657,
+    15506070680864908354,
+    14592029528395288683,
+    86894996911590666,
+    492936403188977488,
+    17587609748635244115,
+    4218450603627073980,
+    1945295238134711716,
+    15808936898871776430,
+    3819935313793014917,
+    12382793752236553986,
+    1587201516757095709,
+    7039878669331849168,
+    15583559642729926312,
+    15447237749050643972,
+    3059836458802354791,
+    2102733423746951300,
+    6135575027388042061,
+    15080125737946222155,
+    2781596282207598493,
+    11098543980635062306,
+    9295456126789672340,
+    6964444448206798733,
+    2042052778643713808,
+    3840970630551262003,
+    15913493053259302830,
+    10592839985743160272,
+    2187123497109244566,
+    8722800387137219338,
+    2346655814903542778,
+    17295585232669159307,
+    1798557491525427633,
+    15079384672747614148,
+    280413309083461553,
+    6455223214200117375,
+    2899962405453904964,
+    41037649596777144,
+    14825083219374273370,
+    4019762056139848261,
+    226532786058205716,
+    7022691026006463739,
+    1712984744894355451,
+    9312920686809895745,
+    860035446659122615,
+    11815861306504543877,
+    9583808659547619662,
+    13461536520485047515,
+    1861448434727923468,
+    16837635943355987780,
+    4245327977653510182,
+    17163180735536444800,
+    1945783769689166899,
+    13762473946838800459,
+    10442780905677827822,
+    11428775209310587458,
+    1416492813673453611,
+    11265847654559293973,
+    14592879703105173064,
+    13152009630392584128,
+    3368742037256284345,
+    9770998804655710878,
+    11098592329029431303,
+    15604393689446794515,
+    3271819381319745307,
+    5466468603676469749,
+    6642102734606516484,
+    16556629775927440575,
+    2415856448679378385,
+    1658173678748351010,
+    16865883002293079107,
+    18400704771041213350,
+    3149756878260756122,
+    1866183224118111459,
+    3730516410244571424,
+    15229534072763585675,
+    236865808939235306,
+    17641949134418450485,
+    13235626075668652661,
+    8829846507753933334,
+    192072408770741156,
+    8160306436782689710,
+    18378519553307924039,
+    17994786594003693847,
+    2657539638824823865,
+    8975562735616564201,
+    14125304062661674366,
+    10564306374075812635,
+    1870891675972403604,
+    7434228270137592717,
+    14889244980991390219,
+    5261670072494634307,
+    1737930104314945048,
+    912683113888025969,
+    12500348978038762204,
+    5981596814754785087,
+    833566978471902825,
+    15966799267290173227,
+    874546713421907977,
+    4428833115686640833,
+    2623769549688643994,
+    6270710584893312456,
+    14310680432560134689,
+    6242811704006188877,
+    402946807423085345,
+    10859381009266954694,
+    13773178476145393835,
+    13271663025553434701,
+    3402715905231570466,
+    17755562409509342349,
+    310877228098843365,
+    8785718176963445795,
+    3331058881829176907,
+    390500834773434783,
+    2695473247547469518,
+    18396569749386877743,
+    601247051568898221,
+    1180486598008179896,
+    17578850200252934307,
+    17372585224319592054,
+    2825168924231264867,
+    7607734295652214574,
+    5667198595992237618,
+    13407125911732827765,
+    2407571207527997697,
+    9411355060994486625,
+    5470186720508814996,
+    17567922092703318881,
+    123100770982977355,
+    10387523798587943733,
+    15304588445698739579,
+    78676211860199883,
+    633981002387146566,
+    11405325910917968212,
+    13985488208652522444,
+    17431404584950336845,
+    73401888375780537,
+    8041495314918269732,
+    3521762108199896504,
+    7269302421139391298,
+    2834352212729882875,
+    7552388029056927788,
+    4194249172138815884,
+    14429531915257018586,
+    2991712057450853038,
+    1287823102225221441,
+    14560338304735459315,
+    14077201184773183430,
+    2190632238701035052,
+    6980722602426171511,
+    2891626793207522466,
+    11401124719759539094,
+    3202180239358742902,
+    17398753361513856816,
+    9635603364337249685,
+    9282768984665941292,
+    3129006477851816012,
+    12473949393053051815,
+    17216794198395593483,
+    2332881540803867983,
+    1489522091444376292,
+    17003998669990943952,
+    8075917880878009065,
+    11156302952316883420,
+    2890142708305056821,
+    7008030942215607825,
+    16530684279688265741,
+    6029201078720201217,
+    1107363007797770705,
+    10977566132559724163,
+    15188884850869970901,
+    244766216007716249,
+    1786810967729942463,
+    17598491762721770357,
+    12572419524889698896,
+    4109370434484047414,
+    1800076679304403199,
+    16757176384717660121,
+    10941761314184055678,
+    1637326630073040029,
+    685844574779112110,
+    9203528879262457116,
+    3161173093392801988,
+    11830537965844281489,
+    1210468385820726295,
+    2094825415955718699,
+    1634748254242484668,
+    16453705666398331684,
+    1126133840626024821,
+    17515062461369868624,
+    18192298340307583637,
+    8534416515131090801,
+    2061013194157292498,
+    5903387681448396373,
+    14443172129391209923,
+    1479618142525976424,
+    136448874091575901,
+    18176562360336991205,
+    16012456995364850530,
+    2890045942490014611,
+    765381790678888621,
+    10425997351249183757,
+    1271186935159965065,
+    10139524061176287252,
+    2848343178009025264,
+    2269633302166271256,
+    6408714690732795229,
+    1583832506936827077,
+    3117671121459479665,
+    12149060238067038752,
+    4024945521312872585,
+    9995801013081384528,
+    3089261261742901462,
+    16537506927609185354,
+    5961163508715249251,
+    15628970020836515974,
+    1025399101403699124,
+    17587051539178853685,
+    9614110122890027772,
+    11093675777538326618,
+    1802429750787110657,
+    17314791355657101885,
+    13210554081243208269,
+    5544043209637561470,
+    2571279648471591187,
+    412803753912518246,
+    15774042018800895332,
+    1937128317996426837,
+    3399363020331064725,
+    10878491673077116873,
+    5683131325089837291,
+    12829531369151015451,
+    3167588236283041892,
+    2376706847204908171,
+    5236269111092316661,
+    6998063405621216702,
+    1531652978194914330,
+    2534052221054072823,
+    15311766567520345369,
+    8682566677123496432,
+    594732458150760673,
+    14248040958470969233,
+    16159867355509117482,
+    10999848205052169398,
+    641474980945480486,
+    17417179829704483389,
+    11698714946955419450,
+    11760065544458290367,
+    2604041032639001827,
+    13014547292431389573,
+    16876488755498962637,
+    8566610927914666616,
+    1393943785606180586,
+    919080765623877417,
+    2250803643148300030,
+    2921249428523049442,
+    1860543982019002370,
+    14506844706069804430,
+    13982316560365825723,
+    7675585953408407360,
+    373300733116953299,
+    16966903301776491799,
+    7375680799295316210,
+    10108814522865135538,
+    2752007466149068176,
+    12549101238881487111,
+    10580437428485875664,
+    6359784135586363998,
+    464284105312253924,
+    12427988326199033901,
+    15944151352649514478,
+    14613927351643605919,
+    1659688714032672450,
+    1751802369655720798,
+    7319975607831981573,
+    7679351830700338453,
+    780906760972141564,
+    12358361323421971136,
+    12372574077858157896,
+    12214177433938830137,
+    1488110166845789469,
+    16710222101954598345,
+    10576948596146083688,
+    9134131096132418074,
+    1703181941602169639,
+    7478844427947226040,
+    14673861095686613823,
+    7599315548124802868,
+    1785229282289827626,
+    300655502307699676,
+    14169217094534607605,
+    4875400780438374695,
+    3273385593442564395,
+    11663949643250842002,
+    15089149355289655666,
+    3367041797300615393,
+    1227605919166915160,
+    6454994087929946156,
+    8450698448912581203,
+    13819226505758361274,
+    1339649891805122736,
+    5726505574362805897,
+    12598879079625625206,
+    14183141011944273480,
+    3190706179492385402,
+    8435166647834518546,
+    17149697714915134361,
+    6881940724704999069,
+    311906374967139706,
+    17184440146812051004,
+    13206631871762874649,
+    10971889559608705139,
+    2305558398534463430,
+    7365317700700026195,
+    3493942012802873548,
+    16965038798190528408,
+    916357297220800031,
+    6089581892365679784,
+    10345283340153746338,
+    17194475319267730921,
+    591883093731061706,
+    13311737965866942839,
+    16411132664562377975,
+    2591568664277089784,
+    2565580837005314237,
+    4060136516725654826,
+    12283943188563729349,
+    2152569861509880505,
+    506065632386338961,
+    11010399218888746585,
+    17815900248928912549,
+    11087379182401034504,
+    2016543094924424293,
+    15600905569196808555,
+    10616875851457300692,
+    2828433299347814764,
+    610628413022598928,
+    16613617808138892646,
+    3938687237628457142,
+    11755000305774362568,
+    1912374589029111136,
+    6285499103888612394,
+    1334464312909035317,
+    14805143539502865485,
+    1972019778352550041,
+    2780001824757548708,
+    14625041783582513818,
+    9058496249629825390,
+    268979152079818153,
+    3115878830630064434,
+    14417509227374648141,
+    3762129575349843524,
+    2958865238556195693,
+    10379752871352821885,
+    14820330086105898799,
+    50256540181047773,
+    1856407738047975216,
+    16912025688066950457,
+    5550683047780870813,
+    12819554736714335822,
+    2409718475473820654,
+    9358953562412775680,
+    13716327762801204732,
+    15648597184128523204,
+    2235398614704487328,
+    2988614535588296796,
+    16124260340438799660,
+    139591418803522399,
+    130486651258890574,
+    4104564711106059325,
+    15513042620462003025,
+    4503213193957344004,
+    986850084364705004,
+    12349585448508251200,
+    6396706515668013318,
+    5290591985374303445,
+    2952955337180797240,
+    1769841320552302488,
+    16855231511518309198,
+    1190373734322140981,
+    1368723171267282357,
+    8723623365736496146,
+    15384573537359783947,
+    8951330116988934085,
+    2913475293993343338,
+    12802691371112259982,
+    14019917986852050464,
+    6279591492631586750,
+    157527370912737085,
+    12302427645948747192,
+    17989639572371304339,
+    22386195397770629,
+    3419359253010445266,
+    14354764318406182222,
+    1399013429489999871,
+    7172084278262541868,
+    3371013610329279715,
+    17853077611318677682,
+    2013438658662788471,
+    11777701131705736490,
+    3092675473301114364,
+    16180730493830603077,
+    12813181066627991589,
+    17575738224764427682,
+    372647008878277427,
+    330419995374737260,
+    7077038620378384650,
+    1911595478666005680,
+    1187551531147755642,
+    18306913221989751608,
+    8277277912208259381,
+    15933909146668209166,
+    91998337453641282,
+    13449060767635774519,
+    2029065671547519518,
+    14104442883736848611,
+    1704861416175513647,
+    17532235813593250490,
+    13747108013305295540,
+    1935819947642029567,
+    1920572259007084074,
+    9184748351107767423,
+    10987199292219608427,
+    16781553857544358901,
+    186732142972915380,
+    8065190952142664804,
+    9636517463314344222,
+    13234010782624522473,
+    1150215545090173464,
+    10477662054348452497,
+    9952780417803718193,
+    15926363284684058962,
+    1686815975963588666,
+    2650339097887204751,
+    16455323650311526278,
+    17474787146363859122,
+    2555143319083124685,
+    4470682487687812286,
+    9445222247483678426,
+    4484117483461362408,
+    1696179746312035770,
+    5243073158587915330,
+    10661337987262635277,
+    14243858299674104433,
+    144354900258296426,
+    4687567066711346092,
+    15639364410250335839,
+    15813042166925151057,
+    357783815268256535,
+    9304030312954910115,
+    15325468413119299565,
+    9491326433740545905,
+    2727351250225663751,
+    12598295752878059089,
+    8333714177785773689,
+    14110617685478472154,
+    3046509788782378591,
+    15106676159106856897,
+    890750329785686865,
+    3260191688455198663,
+    260135727668534495,
+    5206782557950077032,
+    18245585163399143068,
+    11856584956163922712,
+    3311292168489247149,
+    11194849337738467476,
+    8241339519652882157,
+    425576363145064443,
+    1292586630176792606,
+    7748823634462694441,
+    17202038151146696759,
+    8407437031178859678,
+    1342580023091457075,
+    10849222758495883896,
+    9925597122212024501,
+    16276676119039275512,
+    1101701816248994432,
+    18305090501543470677,
+    15201610069331855018,
+    17897183577911705138,
+    1802558776744282696,
+    13064552891569278076,
+    4444978469364807053,
+    11769085113726156054,
+    2372299836242066384,
+    7730134907734339313,
+    7058554661921368663,
+    4724977824980957391,
+    1351188416179811071,
+    10035933399465229644,
+    8762925124154490417,
+    3463101141950949861,
+    348918255338506957,
+    16927863443913089127,
+    2882199634034627516,
+    14478406752793579394,
+    1632594131232874130,
+    10448844324757056833,
+    7401041824438522770,
+    842695447881894979,
+    2208511681439242010,
+    2326635301259943378,
+    9600792280294463058,
+    12484111378600875092,
+    1346136365254518499,
+    848454016881214011,
+    1534180631810646841,
+    5276313482826634022,
+    3341205321449526051,
+    2897721995130922551,
+    6127166848881225361,
+    12784792212144607452,
+    2681085585582238264,
+    5084521845199098184,
+    9727300474088126090,
+    14878009238511576782,
+    2572712525229261965,
+    2540007461085947849,
+    12254606630382526543,
+    14487525751629353916,
+    1684274331212083320,
+    5678679755692057422,
+    3595520443655049649,
+    6597878881733334839,
+    379030949952551118,
+    12840749820059053091,
+    2833346774493193073,
+    8082640138774688996,
+    3188679709870664192,
+    14971492179634122275,
+    17077599330059947640,
+    9389594006552630138,
+    2915697190853570260,
+    10085307432266448139,
+    20403989439678647,
+    17717908253060042286,
+    2772647426464818248,
+    9104718344644329285,
+    11668933067645653826,
+    4986117631589954861,
+    316545380440066624,
+    15557886222253196780,
+    3664592278191502156,
+    5258005531513114636,
+    1793881053940778795,
+    4746856722966918502,
+    11216857196701373508,
+    7174677555936605064,
+    2248273763953469848,
+    8300848113361031435,
+    11683229500573095816,
+    3663479834691191971,
+    2806416206462332433,
+    5838133957067944456,
+    11847206725965767675,
+    3936503583281509411,
+    3180694243439046877,
+    14077080692875822547,
+    6797483123438989791,
+    10446507619559496690,
+    365303620153207782,
+    17480672627090018613,
+    16483647731852783767,
+    4112259937847820238,
+    1404610824181291627,
+    18128368814848450904,
+    3535085900136155156,
+    17598658126505260285,
+    3274454053239301893,
+    15434022257624699017,
+    12051653543111354325,
+    2414804094423086479,
+    1820927552464044507,
+    2486617014198885143,
+    7095763225937662927,
+    12551155175008227086,
+    2567479303307664349,
+    12873571157217577688,
+    2554457568394191983,
+    5063424431661179722,
+    1514104605425126292,
+    14599159626778186036,
+    12633479023069639635,
+    11119837241837965235,
+    3191662285978268519,
+    14945809135746727937,
+    8493636300532897759,
+    578522071829826772,
+    3384995718444207259,
+    10235029512387766048,
+    3242846980693668464,
+    12968519063514212456,
+    182817538131206111,
+    5437511389250709536,
+    2871043042417393504,
+    7155210517097463556,
+    2005385650923550476,
+    14737746102199118064,
+    3913244140167754797,
+    888291398148447816,
+    1878200387215827001,
+    18087888633565672961,
+    5144179338690524605,
+    6436032280484921518,
+    1446961616294039586,
+    8224393475648422671,
+    17128150588560251708,
+    6957320278127965793,
+    1771163957445486128,
+    9413479287168567347,
+    345743919502348772,
+    1209464366909394528,
+    3274046376273586914,
+    5562859030371759435,
+    2947987352508304547,
+    10152131159442511137,
+    2736534784643068058,
+    14645733349232849729,
+    14204263074724955867,
+    18335849998844117899,
+    210146125736669969,
+    9686035005765426669,
+    17886589053563169851,
+    18273494531834076589,
+    1682596483860364644,
+    3412664525763117271,
+    7538208299161213377,
+    12934840553000585114,
+    880661228146975070,
+    101471598561122842,
+    1683382964338221766,
+    10452730784262910922,
+    1968066970630466519,
+    17798632914915634745,
+    5695824174198299614,
+    4411634986021120774,
+    1067154427514680207,
+    2384359687562774575,
+    4524538133319324696,
+    17507477095588236268,
+    2849538288151728276,
+    16987921171437695635,
+    15697307270298293082,
+    8983361710970318798,
+    2668722083622580211,
+    6810839513075325076,
+    5130376518960574866,
+    18442207343643084549,
+    3418925681378834514,
+    4538555997221043522,
+    4035593573629625094,
+    6249063520226232692,
+    1073842759704674974,
+    12681900178608599417,
+    5807495827504975500,
+    13944336568699389271,
+    340618384988619987,
+    1821854108322979222,
+    9194458326470665736,
+    4104376406929534438,
+    404704269075041257,
+    17284086799525568521,
+    7873350712295683399,
+    3370192628133199701,
+    1796251313416470597,
+    2951188940559196838,
+    1017656424905956421,
+    6922428300534267460,
+    3129858064146404447,
+    18120877839366735679,
+    18135566216330323346,
+    6777138478974510390,
+    1871188476840657601,
+    1212507107226355279,
+    4941614059202020195,
+    9758234180584563685,
+    277969809242106250,
+    11498512095575491632,
+    2760707292948834828,
+    7669343579652595138,
+    2478038957123078171,
+    124441705948754593,
+    13482507533611586661,
+    15416825560270374845,
+    2783875416353270734,
+    17460290742046933422,
+    17018386740178639911,
+    1653212366612076582,
+    3161808797014486946,
+    7175488410284459045,
+    11098693265271966621,
+    2700300531260251986,
+    1163667049921502125,
+    15697617251902807770,
+    6543919691317255480,
+    303160413802716377,
+    2778366098007445834,
+    4876124434093077525,
+    17355178107936341684,
+    12561726085854826972,
+    608097763525307493,
+    15119449604813627491,
+    14406646677205989204,
+    2626842159422643392,
+    162658145392488031,
+    7914562421131606772,
+    8597452186760181527,
+    1969691674641463036,
+    3322239421874002441,
+    4823604762069216353,
+    11814430320697654764,
+    16651769237288319624,
+    1616699878794547758,
+    18084152195420859971,
+    13693055443269975413,
+    11184828998500629768,
+    3251829995047873709,
+    1739000287227091044,
+    4263284627154552932,
+    16403460846021192977,
+    870910919741972950,
+    11243329319447698597,
+    7368572001209006716,
+    3056099757594668143,
+    2232304001467492155,
+    1778978308255692447,
+    17645392713774121184,
+    1813323195208634731,
+    3302011320168081499,
+    1121830469177461529,
+    1184735129055143566,
+    5870665731714418439,
+    21577094990370915,
+    8933686368076246890,
+    2964163309649606734,
+    10985139077263919201,
+    884949703532551061,
+    4147204398797233959,
+    4908633759315668816,
+    10717471776975783829,
+    290409132095764497,
+    8876061966416458707,
+    7454108865022506663,
+    5670678780256330803,
+    1261226344364111024,
+    14997430402244441625,
+    4553397858809158535,
+    848813319711644229,
+    2216130069950151510,
+    8821113221302595761,
+    8898551226551809897,
+    487523145438122677,
+    2796423478462178760,
+    4557572156349442352,
+    12970686496183758190,
+    18401801876933043635,
+    1426953464337839660,
+    1636489784455780205,
+    18298291272444858884,
+    16041643357699877002,
+    339070494756602397,
+    14032139620165508567,
+    12224566936232682112,
+    6526931911950169733,
+    2332022431030648034,
+    4780127687178655233,
+    13816065862865097865,
+    2281109960339903155,
+    2654633617384042088,
+    641264778884645848,
+    8546210010722986511,
+    14881793180831703816,
+    2783053252443015732,
+    13562756712975057714,
+    9595994229324787643,
+    1492438188682312705,
+    2478221788168434870,
+    7460725713570451273,
+    15407425425051002743,
+    15643370239628796983,
+    425073829670666071,
+    18107150412368671398,
+    8988271347480055378,
+    4709518838573023508,
+    1702410559928614474,
+    11157417890219371559,
+    2694125809115235712,
+    14441487053916442893,
+    2332554195689127746,
+    9178915349051743772,
+    933097745286780806,
+    6023225467993578713,
+    21623080051664746,
+    3283111053081860777,
+    16440959286157838068,
+    10664688926781753814,
+    2102427047073649658,
+    15371540531435278629,
+    9511986341801520835,
+    11064219416734434983,
+    359116152149493773,
+    16636787912139317895,
+    10591340103076525185,
+    4099976780421780662,
+    3454596367108098293,
+    6819412401204678280,
+    8500853503264506522,
+    1240926187955723534,
+    1142481388065623772,
+    9073934198849336788,
+    18242711614344103914,
+    1457074808798252144,
+    39061868629772970,
+    15311272233934516096,
+    12724808623482809718,
+    5464127097697786409,
+    880700620387731613,
+    18116108876218643658,
+    16490656688845411646,
+    16691113225209670477,
+    2682664146498380089,
+    4021341747591265794,
+    9325970814654400348,
+    6943385448603462733,
+    1870929308298600493,
+    14485799215126793847,
+    10253813879237454501,
+    18051954739745834444,
+    408540059658521789,
+    10882627274344012806,
+    14174779869180096767,
+    3435420218188244441,
+    254228958658321824,
+    14758734169760398743,
+    9930723923537945841,
+    2820578656701811711,
+    11526714011193908,
+    11629649112078344180,
+    12871419167661888831,
+    3044801602658620290,
+    1222999920678895781,
+    2386185708465961589,
+    9204264955678649856,
+    11775357658967957826,
+    2093594841781041516,
+    15214065217023531578,
+    10551307394514078650,
+    13872863269832884139,
+    1653216961657902817,
+    3319467910021996273,
+    7491121591216257878,
+    14410437262618903964,
+    1358575963276507617,
+    18005428334789167345,
+    8913719887375569123,
+    15392997646802811363,
+    2094370917361006830,
+    16491782486937412510,
+    1389958618326321741,
+    16532752009938054271,
+    813550526215929445,
+    387571961802600728,
+    7322887385887668511,
+    867632613701505306,
+    996200395661742069,
+    12729449948384328992,
+    14956302627865895541,
+    95619024407449640,
+    1914090288266281387,
+    6053649375383035730,
+    3598346371601303949,
+    10061419700168502251,
+    3245644628012536516,
+    1189431313506128823,
+    16267486281682921585,
+    17810922724320141428,
+    1536390267912283005,
+    124683455082036456,
+    1442598294518971459,
+    7875564203255703891,
+    1454273277066920115,
+    17740009616195585685,
+    9426684785772267860,
+    4918557630116683872,
+    2905728322387089726,
+    7280485106029712370,
+    14183012774929380377,
+    798416689406423225,
+    1533682941023250704,
+    2740980979318827885,
+    10880087625615474011,
+    8255075073852326888,
+    956213866571357780,
+    3035898580009090049,
+    7834425157534249069,
+    6456721669394930394,
+    2331899358042532000,
+    14513710114554561015,
+    5767780254885093433,
+    254718591762563493,
+    2894321896931735973,
+    1880225360045944368,
+    5273172648683618649,
+    11872320363359052109,
+    1776485881646235011,
+    5047333516577004504,
+    13145559538675969173,
+    11484173745773154941,
+    2371290820125982052,
+    13636123354797474202,
+    14033796984347374554,
+    7212087939383782734,
+    1357043941897279913,
+    336523438714750365,
+    2942209683551331148,
+    1185851587153651564,
+    385101807013292173,
+    839025864596389707,
+    8817124119430493553,
+    18087473628614693812,
+    795317058172713213,
+    2672286492090797458,
+    797290918643271145,
+    2029148420869684690,
+    1962268154738880270,
+    7899144537400954950,
+    8724974395205422960,
+    14648280849234546630,
+    527879956422641921,
+    16529774499537534204,
+    3324527849759257750,
+    10635950440107889684,
+    3132910584391865516,
+    8539907705345510795,
+    10927890955933720558,
+    8958996448426322314,
+    3337754976528556466,
+    14962640208076923060,
+    17573920670652575745,
+    8488445511947358073,
+    988367742165020057,
+    13116089709163047203,
+    14636637882142374973,
+    10024045967174803472,
+    1880852125937935434,
+    8399001968688969785,
+    77195176902641448,
+    10533009794956580790,
+    2048031319258633474,
+    12173890108863946434,
+    26974060367219660,
+    17190250118471511770,
+    668266407770495638,
+    6181447404354135738,
+    4683500453258193282,
+    3219342628508648409,
+    3425784368455387028,
+    1692358274929182313,
+    8530012260424610296,
+    14922301493068680883,
+    1155836159321005759,
+    14222268153096682851,
+    13915084544761002210,
+    15415170419916983201,
+    2358085657779920806,
+    11939728358121654448,
+    17511982027205792962,
+    16754390387495027299,
+    2631734950877360468,
+    8671291534362871798,
+    13931407380806284183,
+    9303813270482371822,
+    720126218988107936,
+    11093536438740080986,
+    18211551766064983561,
+    7657875228209731271,
+    1762769377746635551,
+    6835855120705833193,
+    3448563954088302542,
+    10817465993042792934,
+    1347083192137445194,
+    9370197738539091674,
+    2053689636262583242,
+    8295681439401478660,
+    3240669802126447810,
+    10159533659850618822,
+    7728146773513667514,
+    2039455119554678120,
+    692608641398158896,
+    11349889410146851546,
+    14116083986371872507,
+    3147982016971117103,
+    3158076710220976221,
+    893410962004078911,
+    6564020239959208790,
+    12207582605973205729,
+    2801808104249442514,
+    16782379284974305321,
+    1241100083538897547,
+    1214581281201224641,
+    38635371089632984,
+    11923265079312654166,
+    7102117139007695505,
+    8572965444002865421,
+    3068742108439304661,
+    7555488891319718725,
+    16566947279921562688,
+    9270007034102080144,
+    3147750929519578500,
+    16537879464134635744,
+    581361378257738905,
+    8492665936498774755,
+    3118728365022284682,
+    1622845605517731026,
+    5046599814324352642,
+    14055337548489252213,
+    2308290947619799094,
+    13322717523267537981,
+    12897991301480690389,
+    1006825898915573989,
+    423651476034281421,
+    7954701537676142010,
+    13320762560780654002,
+    14231053247797243304,
+    1303141001638966080,
+    3015385964541361330,
+    10765050379694900575,
+    1997534152717720279,
+    2482208267376641708,
+    5820606796747289237,
+    5113455092468747645,
+    13498068415079475262,
+    1464249099639289619,
+    18344844234275702479,
+    14629461350799014792,
+    8447602454229025435,
+    1881465974906550911,
+    17201778622109645425,
+    10329457069566765111,
+    16090715811046254810,
+    1856866946882595021,
+    11068392638617159591,
+    18037811623458856243,
+    4983418285809237815,
+    848822267074713173,
+    12391948952749359543,
+    12853598989202253068,
+    14170912258680521595,
+    208834601662630226,
+    5828171998240797816,
+    8667941090552656050,
+    10984955373365242641,
+    2877455611586058240,
+    2833028370645821814,
+    971022006529761824,
+    12526157471814266369,
+    301204518068044545,
+    5447728539177829193,
+    14928069672188525534,
+    7655055235432265586,
+    1702119508687563629,
+    9173205517074375347,
+    12461231260999323277,
+    564829658332238315,
+    1413480862333362034,
+    1712403289508847811,
+    15539642682498419671,
+    1572663140901713544,
+    3128213708159188983,
+    5363873707587721024,
+    18256002616391103221,
+    5995081807542072367,
+    2194316913561432724,
+    15289093848247051954,
+    1306749821725726990,
+    7145335860641187798,
+    1293934515587683994,
+    15224391412993551064,
+    268761189840877335,
+    4858197806321111471,
+    2266199364308338809,
+    4730487230950247589,
+    12001979393247183013,
+    1552668693723451595,
+    230751749260742155,
+    406392061217434678,
+    8121213697731096753,
+    3873087777018827821,
+    47969939229678773,
+    16917635884065997888,
+    439895884878615623,
+    4629726588144753016,
+    2519501761239933129,
+    14263878335983358895,
+    16271981533001963159,
+    6730787852803487307,
+    28818589736797156,
+    7778712547693219595,
+    4832563302118011082,
+    10179705315080140202,
+    986079609287280907,
+    14860349224053986034,
+    17710238573804272861,
+    6710610744416193904,
+    2255057449512841963,
+    7186084107916368439,
+    398442605685400158,
+    12746017017432958073,
+    798549407676771465,
+    14766007100905055699,
+    378972691545339253,
+    13534177272041319845,
+    361491118675645941,
+    8211082973861570365,
+    8462119711044410574,
+    18188928298926000495,
+    1602728166697967902,
+    7188298404485510284,
+    7360796761815402564,
+    17483122668289285670,
+    3033726315619054190,
+    13783680055184470367,
+    12936290459948850394,
+    18297970751976956209,
+    679422544261844391,
+    17633903422744223111,
+    5211476884706086586,
+    132547949440778691,
+    3460436082626921363,
+    9184299674243817918,
+    11976742639808471056,
+    8110032520165426237,
+    2982020026486403368,
+    10440714868919558734,
+    15538400928962643542,
+    13976376815485029234,
+    3101992018173858032,
+    17506092341515540391,
+    14116118322263842043,
+    3620627882326242476,
+    1915514531206257398,
+    5668811938295393401,
+    4319487073999745938,
+    15077251002700152172,
+    527006502487492092,
+    16356157128620850550,
+    9799458827373360032,
+    18415150449408177852,
+    871664249200407184,
+    14211280106372380980,
+    12750553904787340239,
+    9818806857954341181,
+    2292277491636209107,
+    2347235181218726859,
+    16767878079206588958,
+    3772587044378764811,
+    3297397259238600301,
+    16495265151066634258,
+    1401528188270868190,
+    12759629071804302508,
+    401015722366678122,
+    7406154179512446570,
+    8328038836787333239,
+    15809230193172062004,
+    2664657786842392361,
+    7523164760713388496,
+    16198598909881643942,
+    4439887655856500937,
+    1604039915135951108,
+    222653383156001595,
+    14909492405242382215,
+    13448703972442196472,
+    2000971914134624234,
+    9467559726546046642,
+    17138885364170402817,
+    7557559991001659234,
+    1231769499950687073,
+    8186293625220114595,
+    12148629038850323510,
+    11403057707559815939,
+    1525825201072924606,
+    3110574421059193154,
+    10833372687728701464,
+    11728936064908519484,
+    1868414016133203198,
+    5598357343207580177,
+    18071523547247740622,
+    15619259988156144516,
+    2876635066055991636,
+    7544736252162004045,
+    14367396917712670305,
+    14787068147407063915,
+    2644735926112633202,
+    18141073516231356306,
+    10409678115694589138,
+    13148375268031218612,
+    1978276480588828935,
+    1444640943326835067,
+    1221839081864778537,
+    2829501533593915446,
+    3181180113536992559,
+    4632192248612621918,
+    10712490013492136804,
+    11124611304403077739,
+    2259828596638077964,
+    8288059422447171146,
+    10034372309668443248,
+    15253899729213293202,
+    2633303776517692585,
+    18366887691276592369,
+    12343539253854937713,
+    3922460972444641972,
+    3049061360632493107,
+    13375625257076835452,
+    13655092360892587773,
+    650255677390661483,
+    1102503238437366159,
+    12463475669826706066,
+    17203709951518063181,
+    1202728628131871801,
+    1234220018558950904,
+    3366529176256408069,
+    3603819448400995382,
+    11422214805873212679,
+    3144569740730294557,
+    744025498326930389,
+    9619379751598181669,
+    1648864083585085321,
+    1941023779850588784,
+    15128449294865293949,
+    9154799192487464011,
+    6628650905352915670,
+    2685608259100776073,
+    11144011058340383999,
+    13707476604324910562,
+    14360791122354214022,
+    417814084053194225,
+    4606427000286632298,
+    2368336008251702875,
+    12018090910513587557,
+    3404959539196132334,
+    7711082771119400980,
+    14924129087661923418,
+    10013977829997583583,
+    1118593196306511674,
+    15545898848096871691,
+    14505780447105131372,
+    1181466675874825932,
+    3053665846475113642,
+    8352300095546847990,
+    10927636075838098833,
+    14191572062365321396,
+    828375351503369467,
+    476946798609091350,
+    15810808875279161984,
+    10861092003263107686,
+    1596056155731963495,
+    2230722434189019181,
+    6447198243453939990,
+    13547753522956975024,
+    3276524502815973840,
+    9628236630463482677,
+    6080336817216767886,
+    5016536059644870757,
+    1791439574162568404,
+    18221231970953118041,
+    14075865337044194403,
+    9291694449511220619,
+    1313703888319780632,
+    10674406191250689175,
+    8099501055385538509,
+    11065643829821864201,
+    628030781644545729,
+    8750796603971770126,
+    5194372031106706095,
+    18387129655724913342,
+    2630892094122826229,
+    12058476625099535651,
+    10011730688820747899,
+    2001595409116497311,
+    1450737850105101198,
+    3233260844777278504,
+    7295808412086317246,
+    213023709752123372,
+    2019035611147718560,
+    9761835833949701718,
+    5834230631942279628,
+    4163810894732733072,
+    2993465455563183464,
+    3492331153285349512,
+    7982100195241757698,
+    17164928472457239259,
+    1439740649373608014,
+    11756556855209437814,
+    7839148893992511973,
+    11459656006322159462,
+    2640871685300553695,
+    4481965595055688547,
+    15389855524232142549,
+    427858842773641670,
+    271945507492727028,
+    13598014686494537543,
+    180964986546207697,
+    14749578140583874903,
+    3047844667779432178,
+    2491444907971178339,
+    15173092246370214223,
+    17172693322702337244,
+    3385348994776662103,
+    5934721697651159686,
+    4384099414390579055,
+    366166655446161610,
+    285194144547515361,
+    11804093355854951176,
+    7733530960380487959,
+    1331230980418538270,
+    2677675737691116692,
+    542998485711099099,
+    17096152343003533001,
+    70128886166795225,
+    204518796001778489,
+    17922834668054091106,
+    6563534912273453081,
+    15202616176757619459,
+    2700138958436986450,
+    6403096390153783333,
+    269860391427278914,
+    14470219657785998270,
+    3185443003496787606,
+    13616561835546484968,
+    18443109495137117320,
+    6649918423136092347,
+    2270966864044023307,
+    1542251329992143499,
+    11230399577145070986,
+    4644328630542720544,
+    3037003591138573900,
+    12534978760770806124,
+    10535348162861530829,
+    13519073675325308923,
+    1392125915883347408,
+    17249228819305339616,
+    7972263163006677636,
+    809824760406230429,
+    3206813558360664744,
+    6568116150672603760,
+    2016633205419711597,
+    8677346509228483230,
+    2757545063704888800,
+    17785437466131988623,
+    9670478764333811346,
+    4452440350373002800,
+    557425707560146864,
+    13473935029158149661,
+    18362061463084878682,
+    12449549285300022278,
+    1400923446926844091,
+    1023607743237036115,
+    9931448342549086119,
+    1712387905705481576,
+    1293228322122806626,
+    14319168604404223114,
+    7277170910275545088,
+    6429489357604930738,
+    1491001424859529013,
+    9715487722318985824,
+    15807167626745667087,
+    16218773349853963829,
+    337663401935290453,
+    5122665567093693480,
+    10425271636837159840,
+    8503437967820527247,
+    936224926373650333,
+    5362905387888892828,
+    13655302081091581519,
+    18086525967101759988,
+    1310361155293980113,
+    16983539723587193865,
+    6198549867019245323,
+    140919665757032108,
+    1061253834442039338,
+    18291501402491790461,
+    15161571433751312416,
+    4295783434024496980,
+    393278738438382888,
+    12898155673144392734,
+    11252970416047582520,
+    6880407551008070077,
+    2908774729396442598,
+    17655122899916652176,
+    8824359218334574171,
+    14338066908032292370,
+    2876381949029546058,
+    15303045941948404629,
+    1649537900210249063,
+    11842626207074145826,
+    2001174096231699416,
+    16533703929260205845,
+    9173310986080060241,
+    15581518518660428760,
+    291662874594898890,
+    15741593588101514774,
+    12681272578503546881,
+    11744952022688703301,
+    451846072452650550,
+    10353813229809508303,
+    3667071172125394473,
+    7408130142773127960,
+    719172995301960724,
+    11360251356538036192,
+    15968841585010670249,
+    8235362525965655467,
+    2664496487933786532,
+    10496553871764733988,
+    13041232021958046283,
+    6448785629895863651,
+    3262012610583000322,
+    2605741394548542209,
+    6822529065417004846,
+    688824016357802066,
+    3030430664573879414,
+    2622540273014308597,
+    10751250717589516806,
+    3748488699416475873,
+    1397847567096115355,
+    4367743240655207464,
+    17585070847900409001,
+    9247290428988509687,
+    2291378048991347192,
+    12527882478546454194,
+    9961951221514619112,
+    2369683115220699042,
+    632449886296389470,
+    5814140711396290655,
+    7837438589652894500,
+    7707167593492907475,
+    802837899946744983,
+    9960030747042793607,
+    5659234851397591930,
+    12065745184488463415,
+    3003585469748208615,
+    13827616126270463738,
+    3308507608414049709,
+    6477460464549221996,
+    378114689040119012,
+    17650693168764429286,
+    9732341240548533536,
+    2331748261965581725,
+    26238073927407144,
+    18029164555080499174,
+    89538417082776374,
+    5376129682723802544,
+    2148721585667248983,
+    11877690264620758467,
+    2406157169289165182,
+    16038923317962112565,
+    2752893794332833873,
+    16171482810522846992,
+    858315124806905507,
+    16096566294247275432,
+    1374035919378838326,
+    15144511700895570318,
+    3239831409352331009,
+    8865495478683590359,
+    2907722140725447171,
+    3047393354132689525,
+    11393310160552428316,
+    3831961315033720853,
+    1415932115308674856,
+    5480318564315735966,
+    7944784301464709304,
+    4255504093347557809,
+    322276611651953303,
+    2001532129204766208,
+    15008148267495023971,
+    4891351941692524214,
+    2459269310944983139,
+    13409345882501000382,
+    5657978070640913897,
+    10519973515574563772,
+    659996257766702885,
+    6838599740812732645,
+    11406643716445367983,
+    17417353321326594993,
+    954416198663290403,
+    10934907816400398357,
+    4271824277168265576,
+    4791525456387356693,
+    684791697484304947,
+    17065418368284284930,
+    2318980265867133693,
+    9220772763271984640,
+    372808081812221497,
+    2852993625731374221,
+    4444741329595047185,
+    15721718860099260581,
+    1704387177117809979,
+    6728975295775571715,
+    16856062569007181253,
+    18310084317611682334,
+    2716089606070398410,
+    2165043280543905309,
+    5804470206426505673,
+    17438386793277472366,
+    2356545075776487148,
+    5844594219998851811,
+    15727945789822136977,
+    14062071037705828026,
+    542226327343523091,
+    14311242109324702039,
+    11326203439665829696,
+    3384531273733161074,
+    3156302783661345583,
+    499723594093337755,
+    4135040103291876180,
+    11918090862607809444,
+    3329865616467264819,
+    8577870335339018052,
+    15995366878163616677,
+    2098422703089382991,
+    412912902176614955,
+    2384585077756762026,
+    8976268181310346291,
+    15774298887678659672,
+    3366372644544152738,
+    6227789555447150049,
+    13029765524914067780,
+    7328329488989756091,
+    1586850127264518878,
+    13922221039979146753,
+    9914446213111691145,
+    2384170280512909540,
+    1711034994692030076,
+    6451416940487121862,
+    8582828329441320273,
+    7563843150654649228,
+    2279687867426623746,
+    11546909844879668876,
+    9201373410427131111,
+    6574341455941630394,
+    1451019536334379468,
+    13569959214397965671,
+    9263385941124557599,
+    5596514210107993598,
+    2826020327658810610,
+    1437151436700195172,
+    12346591954322848050,
+    1382745187611486355,
+    35579625010509285,
+    4064240768943778956,
+    2515014196323259443,
+    2114491464963861089,
+    774824060259507303,
+    10291544917758349224,
+    3890544983542101028,
+    12367435736581570036,
+    2839771677531158997,
+    9316795438727825427,
+    12670181026962728765,
+    4510048251832733752,
+    2442297020105795587,
+    8426705447311966908,
+    12422480604381715300,
+    8214225145973014993,
+    3483850441057595111,
+    8046619172031189774,
+    14059951507610998519,
+    3131965538581347412,
+    2820987216559971929,
+    13365021188667658278,
+    13445299260842610875,
+    2743329697747021269,
+    882188587716734325,
+    15006817251841043021,
+    17072514661690384067,
+    4394229762461659785,
+    1185386711541688319,
+    4034412462458235845,
+    10586770866436591570,
+    1793686768633733739,
+    1103119867791966924,
+    3690539421055106947,
+    8461145776283017340,
+    10775652483351358187,
+    2051532777140771729,
+    14593877855708711736,
+    16314386998897550555,
+    7348314285496603730,
+    2690021537934034298,
+    11662625107422089332,
+    4723399917442863469,
+    5278143303020237284,
+    1815114693076570565,
+    16121168709251702633,
+    9981330947390308696,
+    272960743305449688,
+    3117537045379329103,
+    13820479436689304372,
+    11206989175201134650,
+    6598426433691141426,
+    1938470443914329230,
+    14062942603793433584,
+    2747354523105063282,
+    9698140135996495458,
+    2553073423487018411,
+    10168311172654066487,
+    8860286885629849959,
+    8954300387235562587,
+    1890312543999948180,
+    7426340627919565034,
+    12423110929598228383,
+    9007224660302142191,
+    3209524089538560062,
+    3332025842418935500,
+    11604297253617944581,
+    17546418783580926037,
+    1449979973011279090,
+    13367258016019624054,
+    7560195567597986154,
+    15842075965617657503,
+    298864266569927488,
+    10142704800311577866,
+    10210747241302797150,
+    6839712732119692981,
+    2883354459273497993,
+    6443085076275117082,
+    16704702537901901015,
+    14802094532144656782,
+    2787928797016929855,
+    15898343265391544037,
+    5661520544959997641,
+    1527411579444122917,
+    1741323157483277086,
+    2020580378276866321,
+    17615022728351436556,
+    3018607200391598690,
+    742210963034577462,
+    13291256818330603777,
+    1421119263806554600,
+    6376395021785347035,
+    1289046464220811966,
+    8287288615556166311,
+    6746886518049944265,
+    146699509542728327,
+    2278879644700064205,
+    12517456965903821067,
+    9213179423945601277,
+    9512698871155572344,
+    3462831347721552146,
+    15802409872716915004,
+    16725177013459244108,
+    1486391885744812141,
+    3132340491855944736,
+    13606643064609679672,
+    12247271602295646052,
+    17556178139601420479,
+    201464644529178269,
+    17372258487163870031,
+    14208012769557118004,
+    9859597656952919728,
+    1292329897876258406,
+    1157312652733285835,
+    7882271968867205569,
+    15377132969442843746,
+    1215301361020325985,
+    989416377758956186,
+    15382797582607170620,
+    6443758369238256869,
+    1603731395110942585,
+    5055734629752080422,
+    6015748551023980226,
+    14792070015622472120,
+    1028685753857458774,
+    12970521803341419985,
+    5687908738690712537,
+    18043159852511447626,
+    3064898649536046074,
+    6297837568905510364,
+    16741335332321677474,
+    9022079336970173403,
+    3039816336884094102,
+    14763779484134537822,
+    8547539288152196989,
+    3816978707198243127,
+    2687480543539982157,
+    17451341414063870003,
+    16752793857217043219,
+    16227971886757321827,
+    2279694344657582259,
+    3868294237974083526,
+    10937915223303640892,
+    5123562362165893444,
+    2785600005328575353,
+    2514848161612611918,
+    10190876013770472110,
+    14402717492842236653,
+    2240461090088083400,
+    14297679334789033366,
+    11329445176292137671,
+    9893668641215576163,
+    2413660658409004888,
+    5947615752267292381,
+    379312889463849718,
+    1631703901633597249,
+    626302196909575538,
+    512502094439034116,
+    17637937252736982607,
+    12948521783952376841,
+    2552746687566806695,
+    4931079897854634642,
+    9044986151070883401,
+    2599918089738977318,
+    2696498178822086536,
+    14020178200986749227,
+    14886970337841272070,
+    17220740454651871717,
+    1381680527030928441,
+    16096332707210658425,
+    9100709298143919696,
+    9279203032833038077,
+    295262256208806262,
+    12498962325945460759,
+    17008505080595630439,
+    10945174426833150990,
+    584755576333279480,
+    10264047997594758199,
+    920640803166777006,
+    5136503834445726048,
+    1279645946043724978,
+    7020820904775706106,
+    4746022872387228364,
+    849965049588756496,
+    2341747563875295697,
+    8017721537531131714,
+    1756556660119572801,
+    16968359448129812196,
+    372013716983282889,
+    4184301443033197248,
+    9076358896974198806,
+    14242707086266333928,
+    2248110849383970754,
+    8523632496672404387,
+    7293973917691986764,
+    8250645572515356295,
+    2426525488632006138,
+    14190254226212411428,
+    127807379293724605,
+    6707726386095507812,
+    3220046359762267522,
+    8549630585193421738,
+    9552923336789419509,
+    1946191970607228379,
+    762418538227998408,
+    14777773328459793851,
+    11336218541070866226,
+    9917150575319003810,
+    1074358147729150265,
+    2223543086864624752,
+    4401134309321004995,
+    7726867147742994699,
+    1038389723170550666,
+    3909887462225603154,
+    7664525618025112279,
+    2259238283063562299,
+    217796088202648826,
+    17200328408859198276,
+    14166683161185998822,
+    11091340632138700221,
+    26382369481828488,
+    8434262898165591392,
+    7701234511532530525,
+    4516917996948190912,
+    1818515465792758921,
+    1031506421676419961,
+    11645111706141915848,
+    8146285798278948709,
+    2122298926870137524,
+    11379948143012349016,
+    8441095845679501021,
+    790568850378655209,
+    296983639607376320,
+    15879052709353927785,
+    13701362972762714548,
+    5215808873700305840,
+    768879997822596732,
+    14266765840856961369,
+    11850111643867142841,
+    7390806540009374890,
+    452500312818124859,
+    734953183008097826,
+    17866811507853541043,
+    1104918508710048150,
+    63832431133843979,
+    8048673490464052695,
+    15204352905434288216,
+    11767989238143142786,
+    537478806458423851,
+    9327561584059658346,
+    9814489247066937379,
+    5509071154220307430,
+    1521898790095140513,
+    8169595538233778072,
+    5582883352065152185,
+    1346468915825468816,
+    1290523985414159383,
+    11826466375670288931,
+    8845477579691530557,
+    9116602194520928314,
+    594665341027156012,
+    12635191883679615053,
+    16724613825315232232,
+    5214047181597306374,
+    2784387875605717225,
+    3431992289446806766,
+    139800102479589237,
+    13858802347532591925,
+    949348702981551825,
+    7495480404555292058,
+    18436317459705482327,
+    7318665367746700743,
+    1443420061764939338,
+    7943890881958934310,
+    3031106944098522055,
+    17596702737148264934,
+    1278302548137893189,
+    4038481043315278735,
+    10839449708036355324,
+    16864773864938317075,
+    1968104500626414599,
+    9338130430992401138,
+    6186611251402885288,
+    4310286623833297356,
+    1736931333055708161,
+    14214594689389829337,
+    2818464639084534272,
+    15131696310314802217,
+    1534085480487486036,
+    2220109616794001281,
+    2692249494705065625,
+    2337399519157169358,
+    361053600758078821,
+    11686083151993203439,
+    11862389717405931018,
+    11953007078380869058,
+    1569676131656129866,
+    11424745187629129750,
+    7867909017377455364,
+    3927500248118047660,
+    31330168853102067,
+    15908952862213757799,
+    11692288511137296860,
+    5486066877337607939,
+    1528302578625084488,
+    17926962242909889320,
+    6062913155484671734,
+    10381053721515410539,
+    3281374487458681664,
+    8828883396438629829,
+    17006135280366757140,
+    17924282815106026845,
+    3391771396766247396,
+    9455348630891687141,
+    9163553374401468584,
+    6775561411553518648,
+    1157708326409360519,
+    5105232773672049893,
+    16497831198313735789,
+    3633568345210515295,
+    1700575782366922032,
+    12426273164374779342,
+    1513890600497909973,
+    17908692030617273322,
+    1715520334801743226,
+    2826197857637524764,
+    13383394442345562484,
+    9597406056658245092,
+    734371809597206709,
+    16642527355520796243,
+    18237864311503186780,
+    1113123358270253960,
+    3074112277665057206,
+    3134461769081219181,
+    20060138447489269,
+    708628928217979029,
+    993381984259180713,
+    4603492047053464986,
+    17926350213705392009,
+    10416816979297340844,
+    533856544776424809,
+    12562536055993474121,
+    10247435475308854974,
+    8309672183811486784,
+    108119240954208741,
+    9580928230191407682,
+    15071415439349770166,
+    5476919037705423658,
+    2505021105255773930,
+    7119666868077779924,
+    5682533457015829424,
+    13878307413496788757,
+    2489496931709790555,
+    15540809188577287220,
+    16071428951154159877,
+    2806576143231020743,
+    647364915948539488,
+    17374631050965007757,
+    14952488970978247806,
+    15115511327020466369,
+    1923680880818636115,
+    18076365370931450627,
+    6607477675242678820,
+    8679891351685298392,
+    2703304208488267269,
+    15814057061931773552,
+    13840134768663042224,
+    3670647212064830513,
+    670661430362981285,
+    11268624121817614253,
+    16583367398841698002,
+    1476453661185982683,
+    1870562920979880113,
+    9681706911833338930,
+    16118754220071066069,
+    13527893524877056945,
+    2005781495699512962,
+    8625011782587048374,
+    2649527893431868900,
+    18352367321795752786,
+    975835268052093979,
+    12830073138470395008,
+    17365134238007281434,
+    11532649066128515101,
+    2483026126816508433,
+];
+
+const ALPHA_G1_BETA_G2: [u64; 48] = [
+    7830716141342797833,
+    761476989285576721,
+    15160149494060511636,
+    500123253656662130,
+    15455399008063623933,
+    2089046682968288514,
+    5156671680632163802,
+    2097441235076168710,
+    6710177415585846010,
+    14200842473177234589,
+    9598887321331894045,
+    944518226484069407,
+    12039014370555522565,
+    14661505427148289261,
+    16470200233146282867,
+    1932797199847802338,
+    14491087268598290623,
+    16779786628905874516,
+    17964913413781342838,
+    1218887097326116108,
+    10354975773906847760,
+    14255487104842555140,
+    6388001227704014842,
+    21209977999264241,
+    6058844440171844159,
+    10982184839878324614,
+    2506364353396244200,
+    3410006693435695385,
+    1332279504966519417,
+    11016032740660588308,
+    16826728945569119591,
+    1166306819807478883,
+    7498134255139041805,
+    14421191011078428797,
+    11182006428846724452,
+    3460924594097784471,
+    16976797871978450777,
+    1246971496731147114,
+    1437710750806697874,
+    215762333418719215,
+    3474305610632956650,
+    15255521653304699152,
+    9966317417788244452,
+    1857130836082929270,
+    1384988916883829998,
+    12108746489944365112,
+    8481199400513393049,
+    719860149626287558,
+];
+
+const GAMMA_ABC: [u64; 16] = [
+    9533558355278680064,
+    8165925214566509187,
+    14560823347482945809,
+    788227882905214341,
+    7212198669589072310,
+    9348052930780376943,
+    1998339362144137405,
+    2886485897584190708,
+    8614930233130101529,
+    4707947307666286482,
+    15548722020840236280,
+    2972989878995521026,
+    1569994513473966813,
+    13219998945733597238,
+    8543156385458403426,
+    584540220088362732,
+];
+
+#[cfg(test)]
+mod test {
+    use ark_groth16::prepare_verifying_key;
+    use circuit::range::range_keys;
+
+    use super::*;
+
+    #[test]
+    fn test_range_key_matches_ceremony_keys() {
+        let pvk = prepare_verifying_key(&range_keys().unwrap().1);
+        for j in 0..91 {
+            for k in 0..3 {
+                assert_eq!(RangeKey.gamma_coeff(j, k), pvk.gamma_coeff(j, k));
+                assert_eq!(RangeKey.delta_coeff(j, k), pvk.delta_coeff(j, k));
+            }
+        }
+        assert_eq!(RangeKey.alpha_g1_beta_g2(), pvk.alpha_g1_beta_g2());
+        assert_eq!(RangeKey.gamma_abc_g1(), pvk.gamma_abc_g1());
+    }
+}
@@ -0,0 +1,161 @@
+//! Sessions any account can advance.
+//!
+//! The prover submits the public inputs and the proof once with [`submit_handler`], after which
+//! any account can run the next step of the verification with [`step_handler`]. The contract
+//! keeps a cursor into the [`schedule`] of the session, so relayers only name the session and
+//! can't run steps out of order.
+use ark_bn254::{G1Affine, G2Affine};
+use ark_ec::bn::BnParameters;
+use ark_ff::to_bytes;
+use types::crypto::blake2b;
+
+use crate::error::Error;
+use crate::final_exponentiation::final_exponentiation_handler;
+use crate::inputs::{prepare_inputs_handler, read_inputs};
+use crate::miller_loop::{
+    delta_miller_loop_handler, gamma_miller_loop_handler, qap_miller_loop_handler, PROOF_AB_LEN,
+};
+use crate::pvk::VerifyingKey;
+use crate::session::Session;
+use crate::state::{read_input, Stage};
+use crate::storage::Storage;
+
+const G1_AFFINE_LEN: usize = 65;
+/// Length of a submitted proof: `A` (`G1Affine`), `B` (`G2Affine`) and `C` (`G1Affine`).
+pub const PROOF_LEN: usize = PROOF_AB_LEN + G1_AFFINE_LEN;
+
+/// One call of a verification handler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Step {
+    GammaMillerLoop { i: u8, j: u8 },
+    DeltaMillerLoop { i: u8, j: u8 },
+    QapMillerLoop { i: u8, j: u8 },
+    FinalExponentiation { t: u8, j: u8 },
+}
+
+/// Every step of the verification of a single proof, in the order they run.
+pub fn schedule() -> Vec<Step> {
+    let ate_loop_count = ark_bn254::Parameters::ATE_LOOP_COUNT;
+    let mut miller_loop = vec![];
+    let mut j = 0;
+    for i in (1..ate_loop_count.len()).rev() {
+        miller_loop.push((i as u8, j));
+        j += 1;
+        if ate_loop_count[i - 1] != 0 {
+            j += 1;
+        }
+    }
+    miller_loop.push((0, j));
+
+    let mut steps = vec![];
+    steps.extend(
+        miller_loop
+            .iter()
+            .map(|&(i, j)| Step::GammaMillerLoop { i, j }),
+    );
+    steps.extend(
+        miller_loop
+            .iter()
+            .map(|&(i, j)| Step::DeltaMillerLoop { i, j }),
+    );
+    steps.extend(
+        miller_loop
+            .iter()
+            .map(|&(i, j)| Step::QapMillerLoop { i, j }),
+    );
+    for t in Stage::PrepareFinalData as u8..=Stage::HardPartY16 as u8 {
+        let stage = Stage::from_u8(t).unwrap();
+        steps.extend((0..=stage.last_step()).map(|j| Step::FinalExponentiation { t, j }));
+    }
+    steps
+}
+
+/// The session of the proof `input` submitted by `prover`.
+pub fn relayed_session(prover: &[u8; 32], input: &[u8]) -> Session {
+    let mut src = prover.to_vec();
+    src.extend_from_slice(input);
+    Session::new(blake2b(src))
+}
+
+/// Starts a relayed session, or starts it over if `prover` submitted the same proof before.
+///
+/// `input` holds the public inputs as expected by [`prepare_inputs_handler`], with `prover` as
+/// the caller, followed by the proof.
+pub fn submit_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    prover: &[u8; 32],
+    input: &[u8],
+) -> Result<Session, Error> {
+    if input.len() < PROOF_LEN {
+        return Err(Error::InvalidInput);
+    }
+    let (inputs, proof) = input.split_at(input.len() - PROOF_LEN);
+    let _ = read_input::<G1Affine>(&proof[..G1_AFFINE_LEN])?;
+    let _ = read_input::<G2Affine>(&proof[G1_AFFINE_LEN..PROOF_AB_LEN])?;
+    let _ = read_input::<G1Affine>(&proof[PROOF_AB_LEN..])?;
+
+    let session = relayed_session(prover, input);
+    prepare_inputs_handler(storage, vk, &session, prover, inputs)?;
+    storage.set("data", &session.key("proof"), proof.to_vec());
+    write_cursor(storage, &session, 0);
+    Ok(session)
+}
+
+/// Runs the next step of a relayed session and returns it.
+pub fn step_handler<S: Storage, K: VerifyingKey>(
+    storage: &mut S,
+    vk: &K,
+    session: &Session,
+) -> Result<Step, Error> {
+    let cursor = read_cursor(storage, session)?;
+    let step = *schedule().get(cursor).ok_or(Error::UnexpectedStage)?;
+    let proof = storage
+        .get("data", &session.key("proof"))
+        .ok_or(Error::MissingState)?;
+    if proof.len() != PROOF_LEN {
+        return Err(Error::InvalidState);
+    }
+
+    match step {
+        Step::GammaMillerLoop { i, j } => {
+            let (prepared_input, _) = read_inputs(storage, session)?;
+            let prepared_input = to_bytes!(prepared_input).unwrap();
+            gamma_miller_loop_handler(storage, vk, session, i.into(), j.into(), &prepared_input)
+        }
+        Step::DeltaMillerLoop { i, j } => {
+            let proof_c = &proof[PROOF_AB_LEN..];
+            delta_miller_loop_handler(storage, vk, session, i.into(), j.into(), proof_c)
+        }
+        Step::QapMillerLoop { i, j } => {
+            let proof_ab = &proof[..PROOF_AB_LEN];
+            qap_miller_loop_handler(storage, session, i.into(), j.into(), proof_ab)
+        }
+        Step::FinalExponentiation { t, j } => {
+            final_exponentiation_handler(storage, vk, session, t.into(), j.into(), &[])
+        }
+    }?;
+    write_cursor(storage, session, cursor + 1);
+    Ok(step)
+}
+
+/// The number of steps of `session` that have run, if it is a relayed session.
+pub fn read_cursor<S: Storage>(storage: &S, session: &Session) -> Result<usize, Error> {
+    let src = storage
+        .get("data", &session.key("cursor"))
+        .ok_or(Error::MissingState)?;
+    let mut cursor = [0u8; 2];
+    if src.len() != cursor.len() {
+        return Err(Error::InvalidState);
+    }
+    cursor.copy_from_slice(&src);
+    Ok(u16::from_le_bytes(cursor).into())
+}
+
+fn write_cursor<S: Storage>(storage: &mut S, session: &Session, cursor: usize) {
+    storage.set(
+        "data",
+        &session.key("cursor"),
+        (cursor as u16).to_le_bytes().to_vec(),
+    );
+}
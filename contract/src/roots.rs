@@ -0,0 +1,137 @@
+//! Proofs of membership against a registry of accepted Merkle roots.
+//!
+//! Under a verifying key with a root the public inputs are prepared on chain by
+//! [`crate::inputs::prepare_inputs_handler`], which rejects roots the admin has not registered.
+//! The root is checked again by the final step, so revoking a root also stops the verifications
+//! in progress against it.
+use ark_bn254::{Fr, G1Affine};
+use ark_ff::to_bytes;
+use base64::{encode_config, URL_SAFE_NO_PAD};
+
+use crate::error::Error;
+use crate::inputs::{read_inputs, FR_LEN};
+use crate::pvk::VerifyingKey;
+use crate::session::Session;
+use crate::state::read_input;
+use crate::storage::Storage;
+
+/// Key of the account allowed to update the registry in the `data` dictionary, written when the
+/// contract is installed.
+pub const ADMIN_KEY: &str = "admin";
+
+/// A verifying key whose public input `index` is a Merkle root.
+#[derive(Clone, Copy, Debug)]
+pub struct RootKey<K> {
+    key: K,
+    index: usize,
+}
+
+impl<K: VerifyingKey> RootKey<K> {
+    pub fn new(key: K, index: usize) -> RootKey<K> {
+        RootKey { key, index }
+    }
+}
+
+impl<K: VerifyingKey> VerifyingKey for RootKey<K> {
+    fn gamma_coeff(&self, j: usize, k: usize) -> ark_bn254::Fq2 {
+        self.key.gamma_coeff(j, k)
+    }
+
+    fn delta_coeff(&self, j: usize, k: usize) -> ark_bn254::Fq2 {
+        self.key.delta_coeff(j, k)
+    }
+
+    fn alpha_g1_beta_g2(&self) -> ark_bn254::Fq12 {
+        self.key.alpha_g1_beta_g2()
+    }
+
+    fn gamma_abc_g1(&self) -> Vec<G1Affine> {
+        self.key.gamma_abc_g1()
+    }
+
+    fn nullifier_index(&self) -> Option<usize> {
+        self.key.nullifier_index()
+    }
+
+    fn caller_index(&self) -> Option<usize> {
+        self.key.caller_index()
+    }
+
+    fn root_index(&self) -> Option<usize> {
+        Some(self.index)
+    }
+}
+
+/// The account allowed to update the registry.
+pub fn admin<S: Storage>(storage: &S) -> Option<[u8; 32]> {
+    let src = storage.get("data", ADMIN_KEY)?;
+    let mut admin = [0u8; 32];
+    if src.len() != admin.len() {
+        return None;
+    }
+    admin.copy_from_slice(&src);
+    Some(admin)
+}
+
+/// Registers `root`, a 32 byte little-endian field element, if `accepted` and revokes it
+/// otherwise.
+///
+/// Only the admin can update the registry.
+pub fn set_root_handler<S: Storage>(
+    storage: &mut S,
+    caller: &[u8; 32],
+    root: &[u8],
+    accepted: bool,
+) -> Result<(), Error> {
+    if admin(storage).as_ref() != Some(caller) {
+        return Err(Error::Forbidden);
+    }
+    let root = to_bytes!(read_input::<Fr>(root)?).unwrap();
+    let value = if accepted { vec![1] } else { vec![] };
+    storage.set("roots", &root_key(&root), value);
+    Ok(())
+}
+
+/// Whether proofs against `root` are currently accepted.
+pub fn is_registered<S: Storage>(storage: &S, root: &[u8]) -> bool {
+    storage
+        .get("roots", &root_key(root))
+        .map_or(false, |value| !value.is_empty())
+}
+
+/// Checks that the root among the public inputs is registered, if `vk` has one.
+pub(crate) fn check_root<S: Storage, K: VerifyingKey>(
+    storage: &S,
+    vk: &K,
+    inputs: &[u8],
+) -> Result<(), Error> {
+    let index = match vk.root_index() {
+        Some(index) => index,
+        None => return Ok(()),
+    };
+    let root = inputs
+        .chunks(FR_LEN)
+        .nth(index)
+        .ok_or(Error::InvalidState)?;
+    if !is_registered(storage, root) {
+        return Err(Error::UnknownRoot);
+    }
+    Ok(())
+}
+
+/// Checks the root of the proof verified in `session` once more before it is accepted.
+pub fn check_session_root<S: Storage, K: VerifyingKey>(
+    storage: &S,
+    vk: &K,
+    session: &Session,
+) -> Result<(), Error> {
+    if vk.root_index().is_none() {
+        return Ok(());
+    }
+    let (_, inputs) = read_inputs(storage, session)?;
+    check_root(storage, vk, &inputs)
+}
+
+fn root_key(root: &[u8]) -> String {
+    encode_config(root, URL_SAFE_NO_PAD)
+}
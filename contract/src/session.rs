@@ -0,0 +1,27 @@
+use base64::{encode_config, URL_SAFE_NO_PAD};
+
+/// A verification session, the namespace of the intermediate values of one proof.
+///
+/// Sessions driven through the stage entry points are identified by the account that drives
+/// them, so a caller can only ever read and write its own values. Relayed sessions are
+/// identified by the hash of their prover and proof, see [`crate::relay`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Session([u8; 32]);
+
+impl Session {
+    pub fn new(id: [u8; 32]) -> Session {
+        Session(id)
+    }
+
+    pub fn id(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Dictionary key of `slot` in this session.
+    ///
+    /// Dictionary item keys are limited to 64 bytes, the 43 characters of the encoded id leave
+    /// room for the slot name.
+    pub fn key(&self, slot: &str) -> String {
+        [encode_config(self.0, URL_SAFE_NO_PAD), slot.to_string()].join("_")
+    }
+}
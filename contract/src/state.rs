@@ -0,0 +1,250 @@
+use ark_bn254::Fq12Parameters;
+use ark_ff::{to_bytes, Fp12, FromBytes};
+use num_traits::One;
+
+use crate::batch::BATCH_TARGET_BITS;
+use crate::compression::{compress, decompress};
+use crate::error::Error;
+use crate::miller_loop::QAP_POINT_SLOT;
+use crate::session::Session;
+use crate::storage::Storage;
+
+pub const BN254_DATA_LEN: usize = 384;
+
+/// Decodes an entry point argument that has to hold exactly one `T`.
+pub fn read_input<T: FromBytes>(input: &[u8]) -> Result<T, Error> {
+    let mut reader = input;
+    let value = T::read(&mut reader).map_err(|_| Error::InvalidInput)?;
+    if !reader.is_empty() {
+        return Err(Error::InvalidInput);
+    }
+    Ok(value)
+}
+
+/// Stages of the verification pipeline.
+///
+/// The discriminants of the final exponentiation stages match the `i` argument of the
+/// `final_exponentiation` entry point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Stage {
+    GammaMillerLoop = 0,
+    DeltaMillerLoop = 1,
+    PrepareFinalData = 2,
+    EasyPart1 = 3,
+    EasyPart2 = 4,
+    HardPartY0 = 5,
+    HardPartY1 = 6,
+    HardPartY3 = 7,
+    HardPartY4 = 8,
+    HardPartY6 = 9,
+    HardPartY8 = 10,
+    HardPartY9 = 11,
+    HardPartY11 = 12,
+    HardPartY13 = 13,
+    HardPartY14 = 14,
+    HardPartY15 = 15,
+    HardPartY16 = 16,
+    BatchTarget = 17,
+    BatchHardPartY16 = 18,
+    QapMillerLoop = 19,
+}
+
+impl Stage {
+    pub fn from_u8(value: u8) -> Option<Stage> {
+        let stage = match value {
+            0 => Stage::GammaMillerLoop,
+            1 => Stage::DeltaMillerLoop,
+            2 => Stage::PrepareFinalData,
+            3 => Stage::EasyPart1,
+            4 => Stage::EasyPart2,
+            5 => Stage::HardPartY0,
+            6 => Stage::HardPartY1,
+            7 => Stage::HardPartY3,
+            8 => Stage::HardPartY4,
+            9 => Stage::HardPartY6,
+            10 => Stage::HardPartY8,
+            11 => Stage::HardPartY9,
+            12 => Stage::HardPartY11,
+            13 => Stage::HardPartY13,
+            14 => Stage::HardPartY14,
+            15 => Stage::HardPartY15,
+            16 => Stage::HardPartY16,
+            17 => Stage::BatchTarget,
+            18 => Stage::BatchHardPartY16,
+            19 => Stage::QapMillerLoop,
+            _ => return None,
+        };
+        Some(stage)
+    }
+
+    /// The session slot this stage writes its output to.
+    pub fn slot(self) -> &'static str {
+        match self {
+            Stage::GammaMillerLoop => "gamma",
+            Stage::DeltaMillerLoop => "delta",
+            Stage::PrepareFinalData | Stage::EasyPart1 | Stage::EasyPart2 => "final",
+            Stage::HardPartY0 => "y0",
+            Stage::HardPartY1 => "y1",
+            Stage::HardPartY3 => "y3",
+            Stage::HardPartY4 => "y4",
+            Stage::HardPartY6 => "y6",
+            Stage::HardPartY8 => "y8",
+            Stage::HardPartY9 => "y9",
+            Stage::HardPartY11 => "y11",
+            Stage::HardPartY13 => "y13",
+            Stage::HardPartY14 => "y14",
+            Stage::HardPartY15 => "y15",
+            Stage::HardPartY16 | Stage::BatchHardPartY16 => "y16",
+            Stage::BatchTarget => "target",
+            Stage::QapMillerLoop => "qap",
+        }
+    }
+
+    /// The step `j` after which the value written by this stage is complete.
+    pub fn last_step(self) -> u8 {
+        match self {
+            Stage::GammaMillerLoop | Stage::DeltaMillerLoop | Stage::QapMillerLoop => 89,
+            Stage::HardPartY0 | Stage::HardPartY4 | Stage::HardPartY6 => 62,
+            Stage::BatchTarget => (BATCH_TARGET_BITS - 1) as u8,
+            _ => 0,
+        }
+    }
+
+    fn is_miller_loop(self) -> bool {
+        self.last_step() == 89
+    }
+
+    /// Values written after the first easy part lie in the cyclotomic subgroup and are
+    /// stored compressed.
+    fn is_compressed(self) -> bool {
+        self != Stage::QapMillerLoop && self as u8 >= Stage::EasyPart1 as u8
+    }
+}
+
+/// A value of the pipeline together with the stage and step that produced it.
+struct Entry {
+    stage: Stage,
+    step: u8,
+    value: Fp12<Fq12Parameters>,
+}
+
+fn read_entry<S: Storage>(storage: &S, session: &Session, stage: Stage) -> Result<Entry, Error> {
+    let src = storage
+        .get("data", &session.key(stage.slot()))
+        .ok_or(Error::MissingState)?;
+    let (stage, step, value) = decode_entry(&src)?;
+    Ok(Entry { stage, step, value })
+}
+
+/// Decodes a stored value into the stage and step that wrote it and the value itself.
+pub fn decode_entry(src: &[u8]) -> Result<(Stage, u8, Fp12<Fq12Parameters>), Error> {
+    if src.len() < 2 {
+        return Err(Error::MissingState);
+    }
+    let stage = Stage::from_u8(src[0]).ok_or(Error::UnexpectedStage)?;
+    let step = src[1];
+    let value = if stage.is_compressed() {
+        decompress(&src[2..]).ok_or(Error::InvalidState)?
+    } else {
+        read_input(&src[2..]).map_err(|_| Error::InvalidState)?
+    };
+    Ok((stage, step, value))
+}
+
+/// Reads the complete output of `stage` in `session`.
+///
+/// Fails with [`Error::MissingState`] if nothing was written to the slot of `stage` and with
+/// [`Error::UnexpectedStage`] if the value was written by another stage or is not finished yet.
+pub fn load<S: Storage>(
+    storage: &S,
+    session: &Session,
+    stage: Stage,
+) -> Result<Fp12<Fq12Parameters>, Error> {
+    let entry = read_entry(storage, session, stage)?;
+    if entry.stage != stage || entry.step != stage.last_step() {
+        return Err(Error::UnexpectedStage);
+    }
+    Ok(entry.value)
+}
+
+/// Reads the accumulator of a multi-step `stage` before running step `j`.
+///
+/// The first step starts from one, any later step requires a value written by an earlier step
+/// of the same stage.
+pub fn load_accumulator<S: Storage>(
+    storage: &S,
+    session: &Session,
+    stage: Stage,
+    j: usize,
+) -> Result<Fp12<Fq12Parameters>, Error> {
+    if j == 0 {
+        return Ok(Fp12::<Fq12Parameters>::one());
+    }
+    let entry = read_entry(storage, session, stage)?;
+    if entry.stage != stage || entry.step as usize >= j {
+        return Err(Error::UnexpectedStage);
+    }
+    Ok(entry.value)
+}
+
+/// Writes the value produced by step `j` of `stage` to its slot in `session`.
+pub fn store<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    stage: Stage,
+    j: usize,
+    f: &Fp12<Fq12Parameters>,
+) {
+    let mut dst = vec![stage as u8, j as u8];
+    if stage.is_compressed() {
+        dst.extend_from_slice(compress(f).as_slice());
+    } else {
+        dst.extend_from_slice(to_bytes!(f).unwrap().as_slice());
+    }
+    storage.set("data", &session.key(stage.slot()), dst);
+}
+
+/// The slot holding the input every step of the Miller loop `stage` runs over.
+fn input_slot(stage: Stage) -> String {
+    format!("{}_input", stage.slot())
+}
+
+/// Checks that step `j` of the Miller loop `stage` runs over the same input as its first step,
+/// which records it.
+pub fn pin_input<S: Storage>(
+    storage: &mut S,
+    session: &Session,
+    stage: Stage,
+    j: usize,
+    input: &[u8],
+) -> Result<(), Error> {
+    let key = session.key(&input_slot(stage));
+    if j == 0 {
+        storage.set("data", &key, input.to_vec());
+        return Ok(());
+    }
+    match storage.get("data", &key) {
+        Some(pinned) if pinned == input => Ok(()),
+        Some(pinned) if pinned.is_empty() => Err(Error::MissingState),
+        Some(_) => Err(Error::InvalidInput),
+        None => Err(Error::MissingState),
+    }
+}
+
+/// Clears the output of every stage in `session`, so the next verification starts over from
+/// the Miller loops.
+pub fn reset<S: Storage>(storage: &mut S, session: &Session) {
+    let mut slots = vec![QAP_POINT_SLOT.to_string()];
+    for stage in (0..=u8::MAX).filter_map(Stage::from_u8) {
+        slots.push(stage.slot().to_string());
+        if stage.is_miller_loop() {
+            slots.push(input_slot(stage));
+        }
+    }
+    slots.sort_unstable();
+    slots.dedup();
+    for slot in slots {
+        storage.set("data", &session.key(&slot), vec![]);
+    }
+}
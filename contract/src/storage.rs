@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+/// Key-value storage the verification handlers keep their state in.
+///
+/// On chain this is backed by the contract's dictionaries, [`MemoryStorage`] keeps the values in
+/// memory so the handlers can run natively.
+pub trait Storage {
+    fn get(&self, dictionary: &str, key: &str) -> Option<Vec<u8>>;
+    fn set(&mut self, dictionary: &str, key: &str, value: Vec<u8>);
+}
+
+/// In-memory [`Storage`] for native runs and tests.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryStorage {
+    values: HashMap<(String, String), Vec<u8>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> MemoryStorage {
+        MemoryStorage::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&self, dictionary: &str, key: &str) -> Option<Vec<u8>> {
+        self.values
+            .get(&(dictionary.to_string(), key.to_string()))
+            .cloned()
+    }
+
+    fn set(&mut self, dictionary: &str, key: &str, value: Vec<u8>) {
+        self.values
+            .insert((dictionary.to_string(), key.to_string()), value);
+    }
+}
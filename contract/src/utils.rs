@@ -1,39 +1,92 @@
-use ark_bn254::Fq12Parameters;
-use ark_ff::{to_bytes, Fp12, Fp12ParamsWrapper, FromBytes, QuadExtField};
-use arrayref::{array_mut_ref, array_ref};
 use contract::{
     contract_api::{
         runtime,
         storage::{self},
+        system,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
-use num_traits::One;
 use types::{
-    bytesrepr::{FromBytes as CasperFromBytes, ToBytes},
-    CLTyped, URef,
+    account::AccountHash,
+    bytesrepr::{Bytes, FromBytes as CasperFromBytes, ToBytes},
+    runtime_args, CLTyped, ContractHash, RuntimeArgs, URef, U512,
 };
+use verifier::bounty::Escrow;
+use verifier::callback::{Callback, Invoker};
+use verifier::error::Error;
+use verifier::storage::Storage;
 
-pub const BN254_DATA_LEN: usize = 384;
+/// [`Storage`] backed by the contract's dictionaries.
+pub struct CasperStorage;
 
-pub fn get_account_data(key: String, j: usize) -> QuadExtField<Fp12ParamsWrapper<Fq12Parameters>> {
-    let f = match j {
-        0 => Fp12::<Fq12Parameters>::one(),
-        _ => {
-            let src: Vec<u8> = get("data", &key).unwrap_or([0u8; BN254_DATA_LEN].to_vec());
-            let src = array_ref![src, 0, BN254_DATA_LEN];
-            Fp12::<Fq12Parameters>::read(&mut src.as_ref()).unwrap()
+impl Storage for CasperStorage {
+    fn get(&self, dictionary: &str, key: &str) -> Option<Vec<u8>> {
+        get(dictionary, key)
+    }
+
+    fn set(&mut self, dictionary: &str, key: &str, value: Vec<u8>) {
+        set(dictionary, key, value)
+    }
+}
+
+/// [`Invoker`] calling the target contract through `runtime::call_contract`.
+pub struct CasperInvoker;
+
+impl Invoker for CasperInvoker {
+    fn invoke(&mut self, callback: &Callback, vk_id: [u8; 32], inputs: &[u8]) -> u32 {
+        runtime::call_contract(
+            ContractHash::new(callback.contract_hash),
+            &callback.entry_point,
+            runtime_args! {
+                "vk_id" => vk_id,
+                "inputs" => Bytes::from(inputs.to_vec())
+            },
+        )
+    }
+}
+
+/// [`Escrow`] backed by the contract's `escrow` purse.
+pub struct CasperEscrow {
+    /// The purse deposits are taken from.
+    source: Option<URef>,
+}
+
+impl CasperEscrow {
+    pub fn new() -> CasperEscrow {
+        CasperEscrow { source: None }
+    }
+
+    pub fn with_source(source: URef) -> CasperEscrow {
+        CasperEscrow {
+            source: Some(source),
         }
-    };
-    f
+    }
 }
 
-pub fn put_account_data(key: String, f: &QuadExtField<Fp12ParamsWrapper<Fq12Parameters>>) {
-    let mut dst: Vec<u8> = get("data", &key).unwrap_or([0u8; BN254_DATA_LEN].to_vec());
-    let dst = array_mut_ref![dst, 0, BN254_DATA_LEN];
-    dst.copy_from_slice(to_bytes!(f).unwrap().as_slice());
+impl Escrow for CasperEscrow {
+    fn deposit(&mut self, amount: u64) -> Result<(), Error> {
+        let source = self.source.ok_or(Error::InvalidContext)?;
+        system::transfer_from_purse_to_purse(source, escrow_purse(), U512::from(amount), None)
+            .map_err(|_| Error::InsufficientBalance)
+    }
+
+    fn pay(&mut self, account: &[u8; 32], amount: u64) -> Result<(), Error> {
+        system::transfer_from_purse_to_account(
+            escrow_purse(),
+            AccountHash::new(*account),
+            U512::from(amount),
+            None,
+        )
+        .map(|_| ())
+        .map_err(|_| Error::InsufficientBalance)
+    }
+}
 
-    set("data", &key, dst.to_vec());
+fn escrow_purse() -> URef {
+    runtime::get_key("escrow")
+        .unwrap_or_revert()
+        .into_uref()
+        .unwrap_or_revert()
 }
 
 fn get<T: CasperFromBytes + CLTyped + Default>(dictionary_name: &str, key: &str) -> Option<T> {
@@ -0,0 +1,172 @@
+//! Differential tests of the staged verification against `ark_groth16::verify_proof`.
+//!
+//! Random circuits are set up with fresh parameters, proven, optionally tampered with and then
+//! run through every stage of the contract on an in-memory storage. The stages have to accept a
+//! proof exactly when the reference verifier does.
+use ark_bn254::{Bn254, Fr, G1Projective, G2Projective};
+use ark_ec::bn::BnParameters;
+use ark_ec::ProjectiveCurve;
+use ark_ff::{to_bytes, One};
+use ark_groth16::{
+    create_random_proof, generate_random_parameters, prepare_inputs, prepare_verifying_key,
+    verify_proof, PreparedVerifyingKey, Proof,
+};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError, Variable};
+use ark_std::rand::{rngs::StdRng, Rng, SeedableRng};
+use proptest::prelude::*;
+
+use verifier::error::Error;
+use verifier::final_exponentiation::final_exponentiation_handler;
+use verifier::miller_loop::{
+    delta_miller_loop_handler, gamma_miller_loop_handler, qap_miller_loop_handler,
+};
+use verifier::session::Session;
+use verifier::storage::MemoryStorage;
+
+/// Proves knowledge of `x_k` with `y_k = f(x_k)` for every public input `y_k`, where `f`
+/// iterates `v -> v * (v + c)` over the round constants.
+#[derive(Clone)]
+struct RandomCircuit {
+    preimages: Vec<Option<Fr>>,
+    constants: Vec<Fr>,
+}
+
+impl RandomCircuit {
+    fn image(&self, x: Fr) -> Fr {
+        self.constants.iter().fold(x, |v, c| v * (v + c))
+    }
+}
+
+impl ConstraintSynthesizer<Fr> for RandomCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        for preimage in &self.preimages {
+            let mut value = *preimage;
+            let mut v =
+                cs.new_witness_variable(|| value.ok_or(SynthesisError::AssignmentMissing))?;
+            for c in &self.constants {
+                let next_value = value.map(|v| v * (v + c));
+                let next = cs
+                    .new_witness_variable(|| next_value.ok_or(SynthesisError::AssignmentMissing))?;
+                cs.enforce_constraint(lc!() + v, lc!() + v + (*c, Variable::One), lc!() + next)?;
+                value = next_value;
+                v = next;
+            }
+            let image = cs.new_input_variable(|| value.ok_or(SynthesisError::AssignmentMissing))?;
+            cs.enforce_constraint(lc!() + v, lc!() + Variable::One, lc!() + image)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Tamper {
+    None,
+    A,
+    B,
+    C,
+    Input,
+}
+
+fn tamper() -> impl Strategy<Value = Tamper> {
+    prop_oneof![
+        2 => Just(Tamper::None),
+        1 => Just(Tamper::A),
+        1 => Just(Tamper::B),
+        1 => Just(Tamper::C),
+        1 => Just(Tamper::Input),
+    ]
+}
+
+/// Runs every stage of a single proof verification, as the client schedules them.
+fn staged_verify(
+    vk: &PreparedVerifyingKey<Bn254>,
+    proof: &Proof<Bn254>,
+    inputs: &[Fr],
+) -> Result<(), Error> {
+    let prepared_input = to_bytes!(prepare_inputs(vk, inputs).unwrap()).unwrap();
+    let proof_c = to_bytes!(proof.c).unwrap();
+    let proof_ab = to_bytes!(proof.a, proof.b).unwrap();
+    let mut storage = MemoryStorage::new();
+    let session = Session::new([7u8; 32]);
+
+    let mut j = 0;
+    for i in (0..ark_bn254::Parameters::ATE_LOOP_COUNT.len()).rev() {
+        gamma_miller_loop_handler(&mut storage, vk, &session, i, j, &prepared_input)?;
+        delta_miller_loop_handler(&mut storage, vk, &session, i, j, &proof_c)?;
+        qap_miller_loop_handler(&mut storage, &session, i, j, &proof_ab)?;
+        if i > 0 {
+            j += 1;
+            if ark_bn254::Parameters::ATE_LOOP_COUNT[i - 1] != 0 {
+                j += 1;
+            }
+        }
+    }
+
+    final_exponentiation_handler(&mut storage, vk, &session, 2, 0, &[])?;
+    for t in 3..17 {
+        let steps = match t {
+            5 | 8 | 9 => 63,
+            _ => 1,
+        };
+        for j in 0..steps {
+            final_exponentiation_handler(&mut storage, vk, &session, t, j, &[])?;
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn staged_verification_matches_reference(
+        seed in any::<u64>(),
+        inputs in 1usize..4,
+        rounds in 1usize..8,
+        tamper in tamper(),
+    ) {
+        let rng = &mut StdRng::seed_from_u64(seed);
+        let constants = (0..rounds).map(|_| rng.gen()).collect::<Vec<Fr>>();
+        let preimages = (0..inputs).map(|_| rng.gen()).collect::<Vec<Fr>>();
+        let setup = RandomCircuit {
+            preimages: vec![None; inputs],
+            constants: constants.clone(),
+        };
+        let params = generate_random_parameters::<Bn254, _, _>(setup, rng).unwrap();
+        let pvk = prepare_verifying_key(&params.vk);
+
+        let circuit = RandomCircuit {
+            preimages: preimages.iter().copied().map(Some).collect(),
+            constants,
+        };
+        let mut public_inputs = preimages
+            .iter()
+            .map(|x| circuit.image(*x))
+            .collect::<Vec<_>>();
+        let mut proof = create_random_proof(circuit, &params, rng).unwrap();
+
+        match tamper {
+            Tamper::None => {}
+            Tamper::A => proof.a = rng.gen::<G1Projective>().into_affine(),
+            Tamper::B => proof.b = rng.gen::<G2Projective>().into_affine(),
+            Tamper::C => proof.c = rng.gen::<G1Projective>().into_affine(),
+            Tamper::Input => {
+                let k = rng.gen_range(0..inputs);
+                public_inputs[k] += Fr::one();
+            }
+        }
+
+        let expected = verify_proof(&pvk, &proof, &public_inputs).unwrap();
+        match staged_verify(&pvk, &proof, &public_inputs) {
+            Ok(()) => prop_assert!(expected, "stages accepted a proof rejected by ark-groth16"),
+            Err(Error::InvalidProof) => {
+                prop_assert!(!expected, "stages rejected a proof accepted by ark-groth16")
+            }
+            Err(e) => prop_assert!(false, "verification failed with {:?}", e),
+        }
+        if let Tamper::None = tamper {
+            prop_assert!(expected);
+        }
+    }
+}